@@ -3,41 +3,14 @@
 use std::collections::HashMap;
 
 use proc_macro2::{Ident, TokenStream, TokenTree};
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::{Member, Type, spanned::Spanned};
 
-use super::types::{CloneMode, FieldMapping, Transform, transform_with_expr_tokens};
-
-/// Controls which transforms can be reversed in bidirectional conversions.
-///
-/// Different macros have different policies for what can be reversed:
-/// - `relate_structs!` is strict: only identity mappings are reversible
-/// - `#[derive(Relate)]` is flexible: any non-default transform can be reversed
-///
-/// # Example
-///
-/// ```ignore
-/// // IdentityOnly: only `name;` can be reversed, `age: with = ...` cannot
-/// relate_structs! { A ~ B { name; age: with = _.to_string(); } }
-///
-/// // AllNonDefault: both can be reversed (user takes responsibility)
-/// #[derive(Relate)]
-/// #[relate(Source, both)]
-/// struct Target { name: String, age: String }
-/// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ReverseStrategy {
-    /// Only reverse identity transforms (`relate_structs!` behavior).
-    ///
-    /// Transforms like `with = expr` will cause a compile error if
-    /// bidirectional, since there's no automatic way to reverse arbitrary
-    /// expressions.
-    IdentityOnly,
-    /// Reverse all non-default transforms (`#[derive(Relate)]` behavior).
-    ///
-    /// Allows bidirectional with any reversible transform. The user is
-    /// responsible for ensuring the reverse mapping makes semantic sense.
-    AllNonDefault,
-}
+use super::types::{
+    CloneMode, FieldMapping, Pointer, Transform, clone_consumed_receivers, clone_field_reads,
+    collect_target_refs, field_tuple_elements, generic_arg_mask, member_name,
+    transform_with_expr_tokens, with_expr_usage_key,
+};
 
 /// Check if a token stream contains a method/function call (parentheses).
 /// Used to determine if a WithExpr produces an owned value.
@@ -52,14 +25,133 @@ pub fn tokens_contain_call(tokens: &TokenStream) -> bool {
     })
 }
 
-/// Generate field access code: `src.field` or `src.field.clone()`
+/// The error type of a fallible conversion: the custom one, or
+/// `ConversionError` by default.
 #[must_use]
-pub fn field_access(field: &Ident, should_clone: bool) -> TokenStream {
-    if should_clone {
-        quote! { src.#field.clone() }
+pub fn error_type_tokens(error_type: Option<&Type>) -> TokenStream {
+    error_type
+        .map(|t| quote! { #t })
+        .unwrap_or_else(|| quote! { ::relate::ConversionError })
+}
+
+/// Name the source parameter of a conversion with the given body: `src`, or
+/// `_src` when the body never reads it (every field a default), so the impl
+/// doesn't trip `unused_variables`.
+#[must_use]
+pub fn source_param(body: &TokenStream) -> Ident {
+    fn mentions_src(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            TokenTree::Ident(ident) => ident == "src",
+            TokenTree::Group(group) => mentions_src(group.stream()),
+            _ => false,
+        })
+    }
+    let name = if mentions_src(body.clone()) {
+        "src"
     } else {
-        quote! { src.#field }
+        "_src"
+    };
+    Ident::new(name, proc_macro2::Span::call_site())
+}
+
+/// Check if a token stream computes a new value from the fields it reads,
+/// with an operator or an `as` cast (`.a + .b`, `_ as u64`, `!_`). Such an
+/// expression only borrows or copies its fields, so it never needs a clone.
+///
+/// A block (`{ .a }`) or a dereference (`*_`) can still evaluate to a field,
+/// so they don't count.
+#[must_use]
+pub fn tokens_compute_value(tokens: &TokenStream) -> bool {
+    let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    let mut computes = false;
+    for (i, tt) in tokens.iter().enumerate() {
+        match tt {
+            TokenTree::Group(group) if group.delimiter() == proc_macro2::Delimiter::Brace => {
+                return false;
+            }
+            TokenTree::Punct(p) => match p.as_char() {
+                // A `*` with no operand before it dereferences
+                '*' if i == 0 || matches!(tokens[i - 1], TokenTree::Punct(_)) => return false,
+                '.' | '?' | '$' | '@' | ',' | ':' | ';' | '#' | '\'' => {}
+                _ => computes = true,
+            },
+            TokenTree::Ident(ident) if ident == "as" => computes = true,
+            _ => {}
+        }
+    }
+    computes
+}
+
+/// Generate field access code: `src.field`, `src.field.clone()`, or
+/// `Arc::clone(&src.field)` in `arc_clone` mode
+#[must_use]
+pub fn field_access(field: &Member, should_clone: bool, clone_mode: CloneMode) -> TokenStream {
+    match (should_clone, clone_mode) {
+        (false, _) => quote! { src.#field },
+        (true, CloneMode::ArcClone) => quote! { ::std::sync::Arc::clone(&src.#field) },
+        (true, _) => quote! { src.#field.clone() },
+    }
+}
+
+/// Generate a `copy` mode field access that checks the field is `Copy`.
+///
+/// Without the check, a non-`Copy` field fails with a move error deep in the
+/// generated impl; the bound names the requirement instead.
+#[must_use]
+pub fn copy_access(field: &Member) -> TokenStream {
+    // Span the check at the field so the error points at it
+    let check = quote_spanned! {field.span()=> __relate_copy_field_must_be_copy(&src.#field) };
+    quote! {
+        {
+            const fn __relate_copy_field_must_be_copy<T: ::core::marker::Copy>(value: &T) -> &T {
+                value
+            }
+            *#check
+        }
+    }
+}
+
+/// Convert a type name to snake case: `ForeignUser` -> `foreign_user`.
+///
+/// A run of capitals is one acronym word: `HTTPConfig` -> `http_config`.
+#[must_use]
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, &ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() {
+            let prev = i.checked_sub(1).map(|prev| chars[prev]);
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            // A word starts after a lowercase letter or digit, or at the last
+            // capital of an acronym followed by a lowercase letter
+            let word_start = match prev {
+                Some(prev) if prev.is_uppercase() => next_lower,
+                Some(prev) => prev != '_',
+                None => false,
+            };
+            if word_start {
+                snake.push('_');
+            }
+            snake.extend(ch.to_lowercase());
+        } else {
+            snake.push(ch);
+        }
     }
+    snake
+}
+
+/// Check whether a closure's parameter is a reference: `|x: &T|` or `|&x|`.
+fn closure_takes_ref(closure: &syn::ExprClosure) -> bool {
+    match closure.inputs.first() {
+        Some(syn::Pat::Type(pat_type)) => matches!(*pat_type.ty, syn::Type::Reference(_)),
+        Some(syn::Pat::Reference(_)) => true,
+        _ => false,
+    }
+}
+
+/// Check whether a closure's parameter has a type annotation.
+fn closure_param_typed(closure: &syn::ExprClosure) -> bool {
+    matches!(closure.inputs.first(), Some(syn::Pat::Type(_)))
 }
 
 /// Generate a single field initialization expression.
@@ -70,6 +162,22 @@ pub fn generate_field_init(
     is_ref: bool,
     field_usage: &HashMap<String, FieldUsage>,
     struct_clone_mode: CloneMode,
+) -> TokenStream {
+    let target = &mapping.target_field;
+    let value = generate_field_value(mapping, field_index, is_ref, field_usage, struct_clone_mode);
+    quote! { #target: #value }
+}
+
+/// Generate a single field's value expression (without the `field:` prefix).
+///
+/// Used directly for positional construction of tuple structs.
+#[must_use]
+pub fn generate_field_value(
+    mapping: &FieldMapping,
+    field_index: usize,
+    is_ref: bool,
+    field_usage: &HashMap<String, FieldUsage>,
+    struct_clone_mode: CloneMode,
 ) -> TokenStream {
     let target = &mapping.target_field;
     let source_field = mapping.source.get_field_name(target);
@@ -86,21 +194,59 @@ pub fn generate_field_init(
         effective_clone_mode,
     );
 
+    // Direct source field access; `copy` mode asserts the field is `Copy`
+    let access = || {
+        if effective_clone_mode == CloneMode::Copy {
+            copy_access(source_field)
+        } else {
+            field_access(source_field, should_clone, effective_clone_mode)
+        }
+    };
+
     let value = match &mapping.source.transform {
         // Default transforms don't use a source field value
         Transform::Default => quote! { ::core::default::Default::default() },
         Transform::DefaultExpr(expr) => quote! { #expr },
+        Transform::DefaultFn(path) => quote! { #path() },
 
         // Identity: direct field access
-        Transform::Identity => field_access(source_field, should_clone),
+        Transform::Identity => access(),
+
+        // `with = (.x, .y)` - a tuple of fields, each cloned on its own
+        Transform::WithExpr(tokens, false) if field_tuple_elements(tokens).is_some() => {
+            let elements = field_tuple_elements(tokens).unwrap_or_default();
+            let clones = tuple_element_clones(
+                &elements,
+                source_field,
+                field_index,
+                is_ref,
+                field_usage,
+                effective_clone_mode,
+            );
+            field_tuple_value(&elements, &clones, source_field, effective_clone_mode)
+        }
 
         // `with = expr` - transform tokens using `.field` and `_` syntax
         Transform::WithExpr(tokens, fallible) => {
-            let transformed = transform_with_expr_tokens(tokens, source_field);
+            // `cloned` clones each field the expression reads by value
+            let cloned = effective_clone_mode == CloneMode::Cloned;
+            let transformed = if cloned {
+                transform_with_expr_tokens(&clone_field_reads(tokens), source_field)
+            } else if should_clone {
+                transform_with_expr_tokens(&clone_consumed_receivers(tokens), source_field)
+            } else {
+                transform_with_expr_tokens(tokens, source_field)
+            };
             // For simple field paths (no method calls), we need to clone in ref impl
-            // Method calls typically return owned values, so no clone needed
-            let needs_clone = should_clone && !tokens_contain_call(tokens);
-            let value = if needs_clone {
+            // Method calls typically return owned values, and operators compute
+            // new ones, so no clone needed
+            let needs_clone = should_clone
+                && !cloned
+                && !tokens_contain_call(tokens)
+                && !tokens_compute_value(tokens);
+            let value = if needs_clone && effective_clone_mode == CloneMode::ArcClone {
+                quote! { ::std::sync::Arc::clone(&(#transformed)) }
+            } else if needs_clone {
                 quote! { (#transformed).clone() }
             } else {
                 transformed
@@ -112,25 +258,308 @@ pub fn generate_field_init(
             }
         }
 
-        // Collection map: `with = [_.field]`
-        Transform::CollectionMap(tokens) => {
-            let replaced = replace_placeholder(tokens, "__item");
+        // Required: `Option<A>` source must be `Some`, inner value converted with `Into`
+        Transform::Required => {
+            let access = access();
+            let name = member_name(target);
+            quote! {
+                #access
+                    .map(::core::convert::Into::into)
+                    .ok_or_else(|| ::relate::ConversionError::missing_field(#name))?
+            }
+        }
+
+        // Skip if: fallback when the predicate holds, otherwise the field itself
+        Transform::SkipIf(predicate, fallback) => {
+            let condition = transform_with_expr_tokens(predicate, source_field);
+            let fallback = fallback
+                .as_ref()
+                .map(|expr| quote! { #expr })
+                .unwrap_or_else(|| quote! { ::core::default::Default::default() });
+            let access = access();
+            quote! {
+                if #condition { #fallback } else { #access }
+            }
+        }
+
+        // Fallback field: each read is cloned like a same-named field read
+        Transform::FallbackField(fallback) => {
+            let elements = [quote! { .#source_field }, quote! { .#fallback }];
+            let clones = tuple_element_clones(
+                &elements,
+                source_field,
+                field_index,
+                is_ref,
+                field_usage,
+                effective_clone_mode,
+            );
+            let [primary, fallback] = [(source_field, clones[0]), (fallback, clones[1])]
+                .map(|(field, clone)| field_access(field, clone, effective_clone_mode));
+            quote! { #primary.or_else(|| #fallback) }
+        }
+
+        // Some: wrap the field for an `Option` target
+        Transform::WrapSome => {
+            let access = access();
+            quote! { ::core::option::Option::Some(#access) }
+        }
+
+        // Smart pointer: clone then wrap in the ref impl
+        Transform::Wrap(pointer) => {
+            let access = access();
+            let path = match pointer {
+                Pointer::Box => quote! { ::std::boxed::Box },
+                Pointer::Rc => quote! { ::std::rc::Rc },
+                Pointer::Arc => quote! { ::std::sync::Arc },
+            };
+            quote! { #path::new(#access) }
+        }
+
+        // Unbox: move out of the box, or clone the boxed value
+        Transform::Unbox => {
+            if should_clone {
+                quote! { (*src.#source_field).clone() }
+            } else {
+                quote! { *src.#source_field }
+            }
+        }
+
+        // Into owned: consume the `Cow`, or copy out of it when it can't move
+        Transform::IntoOwned => {
+            if should_clone {
+                quote! { ::std::borrow::ToOwned::to_owned(&*src.#source_field) }
+            } else {
+                quote! { ::std::borrow::Cow::into_owned(src.#source_field) }
+            }
+        }
+
+        // To vec: copy the array or slice out by reference in both impls
+        Transform::ToVec => quote! { src.#source_field.to_vec() },
+
+        // Into: convert by value, or from a reference in the ref impl
+        Transform::Into => {
+            let by_ref =
+                is_ref && !matches!(effective_clone_mode, CloneMode::Cloned | CloneMode::Copy);
+            if by_ref {
+                quote! { ::core::convert::Into::into(&src.#source_field) }
+            } else {
+                let access = access();
+                quote! { ::core::convert::Into::into(#access) }
+            }
+        }
+
+        // Getter: call the method on the source, which returns a new value
+        Transform::Getter(method) if effective_clone_mode == CloneMode::Cloned => {
+            quote! { src.#method().clone() }
+        }
+        Transform::Getter(method) => quote! { src.#method() },
+
+        // Function call: by value, or by reference in the ref impl
+        Transform::FnCall(path) | Transform::TryFnCall(path) => {
+            let by_ref =
+                is_ref && !matches!(effective_clone_mode, CloneMode::Cloned | CloneMode::Copy);
+            let call = if by_ref {
+                quote! { #path(&src.#source_field) }
+            } else {
+                let access = access();
+                quote! { #path(#access) }
+            };
+            if matches!(mapping.source.transform, Transform::TryFnCall(_)) {
+                quote! { #call? }
+            } else {
+                call
+            }
+        }
+
+        // Closure: a reference-taking closure borrows in both impls
+        Transform::Closure(closure) => {
+            let arg = if closure_takes_ref(closure) {
+                quote! { &src.#source_field }
+            } else {
+                access()
+            };
+            if closure_param_typed(closure) {
+                quote! { (#closure)(#arg) }
+            } else {
+                // Untyped parameters are inferred from the argument, which
+                // an immediately invoked closure can't do
+                quote! {
+                    {
+                        fn __relate_apply<A, R>(f: impl ::core::ops::FnOnce(A) -> R, arg: A) -> R {
+                            f(arg)
+                        }
+                        __relate_apply(#closure, #arg)
+                    }
+                }
+            }
+        }
+
+        // Collection map: `with = [_.field]`, or `[idx, _ => expr]` to
+        // enumerate the items; `opt[..]` maps the collection inside an `Option`
+        Transform::CollectionMap(tokens, index) | Transform::OptionCollectionMap(tokens, index) => {
+            let optional = matches!(mapping.source.transform, Transform::OptionCollectionMap(..));
+            let collection = if optional {
+                quote! { __items }
+            } else {
+                quote! { src.#source_field }
+            };
+            let (element, fallible) = split_trailing_question_mark(tokens);
+            let replaced = replace_placeholder(&element, "__item");
+            // An owned source that isn't read again is consumed, so the items
+            // are moved into the closure instead of borrowed or cloned. That
+            // holds in `cloned` and `copy` mode too, which only describe the
+            // items.
+            let container_mode = match effective_clone_mode {
+                CloneMode::Move => CloneMode::Move,
+                _ => CloneMode::Auto,
+            };
+            let consumed =
+                !should_clone_field(mapping, field_index, is_ref, field_usage, container_mode);
             // With cloned mode, use .iter().cloned().map(...).collect()
             // and always apply Into::into for type conversion
-            if effective_clone_mode == CloneMode::Cloned {
+            let (items, mapped) = if effective_clone_mode == CloneMode::Cloned {
+                let mapped = if fallible {
+                    quote! { (#replaced).map(::core::convert::Into::into) }
+                } else {
+                    quote! { ::core::convert::Into::into(#replaced) }
+                };
+                let items = if consumed {
+                    quote! { #collection.into_iter() }
+                } else {
+                    quote! { #collection.iter().cloned() }
+                };
+                (items, mapped)
+            } else if is_placeholder(&element) && !fallible {
+                // Bare `[_]` converts each item with Into, cloning the items
+                // of a borrowed source
+                let items = if consumed {
+                    quote! { #collection.into_iter() }
+                } else {
+                    quote! { #collection.iter().cloned() }
+                };
+                (items, quote! { ::core::convert::Into::into(__item) })
+            } else if consumed && placeholder_only_receiver(&element) {
+                // `_.field` / `_.method()` read the item the same way whether
+                // it's owned or borrowed
+                (quote! { #collection.into_iter() }, replaced)
+            } else {
+                (quote! { #collection.iter() }, replaced)
+            };
+            let iter = match index {
+                Some(index) => quote! { #items.enumerate().map(|(#index, __item)| #mapped) },
+                None => quote! { #items.map(|__item| #mapped) },
+            };
+            if optional {
+                // Map inside the `Option`, borrowing it unless it's consumed
+                let option = if consumed {
+                    quote! { src.#source_field }
+                } else {
+                    quote! { src.#source_field.as_ref() }
+                };
+                if fallible {
+                    quote! {
+                        #option
+                            .map(|__items| #iter.collect::<::core::result::Result<_, _>>())
+                            .transpose()?
+                    }
+                } else {
+                    quote! { #option.map(|__items| #iter.collect()) }
+                }
+            } else {
+                collect_mapped(&iter, fallible)
+            }
+        }
+
+        // Map values: `with = map[_.field]` - keys are cloned, values transformed
+        Transform::MapValues(tokens) => {
+            let (element, fallible) = split_trailing_question_mark(tokens);
+            let replaced = replace_placeholder(&element, "__item");
+            let entries = if effective_clone_mode == CloneMode::Cloned {
+                let entry = if fallible {
+                    quote! {
+                        (#replaced).map(|__value| (__key, ::core::convert::Into::into(__value)))
+                    }
+                } else {
+                    quote! { (__key, ::core::convert::Into::into(#replaced)) }
+                };
                 quote! {
                     src.#source_field.iter()
-                        .cloned()
-                        .map(|__item| ::core::convert::Into::into(#replaced))
-                        .collect()
+                        .map(|(__key, __item)| (__key.clone(), __item.clone()))
+                        .map(|(__key, __item)| #entry)
                 }
             } else {
-                quote! { src.#source_field.iter().map(|__item| #replaced).collect() }
-            }
+                let entry = if fallible {
+                    quote! { (#replaced).map(|__value| (__key.clone(), __value)) }
+                } else {
+                    quote! { (__key.clone(), #replaced) }
+                };
+                quote! { src.#source_field.iter().map(|(__key, __item)| #entry) }
+            };
+            collect_mapped(&entries, fallible)
         }
     };
 
-    quote! { #target: #value }
+    let value = apply_map_err(&value, mapping.source.map_err.as_ref());
+    localize_question_mark(&value, target)
+}
+
+/// Span a value's trailing `?` at the target field.
+///
+/// When the conversion's error type can't be built from the field's error,
+/// the compiler reports the failed `?` conversion there instead of at the
+/// whole macro invocation.
+#[must_use]
+pub fn localize_question_mark(value: &TokenStream, field: &Member) -> TokenStream {
+    let (inner, fallible) = split_trailing_question_mark(value);
+    if fallible {
+        // The parentheses give the operand the field's span too
+        quote_spanned! {field.span()=> (#inner)? }
+    } else {
+        value.clone()
+    }
+}
+
+/// Adapt the error of a fallible value with its `map_err` closure:
+/// `expr?` becomes `(expr).map_err(closure)?`.
+#[must_use]
+pub fn apply_map_err(value: &TokenStream, map_err: Option<&syn::Expr>) -> TokenStream {
+    let Some(closure) = map_err else {
+        return value.clone();
+    };
+    let (inner, fallible) = split_trailing_question_mark(value);
+    if fallible {
+        quote! { (#inner).map_err(#closure)? }
+    } else {
+        value.clone()
+    }
+}
+
+/// Split a trailing top-level `?` off collection element tokens.
+///
+/// `[_.parse()?]` becomes (`_.parse()`, true): the `?` belongs to the whole
+/// collection, not to each element inside the closure.
+#[must_use]
+pub fn split_trailing_question_mark(tokens: &TokenStream) -> (TokenStream, bool) {
+    let mut trees: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    if matches!(trees.last(), Some(TokenTree::Punct(p)) if p.as_char() == '?') {
+        trees.pop();
+        (trees.into_iter().collect(), true)
+    } else {
+        (tokens.clone(), false)
+    }
+}
+
+/// Collect a mapped iterator, short-circuiting on the first error when
+/// fallible.
+///
+/// The container is never named: it is inferred from the target field, so
+/// `Vec<A>` can feed a `VecDeque`, `BTreeSet`, or any other `FromIterator`.
+fn collect_mapped(iter: &TokenStream, fallible: bool) -> TokenStream {
+    if fallible {
+        quote! { #iter.collect::<::core::result::Result<_, _>>()? }
+    } else {
+        quote! { #iter.collect() }
+    }
 }
 
 /// Determine if a field should be cloned based on clone mode.
@@ -167,12 +596,6 @@ fn should_clone_field(
 
     // --- Below here: owned impl only ---
 
-    // Move mode: never clone in owned impl
-    if effective_clone_mode == CloneMode::Move {
-        return false;
-    }
-
-    // Auto mode: clone only multi-use fields (except last use of Identity)
     let usage_key = mapping.source.get_usage_key(&mapping.target_field);
     let Some(usage) = field_usage.get(&usage_key) else {
         return false;
@@ -182,10 +605,191 @@ fn should_clone_field(
         return false;
     }
 
+    // Move mode: move on the last use; earlier reads clone to avoid a
+    // partial move
+    if effective_clone_mode == CloneMode::Move {
+        return field_index != usage.last_index;
+    }
+
+    // Auto mode: clone only multi-use fields (except last use of Identity)
+
     // Multi-use: Identity can move on last use, others always clone
     !matches!(mapping.source.transform, Transform::Identity if field_index == usage.last_index)
 }
 
+/// Decide which elements of a tuple of fields (`with = (.x, .y)`) to clone.
+///
+/// Each element is cloned like a same-named field read: always in the ref
+/// impl, and in the owned impl unless it is the field's last use.
+#[must_use]
+pub fn tuple_element_clones(
+    elements: &[TokenStream],
+    source_field: &Member,
+    field_index: usize,
+    is_ref: bool,
+    field_usage: &HashMap<String, FieldUsage>,
+    clone_mode: CloneMode,
+) -> Vec<bool> {
+    let keys: Vec<String> = elements
+        .iter()
+        .map(|e| with_expr_usage_key(e, source_field))
+        .collect();
+    keys.iter()
+        .enumerate()
+        .map(|(i, key)| match clone_mode {
+            CloneMode::Copy => false,
+            CloneMode::Cloned => true,
+            _ if is_ref => true,
+            CloneMode::Auto | CloneMode::Move | CloneMode::ArcClone => {
+                // A field repeated later in the same tuple isn't the last use
+                let repeated = keys[i + 1..].contains(key);
+                field_usage.get(key).is_some_and(|usage| {
+                    usage.count > 1 && (field_index != usage.last_index || repeated)
+                })
+            }
+        })
+        .collect()
+}
+
+/// Generate a tuple of fields (`with = (.x, .y)`), cloning the elements
+/// marked in `clones`.
+#[must_use]
+pub fn field_tuple_value(
+    elements: &[TokenStream],
+    clones: &[bool],
+    source_field: &Member,
+    clone_mode: CloneMode,
+) -> TokenStream {
+    let values = elements.iter().zip(clones).map(|(element, &clone)| {
+        let access = transform_with_expr_tokens(element, source_field);
+        match (clone, clone_mode) {
+            (false, _) => access,
+            (true, CloneMode::ArcClone) => quote! { ::std::sync::Arc::clone(&#access) },
+            (true, _) => quote! { #access.clone() },
+        }
+    });
+    quote! { (#(#values,)*) }
+}
+
+/// Find the first field the owned impl would clone implicitly, for `no_clone`.
+///
+/// Only `Auto` mode clones implicitly: an explicit `cloned` is allowed, and
+/// `with` expressions containing a call produce an owned value without a
+/// clone. `@field` targets are bound up front and clone on every read of a
+/// multi-use field.
+#[must_use]
+pub fn implicit_owned_clone(
+    fields: &[FieldMapping],
+    struct_clone_mode: CloneMode,
+) -> Option<&FieldMapping> {
+    let field_usage = count_field_usage(fields);
+    let target_refs = target_ref_order(fields).unwrap_or_default();
+    fields.iter().enumerate().find_map(|(idx, mapping)| {
+        let mode = mapping.source.clone_mode.unwrap_or(struct_clone_mode);
+        if mode != CloneMode::Auto {
+            return None;
+        }
+        let index = if target_refs.contains(&idx) {
+            usize::MAX
+        } else {
+            idx
+        };
+        if let Transform::WithExpr(tokens, _) = &mapping.source.transform {
+            if let Some(elements) = field_tuple_elements(tokens) {
+                let field = mapping.source.get_field_name(&mapping.target_field);
+                let clones =
+                    tuple_element_clones(&elements, field, index, false, &field_usage, mode);
+                return clones.contains(&true).then_some(mapping);
+            }
+            if tokens_contain_call(tokens) {
+                return None;
+            }
+        }
+        if let Transform::FallbackField(fallback) = &mapping.source.transform {
+            let field = mapping.source.get_field_name(&mapping.target_field);
+            let elements = [quote! { .#field }, quote! { .#fallback }];
+            let clones = tuple_element_clones(&elements, field, index, false, &field_usage, mode);
+            return clones.contains(&true).then_some(mapping);
+        }
+        should_clone_field(mapping, index, false, &field_usage, mode).then_some(mapping)
+    })
+}
+
+/// Get the lifetime a target borrows from its source: the first lifetime
+/// parameter of the target that the source type doesn't mention.
+///
+/// The ref impls take `&'a Source` for a target `View<'a>`, so its fields
+/// can borrow from the source for as long as the target lives.
+#[must_use]
+pub fn borrowed_lifetime(
+    target: &syn::Generics,
+    source_type: &TokenStream,
+) -> Option<syn::Lifetime> {
+    fn mentions(tokens: TokenStream, name: &Ident) -> bool {
+        let tokens: Vec<_> = tokens.into_iter().collect();
+        tokens
+            .iter()
+            .enumerate()
+            .any(|(i, tt)| match (tt, tokens.get(i + 1)) {
+                (TokenTree::Punct(p), Some(TokenTree::Ident(ident))) => {
+                    p.as_char() == '\'' && ident == name
+                }
+                (TokenTree::Group(group), _) => mentions(group.stream(), name),
+                _ => false,
+            })
+    }
+    target
+        .lifetimes()
+        .find(|param| !mentions(source_type.clone(), &param.lifetime.ident))
+        .map(|param| param.lifetime.clone())
+}
+
+/// Generate the inherent `__relate_roundtrip(a: Source) -> bool` on the
+/// target of a bidirectional `From` relation (`roundtrip_check`).
+///
+/// Converts `a` to the target and back, and compares with the original. Uses
+/// the owned impls when `by_ref` is false (requiring `Source: Clone`), the
+/// `&Source` / `&Target` impls otherwise.
+#[must_use]
+pub fn roundtrip_check_fn(
+    impl_generics: &TokenStream,
+    target_type: &TokenStream,
+    source_type: &TokenStream,
+    where_clause: &TokenStream,
+    by_ref: bool,
+) -> TokenStream {
+    let (bounds, there, back) = if by_ref {
+        (
+            quote! { ::core::cmp::PartialEq },
+            quote! { <#target_type as ::core::convert::From<&#source_type>>::from(&a) },
+            quote! {
+                <#source_type as ::core::convert::From<&#target_type>>::from(&__relate_target)
+            },
+        )
+    } else {
+        (
+            quote! { ::core::cmp::PartialEq + ::core::clone::Clone },
+            quote! {
+                <#target_type as ::core::convert::From<#source_type>>::from(
+                    ::core::clone::Clone::clone(&a),
+                )
+            },
+            quote! { <#source_type as ::core::convert::From<#target_type>>::from(__relate_target) },
+        )
+    };
+    quote! {
+        impl #impl_generics #target_type #where_clause {
+            pub fn __relate_roundtrip(a: #source_type) -> bool
+            where
+                #source_type: #bounds,
+            {
+                let __relate_target = #there;
+                #back == a
+            }
+        }
+    }
+}
+
 /// Field usage information for smart cloning.
 #[derive(Debug, Clone)]
 pub struct FieldUsage {
@@ -205,17 +809,18 @@ pub fn count_field_usage(mappings: &[FieldMapping]) -> HashMap<String, FieldUsag
         if !mapping.source.reads_field() {
             continue;
         }
-        let usage_key = mapping.source.get_usage_key(&mapping.target_field);
-        usage
-            .entry(usage_key)
-            .and_modify(|u| {
-                u.count += 1;
-                u.last_index = index;
-            })
-            .or_insert(FieldUsage {
-                count:      1,
-                last_index: index,
-            });
+        for usage_key in mapping.source.get_usage_keys(&mapping.target_field) {
+            usage
+                .entry(usage_key)
+                .and_modify(|u| {
+                    u.count += 1;
+                    u.last_index = index;
+                })
+                .or_insert(FieldUsage {
+                    count:      1,
+                    last_index: index,
+                });
+        }
     }
 
     usage
@@ -230,20 +835,47 @@ pub fn count_reverse_field_usage(mappings: &[FieldMapping]) -> HashMap<String, u
         if mapping.source.transform.is_default_kind() {
             continue;
         }
-        *counts.entry(mapping.target_field.to_string()).or_insert(0) += 1;
+        *counts
+            .entry(member_name(&mapping.target_field))
+            .or_insert(0) += 1;
     }
 
     counts
 }
 
-/// Generate a reverse field initialization for bidirectional relations.
-/// Returns None for fields that can't be reversed (defaults, transforms, etc.).
+/// Generate a reverse field initialization for `#[derive(Relate)]`
+/// bidirectional conversions.
+///
+/// Every non-default transform is reversed by mapping the target field back
+/// to its source field; the user is responsible for the reverse making sense.
+/// Returns None for default fields, which have no source value.
 #[must_use]
 pub fn generate_reverse_field_init(
     mapping: &FieldMapping,
     is_ref: bool,
     field_usage: &HashMap<String, usize>,
-    strategy: ReverseStrategy,
+    clone_mode: CloneMode,
+) -> Option<TokenStream> {
+    let value = generate_reverse_field_value(mapping, is_ref, field_usage, clone_mode)?;
+
+    // Get the source field name (in reverse, it becomes the destination)
+    let source_field = mapping.source.get_field_name(&mapping.target_field);
+
+    Some(quote! { #source_field: #value })
+}
+
+/// Generate the value a reverse conversion writes back to a field's source
+/// field, or `None` for fields that don't read one (defaults).
+///
+/// `clone_mode` is the reverse impls' own clone mode (`reverse_cloned`,
+/// `reverse_copy`, `reverse_move`); `Auto` clones in the ref impl and for
+/// fields read more than once.
+#[must_use]
+pub fn generate_reverse_field_value(
+    mapping: &FieldMapping,
+    is_ref: bool,
+    field_usage: &HashMap<String, usize>,
+    clone_mode: CloneMode,
 ) -> Option<TokenStream> {
     let target = &mapping.target_field;
 
@@ -251,26 +883,120 @@ pub fn generate_reverse_field_init(
     if mapping.source.transform.is_default_kind() {
         return None;
     }
-
-    // For relate_structs!, only reverse identity transforms
-    if strategy == ReverseStrategy::IdentityOnly
-        && !matches!(mapping.source.transform, Transform::Identity)
-    {
-        return None;
+    // Shorthands with a known inverse (`secs_to_duration`) convert back with it
+    if let Some(Transform::WithExpr(tokens, _)) = mapping.source.reverse.as_deref() {
+        return Some(transform_with_expr_tokens(tokens, target));
     }
 
-    let should_clone = is_ref || field_usage.get(&target.to_string()).copied().unwrap_or(0) > 1;
+    let should_clone = match clone_mode {
+        CloneMode::Cloned => true,
+        CloneMode::Copy => false,
+        CloneMode::Move => is_ref,
+        CloneMode::Auto | CloneMode::ArcClone => {
+            is_ref || field_usage.get(&member_name(target)).copied().unwrap_or(0) > 1
+        }
+    };
 
-    let value = if should_clone {
+    let value = if matches!(mapping.source.transform, Transform::Into) {
+        // Convert back the same way: from a reference in the ref impl
+        let access = if is_ref {
+            quote! { &src.#target }
+        } else if should_clone {
+            quote! { src.#target.clone() }
+        } else {
+            quote! { src.#target }
+        };
+        quote! { ::core::convert::Into::into(#access) }
+    } else if should_clone {
         quote! { src.#target.clone() }
     } else {
         quote! { src.#target }
     };
 
-    // Get the source field name (in reverse, it becomes the destination)
-    let source_field = mapping.source.get_field_name(target);
+    // A required field came from an `Option`, so wrap it back up
+    if matches!(mapping.source.transform, Transform::Required) {
+        Some(quote! { ::core::option::Option::Some(::core::convert::Into::into(#value)) })
+    } else {
+        Some(value)
+    }
+}
 
-    Some(quote! { #source_field: #value })
+/// Order the fields involved in `@field` references so every field comes
+/// after the fields it references.
+///
+/// Returns the indices of referenced and referencing fields, which are
+/// computed into `let` bindings (see `target_ref_binding`) before the struct
+/// is built. Errors on unknown targets and cyclic references.
+pub fn target_ref_order(fields: &[FieldMapping]) -> syn::Result<Vec<usize>> {
+    fn visit(
+        idx: usize,
+        deps: &[Vec<usize>],
+        state: &mut [u8],
+        order: &mut Vec<usize>,
+    ) -> Result<(), usize> {
+        // 0 = unvisited, 1 = in progress, 2 = ordered
+        match state[idx] {
+            2 => return Ok(()),
+            1 => return Err(idx),
+            _ => {}
+        }
+        state[idx] = 1;
+        for &dep in &deps[idx] {
+            visit(dep, deps, state, order)?;
+        }
+        state[idx] = 2;
+        order.push(idx);
+        Ok(())
+    }
+
+    let mut deps = vec![Vec::new(); fields.len()];
+    let mut involved = vec![false; fields.len()];
+    for (idx, field) in fields.iter().enumerate() {
+        let Transform::WithExpr(tokens, _) = &field.source.transform else {
+            continue;
+        };
+        let mut refs = Vec::new();
+        collect_target_refs(tokens, &mut refs);
+        for ident in refs {
+            let Some(dep) = fields
+                .iter()
+                .position(|f| matches!(&f.target_field, Member::Named(name) if *name == ident))
+            else {
+                return Err(syn::Error::new_spanned(
+                    &ident,
+                    format!("`@{ident}` does not name a field of the target"),
+                ));
+            };
+            deps[idx].push(dep);
+            involved[idx] = true;
+            involved[dep] = true;
+        }
+    }
+
+    let mut state = vec![0; fields.len()];
+    let mut order = Vec::new();
+    for idx in (0..fields.len()).filter(|&idx| involved[idx]) {
+        if let Err(cycle) = visit(idx, &deps, &mut state, &mut order) {
+            return Err(syn::Error::new_spanned(
+                &fields[cycle].target_field,
+                format!(
+                    "Cyclic `@field` reference involving `{}`",
+                    member_name(&fields[cycle].target_field)
+                ),
+            ));
+        }
+    }
+    Ok(order)
+}
+
+/// Check if collection tokens are just the `_` placeholder.
+#[must_use]
+pub fn is_placeholder(tokens: &TokenStream) -> bool {
+    let mut iter = tokens.clone().into_iter();
+    matches!(
+        (iter.next(), iter.next()),
+        (Some(TokenTree::Ident(ident)), None) if ident == "_"
+    )
 }
 
 /// Replace `_` with the given replacement in token stream.
@@ -281,10 +1007,13 @@ pub fn replace_placeholder(tokens: &TokenStream, replacement: &str) -> TokenStre
     // Use call_site because the identifier is used in generated closures
     // and must be accessible in the expanded code
     let replacement_ident = Ident::new(replacement, proc_macro2::Span::call_site());
+    let tokens: Vec<_> = tokens.clone().into_iter().collect();
+    let in_generics = generic_arg_mask(&tokens);
     tokens
-        .clone()
         .into_iter()
-        .map(|tt| match tt {
+        .zip(in_generics)
+        .map(|(tt, in_generics)| match tt {
+            _ if in_generics => tt,
             TokenTree::Ident(ident) if ident == "_" => TokenTree::Ident(replacement_ident.clone()),
             TokenTree::Group(group) => {
                 let replaced = replace_placeholder(&group.stream(), replacement);
@@ -295,6 +1024,22 @@ pub fn replace_placeholder(tokens: &TokenStream, replacement: &str) -> TokenStre
         .collect()
 }
 
+/// Check that every `_` placeholder is the receiver of a field access or
+/// method call (`_.id`, `_.len()`), so the expression also works on an owned
+/// item. An argument (`f(_)`) or a dereference (`*_`) may need a reference.
+fn placeholder_only_receiver(tokens: &TokenStream) -> bool {
+    let tokens: Vec<_> = tokens.clone().into_iter().collect();
+    let in_generics = generic_arg_mask(&tokens);
+    tokens.iter().enumerate().all(|(i, tt)| match tt {
+        _ if in_generics[i] => true,
+        TokenTree::Ident(ident) if ident == "_" => {
+            matches!(tokens.get(i + 1), Some(TokenTree::Punct(p)) if p.as_char() == '.')
+        }
+        TokenTree::Group(group) => placeholder_only_receiver(&group.stream()),
+        _ => true,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,18 +1047,27 @@ mod tests {
 
     fn make_identity_mapping(name: &str) -> FieldMapping {
         FieldMapping {
-            target_field: Ident::new(name, proc_macro2::Span::call_site()),
+            target_field: Member::Named(Ident::new(name, proc_macro2::Span::call_site())),
             source:       FieldSource::auto(),
         }
     }
 
     fn make_default_mapping(name: &str) -> FieldMapping {
         FieldMapping {
-            target_field: Ident::new(name, proc_macro2::Span::call_site()),
+            target_field: Member::Named(Ident::new(name, proc_macro2::Span::call_site())),
             source:       FieldSource::default_value(),
         }
     }
 
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("ForeignUser"), "foreign_user");
+        assert_eq!(to_snake_case("HTTPConfig"), "http_config");
+        assert_eq!(to_snake_case("ParseURL"), "parse_url");
+        assert_eq!(to_snake_case("IOError2"), "io_error2");
+        assert_eq!(to_snake_case("Api_V2"), "api_v2");
+    }
+
     #[test]
     fn test_count_field_usage_single() {
         let mappings = vec![
@@ -330,6 +1084,56 @@ mod tests {
         assert_eq!(usage.get("c").map(|u| u.count), Some(1));
     }
 
+    #[test]
+    fn test_count_field_usage_tuple_elements() {
+        let mut point = make_identity_mapping("point");
+        point.source = FieldSource::with_expr(quote! { (.x, .y) }, false);
+        let mappings = vec![point, make_identity_mapping("x")];
+
+        let usage = count_field_usage(&mappings);
+
+        // Each tuple element counts as a read of that field
+        assert_eq!(usage.len(), 2);
+        assert_eq!(
+            usage.get("x").map(|u| (u.count, u.last_index)),
+            Some((2, 1))
+        );
+        assert_eq!(usage.get("y").map(|u| u.count), Some(1));
+    }
+
+    #[test]
+    fn test_count_field_usage_fallback_field() {
+        let mut id = make_identity_mapping("id");
+        let legacy = Member::Named(Ident::new("legacy_id", proc_macro2::Span::call_site()));
+        id.source = FieldSource::with_transform(Transform::FallbackField(legacy));
+        let mappings = vec![id, make_identity_mapping("legacy_id")];
+
+        let usage = count_field_usage(&mappings);
+
+        // `default_if_none` reads both the field and its fallback
+        assert_eq!(usage.get("id").map(|u| u.count), Some(1));
+        assert_eq!(
+            usage.get("legacy_id").map(|u| (u.count, u.last_index)),
+            Some((2, 1))
+        );
+    }
+
+    #[test]
+    fn test_count_field_usage_raw_identifiers() {
+        let mut label = make_identity_mapping("label");
+        label.source = FieldSource::with_expr(quote! { .r#type }, false);
+        let raw = FieldMapping {
+            target_field: Member::Named(Ident::new_raw("type", proc_macro2::Span::call_site())),
+            source:       FieldSource::auto(),
+        };
+
+        let usage = count_field_usage(&[raw, label]);
+
+        // `.r#type` and the `r#type` field are the same source field
+        assert_eq!(usage.len(), 1);
+        assert_eq!(usage.get("type").map(|u| u.count), Some(2));
+    }
+
     #[test]
     fn test_count_field_usage_skips_defaults() {
         let mappings = vec![
@@ -380,6 +1184,61 @@ mod tests {
         assert!(tokens_contain_call(&tokens));
     }
 
+    #[test]
+    fn test_tokens_compute_value() {
+        assert!(tokens_compute_value(&quote! { .width * .height }));
+        assert!(tokens_compute_value(&quote! { _ as u64 }));
+        assert!(tokens_compute_value(&quote! { .a == .b }));
+        assert!(!tokens_compute_value(&quote! { .nested.field }));
+        assert!(!tokens_compute_value(&quote! { *_ }));
+        assert!(!tokens_compute_value(
+            &quote! { if .flag { .a } else { .b } }
+        ));
+    }
+
+    #[test]
+    fn test_clone_consumed_receivers() {
+        let cloned = |tokens: TokenStream| clone_consumed_receivers(&tokens).to_string();
+        assert_eq!(
+            cloned(quote! { _.ok() }),
+            quote! { _.clone().ok() }.to_string()
+        );
+        assert_eq!(
+            cloned(quote! { .meta.status.unwrap_or(0) }),
+            quote! { .meta.status.clone().unwrap_or(0) }.to_string()
+        );
+        assert_eq!(
+            cloned(quote! { f(.a.map_err(E::from)) }),
+            quote! { f(.a.clone().map_err(E::from)) }.to_string()
+        );
+        assert_eq!(cloned(quote! { _.len() }), quote! { _.len() }.to_string());
+        assert_eq!(
+            cloned(quote! { _.get().ok() }),
+            quote! { _.get().ok() }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_clone_field_reads() {
+        let cloned = |tokens: TokenStream| clone_field_reads(&tokens).to_string();
+        assert_eq!(
+            cloned(quote! { Pair::new(.a, .meta.b) }),
+            quote! { Pair::new(.a.clone(), .meta.b.clone()) }.to_string()
+        );
+        assert_eq!(
+            cloned(quote! { vec![_] }),
+            quote! { vec![_.clone()] }.to_string()
+        );
+        assert_eq!(
+            cloned(quote! { _.ok() }),
+            quote! { _.clone().ok() }.to_string()
+        );
+        assert_eq!(
+            cloned(quote! { f(&.a, .b.len()) }),
+            quote! { f(&.a, .b.len()) }.to_string()
+        );
+    }
+
     #[test]
     fn test_replace_placeholder() {
         let tokens: TokenStream = quote! { _.field };
@@ -397,10 +1256,20 @@ mod tests {
         assert!(replaced_str.contains("__item"));
     }
 
+    #[test]
+    fn test_replace_placeholder_keeps_inferred_types() {
+        let tokens: TokenStream = quote! { _.iter().collect::<Vec<_>>() < <_>::default() };
+        let replaced = replace_placeholder(&tokens, "__item");
+        assert_eq!(
+            replaced.to_string(),
+            quote! { __item.iter().collect::<Vec<_>>() < <_>::default() }.to_string()
+        );
+    }
+
     #[test]
     fn test_field_access_without_clone() {
-        let field = Ident::new("name", proc_macro2::Span::call_site());
-        let tokens = field_access(&field, false);
+        let field = Member::Named(Ident::new("name", proc_macro2::Span::call_site()));
+        let tokens = field_access(&field, false, CloneMode::Auto);
         let token_str = tokens.to_string();
         assert!(token_str.contains("src . name"));
         assert!(!token_str.contains("clone"));
@@ -408,12 +1277,61 @@ mod tests {
 
     #[test]
     fn test_field_access_with_clone() {
-        let field = Ident::new("name", proc_macro2::Span::call_site());
-        let tokens = field_access(&field, true);
+        let field = Member::Named(Ident::new("name", proc_macro2::Span::call_site()));
+        let tokens = field_access(&field, true, CloneMode::Auto);
         let token_str = tokens.to_string();
         assert!(token_str.contains("clone"));
     }
 
+    #[test]
+    fn test_field_access_arc_clone() {
+        // `Arc::clone(&x)` is the form `clippy::clone_on_ref_ptr` asks for
+        let field = Member::Named(Ident::new("shared", proc_macro2::Span::call_site()));
+        let tokens = field_access(&field, true, CloneMode::ArcClone);
+        assert_eq!(
+            tokens.to_string(),
+            quote! { ::std::sync::Arc::clone(&src.shared) }.to_string()
+        );
+
+        // Not cloned: plain access, no Arc::clone
+        let tokens = field_access(&field, false, CloneMode::ArcClone);
+        assert_eq!(tokens.to_string(), quote! { src.shared }.to_string());
+    }
+
+    #[test]
+    fn test_arc_clone_in_ref_impl() {
+        let mut mapping = make_identity_mapping("shared");
+        mapping.source.clone_mode = Some(CloneMode::ArcClone);
+        let usage = count_field_usage(std::slice::from_ref(&mapping));
+
+        let by_ref = generate_field_value(&mapping, 0, true, &usage, CloneMode::Auto);
+        assert_eq!(
+            by_ref.to_string(),
+            quote! { ::std::sync::Arc::clone(&src.shared) }.to_string()
+        );
+
+        // The owned impl moves a single-use field
+        let owned = generate_field_value(&mapping, 0, false, &usage, CloneMode::Auto);
+        assert_eq!(owned.to_string(), quote! { src.shared }.to_string());
+    }
+
+    #[test]
+    fn test_move_clones_only_before_last_use() {
+        let mut first = make_identity_mapping("name");
+        first.source.clone_mode = Some(CloneMode::Move);
+        let mut second = make_identity_mapping("copy");
+        second.source = FieldSource::with_expr(quote! { .name }, false);
+        second.source.clone_mode = Some(CloneMode::Move);
+        let mappings = vec![first, second];
+        let usage = count_field_usage(&mappings);
+
+        let earlier = generate_field_value(&mappings[0], 0, false, &usage, CloneMode::Auto);
+        assert_eq!(earlier.to_string(), quote! { src.name.clone() }.to_string());
+
+        let last = generate_field_value(&mappings[1], 1, false, &usage, CloneMode::Auto);
+        assert_eq!(last.to_string(), quote! { src.name }.to_string());
+    }
+
     #[test]
     fn test_count_reverse_field_usage() {
         let mappings = vec![
@@ -430,4 +1348,47 @@ mod tests {
         assert_eq!(usage.get("b").copied(), Some(1));
         assert!(!usage.contains_key("c"));
     }
+
+    #[test]
+    fn test_split_trailing_question_mark() {
+        let (tokens, fallible) = split_trailing_question_mark(&quote! { _.parse()? });
+        assert!(fallible);
+        assert_eq!(tokens.to_string(), quote! { _.parse() }.to_string());
+
+        let (tokens, fallible) = split_trailing_question_mark(&quote! { _.id.clone() });
+        assert!(!fallible);
+        assert_eq!(tokens.to_string(), quote! { _.id.clone() }.to_string());
+    }
+
+    #[test]
+    fn test_target_ref_order_dependencies_first() {
+        let mut full = make_identity_mapping("full");
+        full.source = FieldSource::with_expr(quote! { format!("{} {}", @first, @last) }, false);
+        let mut label = make_identity_mapping("label");
+        label.source = FieldSource::with_expr(quote! { @full.len() }, false);
+        let mappings = vec![
+            label,
+            full,
+            make_identity_mapping("first"),
+            make_identity_mapping("last"),
+            make_identity_mapping("unrelated"),
+        ];
+
+        let order = target_ref_order(&mappings).unwrap();
+
+        assert_eq!(order, vec![2, 3, 1, 0]);
+    }
+
+    #[test]
+    fn test_constant_with_expr_reads_no_field() {
+        let constant = FieldSource::with_expr(quote! { Vec::<u8>::with_capacity(4) }, false);
+        let field = FieldSource::with_expr(quote! { _.len() }, false);
+        let sibling = FieldSource::with_expr(quote! { .count + 1 }, false);
+        let whole = FieldSource::with_expr(quote! { summarize(&$src) }, false);
+
+        assert!(!constant.reads_field());
+        assert!(field.reads_field());
+        assert!(sibling.reads_field());
+        assert!(whole.reads_field());
+    }
 }
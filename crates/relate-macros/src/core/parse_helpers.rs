@@ -1,9 +1,9 @@
 //! Shared parsing utilities for token collection.
 
 use proc_macro2::{TokenStream, TokenTree};
-use syn::{Error, Ident, Result, Token, parse::ParseStream};
+use syn::{Error, Expr, Ident, LitStr, Member, Result, Token, ext::IdentExt, parse::ParseStream};
 
-use super::CloneMode;
+use super::{CloneMode, FieldMapping, Transform, generic_arg_mask};
 
 /// Check if we're at a terminator position.
 fn is_at_terminator(input: ParseStream, check_semicolon: bool) -> bool {
@@ -58,6 +58,184 @@ pub fn parse_tokens_until_terminator(
     Ok((tokens, fallible))
 }
 
+/// Parse a bracketed collection body: `[_.field]` or the `[.field]` shorthand.
+///
+/// The shorthand is normalized to `_.field` so both forms produce the same
+/// tokens. Used for both `[...]` sequence maps and `map[...]` value maps.
+pub fn parse_collection_body(input: ParseStream) -> Result<TokenStream> {
+    let content;
+    syn::bracketed!(content in input);
+    normalize_collection_element(content.parse()?)
+}
+
+/// Parse a bracketed sequence map body, with an optional leading index
+/// binding: `[idx, _ => expr]` enumerates the items and binds each
+/// position to `idx`. A key-value pair, `[_.key.clone(), _.value]`, maps
+/// each item to a tuple, so the items collect into a map.
+///
+/// The index keeps the span the user wrote it with, so `expr` can refer to
+/// it; the generated item binding's name is rejected so the two can't clash.
+pub fn parse_sequence_map_body(input: ParseStream) -> Result<(TokenStream, Option<Ident>)> {
+    let content;
+    syn::bracketed!(content in input);
+    let index = if content.peek(Ident) && content.peek2(Token![,]) {
+        let index: Ident = content.parse()?;
+        if index == "__item" {
+            return Err(Error::new_spanned(
+                &index,
+                "`__item` is reserved for the collection element, use another index name",
+            ));
+        }
+        content.parse::<Token![,]>()?;
+        content.parse::<Token![_]>()?;
+        content.parse::<Token![=>]>()?;
+        Some(index)
+    } else {
+        None
+    };
+    Ok((pair_element(content.parse()?)?, index))
+}
+
+/// Split a `key, value` element at its top-level comma into a `(key, value)`
+/// tuple; any other element is only normalized.
+fn pair_element(inner: TokenStream) -> Result<TokenStream> {
+    let tokens: Vec<TokenTree> = inner.into_iter().collect();
+    let in_generics = generic_arg_mask(&tokens);
+    let commas: Vec<usize> = (0..tokens.len())
+        .filter(|&i| !in_generics[i])
+        .filter(|&i| matches!(&tokens[i], TokenTree::Punct(p) if p.as_char() == ','))
+        .collect();
+    let (key, value) = match commas.as_slice() {
+        [] => return normalize_collection_element(tokens.into_iter().collect()),
+        [comma] => tokens.split_at(*comma),
+        [_, extra, ..] => {
+            return Err(Error::new_spanned(
+                &tokens[*extra],
+                "A collection map element is one expression or a key-value pair: \
+                 `[_.key.clone(), _.value]`",
+            ));
+        }
+    };
+    let value = &value[1..];
+    if key.is_empty() || value.is_empty() {
+        return Err(Error::new_spanned(
+            &tokens[commas[0]],
+            "Expected a key and a value around `,`: `[_.key.clone(), _.value]`",
+        ));
+    }
+    let key = normalize_collection_element(key.iter().cloned().collect())?;
+    let value = normalize_collection_element(value.iter().cloned().collect())?;
+    Ok(quote::quote! { (#key, #value) })
+}
+
+/// Normalize the `[.field]` shorthand to `_.field`.
+fn normalize_collection_element(inner: TokenStream) -> Result<TokenStream> {
+    // If it starts with `.`, it's shorthand: [.id.clone()] -> [_.id.clone()]
+    if inner.to_string().starts_with('.') {
+        let underscore = Ident::new("_", proc_macro2::Span::call_site());
+        Ok(quote::quote! { #underscore #inner })
+    } else {
+        Ok(inner)
+    }
+}
+
+/// Check if the input is at a `map[...]` value-map body.
+pub fn peek_map_values(input: ParseStream) -> bool {
+    let fork = input.fork();
+    matches!(fork.parse::<Ident>(), Ok(ident) if ident == "map") && fork.peek(syn::token::Bracket)
+}
+
+/// Check if the input is at an `opt[...]` optional collection map body.
+pub fn peek_option_collection(input: ParseStream) -> bool {
+    let fork = input.fork();
+    matches!(fork.parse::<Ident>(), Ok(ident) if ident == "opt") && fork.peek(syn::token::Bracket)
+}
+
+/// Parse the rest of `skip_if = predicate` with an optional trailing
+/// `, default` or `, default = expr` fallback (the `skip_if` keyword is
+/// already consumed).
+///
+/// # Arguments
+/// * `input` - The parse stream to read from
+/// * `check_semicolon` - If true, the predicate also stops at `;`
+///   (relate_structs! style)
+pub fn parse_skip_if(input: ParseStream, check_semicolon: bool) -> Result<Transform> {
+    input.parse::<Token![=]>()?;
+    let span = input.span();
+    let (predicate, fallible) = parse_tokens_until_terminator(input, check_semicolon)?;
+    if predicate.is_empty() {
+        return Err(Error::new(span, "Expected a condition after `skip_if =`"));
+    }
+    if fallible {
+        return Err(Error::new(
+            span,
+            "`skip_if` condition can't be fallible: remove the trailing `?`",
+        ));
+    }
+
+    // Optional `, default` or `, default = expr`
+    let fork = input.fork();
+    let has_default = fork.parse::<Token![,]>().is_ok()
+        && matches!(fork.parse::<Ident>(), Ok(ident) if ident == "default");
+    if !has_default {
+        return Ok(Transform::SkipIf(predicate, None));
+    }
+    input.parse::<Token![,]>()?;
+    input.parse::<Ident>()?;
+    let fallback = if input.peek(Token![=]) {
+        input.parse::<Token![=]>()?;
+        Some(input.parse()?)
+    } else {
+        None
+    };
+    Ok(Transform::SkipIf(predicate, fallback))
+}
+
+/// Parse the rest of `default_if_none = .field` (the keyword is already
+/// consumed) into a `FallbackField` transform.
+pub fn parse_default_if_none(input: ParseStream) -> Result<Transform> {
+    input.parse::<Token![=]>()?;
+    if !input.peek(Token![.]) {
+        return Err(Error::new(
+            input.span(),
+            "Expected a source field after `default_if_none =`, like `.legacy_id`",
+        ));
+    }
+    input.parse::<Token![.]>()?;
+    Ok(Transform::FallbackField(input.parse()?))
+}
+
+/// Check if the input is at a trailing `, name = ...` option.
+pub fn peek_trailing_option(input: ParseStream, name: &str) -> bool {
+    let fork = input.fork();
+    fork.parse::<Token![,]>().is_ok()
+        && matches!(fork.parse::<Ident>(), Ok(ident) if ident == name)
+        && fork.peek(Token![=])
+}
+
+/// Parse an optional trailing `, map_err = closure` after a fallible
+/// transform.
+///
+/// The closure adapts the field's error before `?` converts it into the
+/// conversion's error type.
+pub fn parse_trailing_map_err(input: ParseStream, transform: &Transform) -> Result<Option<Expr>> {
+    if !peek_trailing_option(input, "map_err") {
+        return Ok(None);
+    }
+    input.parse::<Token![,]>()?;
+    let keyword: Ident = input.parse()?;
+    input.parse::<Token![=]>()?;
+    let closure: Expr = input.parse()?;
+
+    if !transform.is_fallible() {
+        return Err(Error::new_spanned(
+            keyword,
+            "`map_err` only applies to fallible transforms (ending in `?`)",
+        ));
+    }
+    Ok(Some(closure))
+}
+
 /// Parse an optional trailing clone mode after a comma.
 ///
 /// This handles the common pattern of `, cloned`, `, copy`, or `, move` after
@@ -108,6 +286,11 @@ pub fn parse_trailing_clone_mode(
                 input.parse::<Ident>()?;
                 return Ok(Some(CloneMode::Copy));
             }
+            if ident == "arc_clone" {
+                input.parse::<Token![,]>()?;
+                input.parse::<Ident>()?;
+                return Ok(Some(CloneMode::ArcClone));
+            }
         }
 
         Ok(None)
@@ -126,7 +309,7 @@ fn parse_clone_mode_ident(input: ParseStream) -> Result<Option<CloneMode>> {
         return Err(Error::new(
             input.span(),
             "Expected clone mode after `,`.\n\
-             Valid options: `cloned`, `copy`, `move`",
+             Valid options: `cloned`, `copy`, `move`, `arc_clone`",
         ));
     }
 
@@ -134,13 +317,42 @@ fn parse_clone_mode_ident(input: ParseStream) -> Result<Option<CloneMode>> {
     match ident.to_string().as_str() {
         "cloned" => Ok(Some(CloneMode::Cloned)),
         "copy" => Ok(Some(CloneMode::Copy)),
+        "arc_clone" => Ok(Some(CloneMode::ArcClone)),
         _ => Err(Error::new_spanned(
             ident,
-            "Expected clone mode: `cloned`, `copy`, or `move`",
+            "Expected clone mode: `cloned`, `copy`, `move`, or `arc_clone`",
         )),
     }
 }
 
+/// Map identity fields from `<prefix><name>` in the source
+/// (`strip_prefix = "db_"`).
+///
+/// Fields with their own mapping, and positional fields, are left alone.
+///
+/// # Errors
+/// Returns an error if a prefixed name is not a valid identifier.
+pub fn apply_strip_prefix(fields: &mut [FieldMapping], prefix: &LitStr) -> Result<()> {
+    for field in fields {
+        let Member::Named(name) = &field.target_field else {
+            continue;
+        };
+        let source = &mut field.source;
+        if source.field_name.is_some() || !source.transform.is_identity() {
+            continue;
+        }
+        let prefixed = format!("{}{}", prefix.value(), name.unraw());
+        let ident = syn::parse_str::<Ident>(&prefixed).map_err(|_| {
+            Error::new_spanned(
+                prefix,
+                format!("`strip_prefix` makes `{prefixed}`, which is not a valid field name"),
+            )
+        })?;
+        source.field_name = Some(Member::Named(ident));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use quote::quote;
@@ -1,8 +1,8 @@
 //! Unified types for field mapping across all macros.
 
-use proc_macro2::TokenStream;
-use syn::Expr;
-pub use syn::Ident;
+use proc_macro2::{TokenStream, TokenTree};
+pub use syn::Member;
+use syn::{Expr, ext::IdentExt};
 
 /// Clone mode for field access.
 ///
@@ -14,18 +14,69 @@ pub enum CloneMode {
     Auto,
     /// Always clone field accesses
     Cloned,
-    /// Never implicitly clone (move/take ownership)
+    /// Move on the last use; earlier reads of a multi-use field clone, and
+    /// `relate_structs!` evaluates `move` method calls before the fields move
     Move,
     /// Field is Copy - no clone needed even for ref impl
-    /// User is responsible for ensuring the type actually implements Copy
+    /// Direct field reads check the type actually implements Copy
     Copy,
+    /// Like `Auto`, but clones with `Arc::clone(&field)` instead of
+    /// `field.clone()`
+    ArcClone,
+}
+
+/// Which conversion impls to generate: from the owned source, from a
+/// reference, or both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImplSet {
+    /// `From<Source>` and `From<&Source>`
+    #[default]
+    Both,
+    /// Only `From<Source>` (`owned_only`)
+    OwnedOnly,
+    /// Only `From<&Source>` (`ref_only`)
+    RefOnly,
+}
+
+impl ImplSet {
+    /// Whether the owned impl is generated.
+    #[must_use]
+    pub const fn owned(self) -> bool { !matches!(self, Self::RefOnly) }
+
+    /// Whether the ref impl is generated.
+    #[must_use]
+    pub const fn by_ref(self) -> bool { !matches!(self, Self::OwnedOnly) }
+}
+
+/// Smart pointer a field is wrapped in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pointer {
+    /// `Box::new(value)` (`boxed`)
+    Box,
+    /// `Rc::new(value)` (`rc`)
+    Rc,
+    /// `Arc::new(value)` (`arc`)
+    Arc,
+}
+
+impl Pointer {
+    /// Parse a pointer modifier keyword.
+    #[must_use]
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "boxed" => Some(Self::Box),
+            "rc" => Some(Self::Rc),
+            "arc" => Some(Self::Arc),
+            _ => None,
+        }
+    }
 }
 
 /// Describes how to map a single field from source to target.
 #[derive(Debug, Clone)]
 pub struct FieldMapping {
-    /// The target field name
-    pub target_field: Ident,
+    /// The target field: a name, or an index for tuple structs
+    pub target_field: Member,
     /// Where and how to get the value
     pub source:       FieldSource,
 }
@@ -33,12 +84,16 @@ pub struct FieldMapping {
 /// Where a field's value comes from and how to transform it.
 #[derive(Debug, Clone)]
 pub struct FieldSource {
-    /// The source field name (None = same as target for auto-mapping)
-    pub field_name: Option<Ident>,
+    /// The source field (None = same as target for auto-mapping)
+    pub field_name: Option<Member>,
     /// How to transform the value
     pub transform:  Transform,
     /// Field-level clone mode override (None = use struct default)
     pub clone_mode: Option<CloneMode>,
+    /// Inverse transform for bidirectional relations (`reverse = expr`)
+    pub reverse:    Option<Box<Transform>>,
+    /// Adapts a fallible transform's error before `?` (`map_err = closure`)
+    pub map_err:    Option<Expr>,
 }
 
 impl FieldSource {
@@ -49,6 +104,8 @@ impl FieldSource {
             field_name: None,
             transform:  Transform::Identity,
             clone_mode: None,
+            reverse:    None,
+            map_err:    None,
         }
     }
 
@@ -59,6 +116,8 @@ impl FieldSource {
             field_name: None,
             transform,
             clone_mode: None,
+            reverse: None,
+            map_err: None,
         }
     }
 
@@ -69,6 +128,8 @@ impl FieldSource {
             field_name: None,
             transform:  Transform::Default,
             clone_mode: None,
+            reverse:    None,
+            map_err:    None,
         }
     }
 
@@ -79,6 +140,20 @@ impl FieldSource {
             field_name: None,
             transform:  Transform::DefaultExpr(expr),
             clone_mode: None,
+            reverse:    None,
+            map_err:    None,
+        }
+    }
+
+    /// Create a default value source that calls a factory function
+    #[must_use]
+    pub const fn default_fn(path: syn::Path) -> Self {
+        Self {
+            field_name: None,
+            transform:  Transform::DefaultFn(path),
+            clone_mode: None,
+            reverse:    None,
+            map_err:    None,
         }
     }
 
@@ -89,44 +164,264 @@ impl FieldSource {
             field_name: None,
             transform:  Transform::WithExpr(tokens, fallible),
             clone_mode: None,
+            reverse:    None,
+            map_err:    None,
         }
     }
 
+    /// Create a source for the `unwrap_or_default` shorthand.
+    ///
+    /// Same as `with = _.clone().unwrap_or_default()`: the `Option` is cloned
+    /// so the ref impl works for non-`Copy` values too.
+    #[must_use]
+    pub fn unwrap_or_default() -> Self {
+        let placeholder = proc_macro2::Ident::new("_", proc_macro2::Span::call_site());
+        Self::with_expr(
+            quote::quote! { #placeholder.clone().unwrap_or_default() },
+            false,
+        )
+    }
+
+    /// Create a source for the `secs_to_duration` shorthand.
+    ///
+    /// Same as `with = Duration::from_secs(_), reverse = _.as_secs()`: a `u64`
+    /// count of seconds becomes a `std::time::Duration`, and back.
+    #[must_use]
+    pub fn secs_to_duration() -> Self {
+        let placeholder = proc_macro2::Ident::new("_", proc_macro2::Span::call_site());
+        let mut source = Self::with_expr(
+            quote::quote! { ::std::time::Duration::from_secs(#placeholder) },
+            false,
+        );
+        source.reverse = Some(Box::new(Self::duration_to_secs().transform));
+        source
+    }
+
+    /// Create a source for the `duration_to_secs` shorthand.
+    ///
+    /// Same as `with = _.as_secs(), reverse = Duration::from_secs(_)`: the
+    /// inverse of [`FieldSource::secs_to_duration`].
+    #[must_use]
+    pub fn duration_to_secs() -> Self {
+        let placeholder = proc_macro2::Ident::new("_", proc_macro2::Span::call_site());
+        let mut source = Self::with_expr(quote::quote! { #placeholder.as_secs() }, false);
+        source.reverse = Some(Box::new(Transform::WithExpr(
+            quote::quote! { ::std::time::Duration::from_secs(#placeholder) },
+            false,
+        )));
+        source
+    }
+
     /// Get the effective source field name (falls back to target field if None)
     #[must_use]
-    pub fn get_field_name<'a>(&'a self, target: &'a Ident) -> &'a Ident {
+    pub fn get_field_name<'a>(&'a self, target: &'a Member) -> &'a Member {
         self.field_name.as_ref().unwrap_or(target)
     }
 
+    /// Get the source field that a reverse conversion writes back to.
+    ///
+    /// For `with = expr` this is the single source field the expression
+    /// reads (`_` or `.field`); `None` if it reads zero or several fields.
+    #[must_use]
+    pub fn reverse_field(&self, target: &Member) -> Option<Member> {
+        if let Some(field) = &self.field_name {
+            return Some(field.clone());
+        }
+        match &self.transform {
+            Transform::WithExpr(tokens, _) => {
+                let mut fields = Vec::new();
+                collect_source_fields(tokens, target, &mut fields);
+                match fields.as_slice() {
+                    [field] => Some(field.clone()),
+                    _ => None,
+                }
+            }
+            _ => Some(target.clone()),
+        }
+    }
+
+    /// Get the source fields this mapping reads by name: the same-named (or
+    /// renamed) field, plus the `.field` accesses of an expression.
+    #[must_use]
+    pub fn read_fields(&self, target: &Member) -> Vec<Member> {
+        let field = self.get_field_name(target);
+        let mut fields = Vec::new();
+        match &self.transform {
+            Transform::WithExpr(tokens, _) => collect_source_fields(tokens, field, &mut fields),
+            Transform::SkipIf(tokens, _) => {
+                fields.push(field.clone());
+                collect_source_fields(tokens, field, &mut fields);
+            }
+            Transform::FallbackField(fallback) => {
+                fields.extend([field.clone(), fallback.clone()]);
+            }
+            Transform::Getter(_) => {}
+            transform if transform.is_default_kind() => {}
+            _ => fields.push(field.clone()),
+        }
+        fields
+    }
+
     /// Check if this source reads from a field (not a
-    /// `default`/`skip`/`from_expr`)
+    /// `default`/`skip`/`from_expr`, a `getter` calling a method, or a
+    /// `with = expr` that never touches the source, like `Uuid::new_v4()`)
     #[must_use]
-    pub const fn reads_field(&self) -> bool { !self.transform.is_default_kind() }
+    pub fn reads_field(&self) -> bool {
+        match &self.transform {
+            Transform::Getter(_) => false,
+            Transform::WithExpr(tokens, _) => with_expr_reads_source(tokens),
+            transform => !transform.is_default_kind(),
+        }
+    }
 
     /// Get a usage key for tracking field usage.
     /// For WithExpr, this is the normalized token stream (with `_` replaced).
-    /// For other transforms, this is the source field name.
+    /// For other transforms, including collection maps, this is the source
+    /// field name.
     #[must_use]
-    pub fn get_usage_key(&self, target: &Ident) -> String {
+    pub fn get_usage_key(&self, target: &Member) -> String {
         match &self.transform {
             Transform::WithExpr(tokens, _) => {
-                // Normalize the token stream by replacing `_` with field name
-                let normalized = replace_underscore_in_tokens(tokens, target);
-                // Normalize the string: remove whitespace and leading dots
-                // so `.name` and `name` produce the same key
-                let key = normalized.to_string().replace(' ', "");
-                key.trim_start_matches('.').to_string()
-            }
-            Transform::CollectionMap(tokens) => {
-                // Collection maps also use a path-based key
-                tokens.to_string()
+                with_expr_usage_key(tokens, self.get_field_name(target))
             }
             _ => {
                 // For other transforms, use the source field name
-                self.get_field_name(target).to_string()
+                member_name(self.get_field_name(target))
             }
         }
     }
+
+    /// Get the usage keys of every source field read.
+    ///
+    /// A tuple of fields (`with = (.x, .y)`) reads each element separately,
+    /// and `default_if_none` reads its fallback field too. Any other
+    /// `with = expr` also counts as a read of each field it accesses
+    /// (`.items.len()` reads `items`), so a field moved elsewhere is cloned
+    /// first. Anything else has the single key from `get_usage_key`.
+    #[must_use]
+    pub fn get_usage_keys(&self, target: &Member) -> Vec<String> {
+        match &self.transform {
+            Transform::WithExpr(tokens, _) => {
+                let field = self.get_field_name(target);
+                if let Some(elements) = field_tuple_elements(tokens) {
+                    return elements
+                        .iter()
+                        .map(|e| with_expr_usage_key(e, field))
+                        .collect();
+                }
+                let mut keys = vec![self.get_usage_key(target)];
+                let mut fields = Vec::new();
+                collect_source_fields(tokens, field, &mut fields);
+                for name in fields.iter().map(member_name) {
+                    if !keys.contains(&name) {
+                        keys.push(name);
+                    }
+                }
+                return keys;
+            }
+            Transform::FallbackField(fallback) => {
+                return vec![self.get_usage_key(target), member_name(fallback)];
+            }
+            _ => {}
+        }
+        vec![self.get_usage_key(target)]
+    }
+}
+
+/// Get the usage key of a `with = expr`: the expression with `_` replaced by
+/// the field name, without whitespace or a leading dot, so `.name` and `name`
+/// produce the same key.
+#[must_use]
+pub fn with_expr_usage_key(tokens: &TokenStream, field: &Member) -> String {
+    let normalized = unraw_tokens(&replace_underscore_in_tokens(tokens, field));
+    let key = normalized.to_string().replace(' ', "");
+    key.trim_start_matches('.').to_string()
+}
+
+/// Strip the `r#` from raw identifiers, so `.r#type` keys the same as a
+/// `r#type` field (whose key comes from `member_name`).
+fn unraw_tokens(tokens: &TokenStream) -> TokenStream {
+    use proc_macro2::TokenTree;
+
+    tokens
+        .clone()
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(ident) => TokenTree::Ident(ident.unraw()),
+            TokenTree::Group(group) => TokenTree::Group(proc_macro2::Group::new(
+                group.delimiter(),
+                unraw_tokens(&group.stream()),
+            )),
+            other => other,
+        })
+        .collect()
+}
+
+/// Split a tuple of source fields, `(.x, .y)` or `(_, .nested.z)`, into its
+/// elements.
+///
+/// Returns `None` unless every element is a plain field path, so the elements
+/// can be cloned one by one instead of as a whole.
+#[must_use]
+pub fn field_tuple_elements(tokens: &TokenStream) -> Option<Vec<TokenStream>> {
+    use proc_macro2::{Delimiter, TokenTree};
+
+    let mut trees = tokens.clone().into_iter();
+    let (Some(TokenTree::Group(group)), None) = (trees.next(), trees.next()) else {
+        return None;
+    };
+    if group.delimiter() != Delimiter::Parenthesis {
+        return None;
+    }
+
+    let mut elements = vec![Vec::new()];
+    let mut has_comma = false;
+    for tt in group.stream() {
+        match &tt {
+            TokenTree::Punct(p) if p.as_char() == ',' => {
+                has_comma = true;
+                elements.push(Vec::new());
+            }
+            _ => elements.last_mut()?.push(tt),
+        }
+    }
+    // A trailing comma leaves an empty last element: `(.x,)`
+    if elements.last().is_some_and(Vec::is_empty) {
+        elements.pop();
+    }
+    if !has_comma || !elements.iter().all(|e| is_field_path(e)) {
+        return None;
+    }
+    Some(elements.into_iter().map(TokenStream::from_iter).collect())
+}
+
+/// Check if tokens are a plain field path: `_`, `.field`, or either followed
+/// by `.nested` / `.0` members.
+fn is_field_path(tokens: &[proc_macro2::TokenTree]) -> bool {
+    use proc_macro2::TokenTree;
+
+    // `_` is the same-named field; anything after it is `.member`s
+    let members = match tokens {
+        [TokenTree::Ident(ident), rest @ ..] if ident == "_" => rest,
+        [] => return false,
+        _ => tokens,
+    };
+    members.chunks(2).all(|pair| match pair {
+        [TokenTree::Punct(dot), TokenTree::Ident(_)] => dot.as_char() == '.',
+        [TokenTree::Punct(dot), TokenTree::Literal(lit)] => {
+            dot.as_char() == '.' && lit.to_string().parse::<u32>().is_ok()
+        }
+        _ => false,
+    })
+}
+
+/// Get a field's name as a string: the unraw identifier, or the tuple index.
+#[must_use]
+pub fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.unraw().to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
 }
 
 /// Replace `_` with the field name in a token stream, handling `.` context.
@@ -149,24 +444,26 @@ impl FieldSource {
 ///
 /// * `tokens` - The token stream containing `_` placeholders
 /// * `field` - The field name to replace `_` with
-pub fn replace_underscore_in_tokens(tokens: &TokenStream, field: &Ident) -> TokenStream {
+pub fn replace_underscore_in_tokens(tokens: &TokenStream, field: &Member) -> TokenStream {
     use proc_macro2::TokenTree;
     use quote::quote;
 
     let tokens_vec: Vec<_> = tokens.clone().into_iter().collect();
+    let in_generics = generic_arg_mask(&tokens_vec);
     let mut result = Vec::new();
 
     for (i, tt) in tokens_vec.iter().enumerate() {
         match tt {
+            _ if in_generics[i] => result.push(tt.clone()),
             TokenTree::Ident(ident) if ident == "_" => {
                 let preceded_by_dot = i > 0
                     && matches!(&tokens_vec[i - 1], TokenTree::Punct(p) if p.as_char() == '.');
 
                 if preceded_by_dot {
-                    result.push(TokenTree::Ident(field.clone()));
+                    result.extend(quote! { #field });
                 } else {
                     // Insert `.field` to normalize
-                    result.extend(quote! { .#field }.into_iter());
+                    result.extend(quote! { .#field });
                 }
             }
             TokenTree::Group(group) => {
@@ -231,6 +528,93 @@ fn is_keyword(ident: &proc_macro2::Ident) -> bool {
     )
 }
 
+/// Mark the tokens inside generic arguments: a turbofish (`::<Vec<_>>`), a
+/// qualified path (`<T as Trait>::`) or a type after `as` / `let x:`. A `_`
+/// there is an inferred type, not the same-named field, and is left alone.
+///
+/// A `<` after a value (`a < b`) is a comparison, and `<<` / `<=` are
+/// operators; `->` doesn't close.
+#[must_use]
+pub fn generic_arg_mask(tokens: &[proc_macro2::TokenTree]) -> Vec<bool> {
+    use proc_macro2::{Spacing, TokenTree};
+
+    let mut depth = 0usize;
+    let mut mask = Vec::with_capacity(tokens.len());
+    for (i, tt) in tokens.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|prev| &tokens[prev]);
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '<' => {
+                // `<<`, `<<=` and `<=`
+                let operator = (p.spacing() == Spacing::Joint
+                    && matches!(tokens.get(i + 1), Some(TokenTree::Punct(next))
+                        if matches!(next.as_char(), '<' | '=')))
+                    || matches!(prev, Some(TokenTree::Punct(prev))
+                        if prev.as_char() == '<' && prev.spacing() == Spacing::Joint);
+                // `::<`, a path in type position, or the start of an
+                // expression (`<T as Trait>::`, `x - <i64>::from(..)`)
+                let opens = match prev {
+                    None => true,
+                    Some(TokenTree::Punct(_)) if is_path_sep(tokens, i - 1) => true,
+                    Some(TokenTree::Punct(prev)) => !operator && prev.as_char() != '?',
+                    Some(TokenTree::Ident(prev)) => {
+                        is_keyword(prev) || is_type_path_end(tokens, i - 1)
+                    }
+                    Some(_) => false,
+                };
+                if depth > 0 || opens {
+                    depth += 1;
+                }
+                mask.push(depth > 0);
+            }
+            TokenTree::Punct(p) if p.as_char() == '>' && depth > 0 => {
+                mask.push(true);
+                let arrow = matches!(prev, Some(TokenTree::Punct(prev))
+                    if prev.as_char() == '-' && prev.spacing() == Spacing::Joint);
+                if !arrow {
+                    depth -= 1;
+                }
+            }
+            _ => mask.push(depth > 0),
+        }
+    }
+    mask
+}
+
+/// Check whether the `:` at `idx` ends a `::` path separator.
+fn is_path_sep(tokens: &[proc_macro2::TokenTree], idx: usize) -> bool {
+    use proc_macro2::{Spacing, TokenTree};
+
+    matches!(&tokens[idx], TokenTree::Punct(p) if p.as_char() == ':')
+        && idx > 0
+        && matches!(&tokens[idx - 1], TokenTree::Punct(p)
+            if p.as_char() == ':' && p.spacing() == Spacing::Joint)
+}
+
+/// Check whether the identifier at `idx` ends a path in type position: after
+/// `as` (`x as Vec<_>`) or a `let` binding's `:` (`let v: Vec<_>`).
+fn is_type_path_end(tokens: &[proc_macro2::TokenTree], idx: usize) -> bool {
+    use proc_macro2::TokenTree;
+
+    let is_ident = |i: usize, name: Option<&str>| matches!(&tokens[i], TokenTree::Ident(ident) if name.is_none_or(|name| ident == name));
+    // Walk back over the leading `a::b::` segments
+    let mut start = idx;
+    while start >= 3 && is_path_sep(tokens, start - 1) && is_ident(start - 3, None) {
+        start -= 3;
+    }
+    if start >= 1 && is_ident(start - 1, Some("as")) {
+        return true;
+    }
+    let single_colon = start >= 1
+        && matches!(&tokens[start - 1], TokenTree::Punct(p) if p.as_char() == ':')
+        && !is_path_sep(tokens, start - 1);
+    // `let v:` / `let mut v:`
+    single_colon
+        && start >= 3
+        && is_ident(start - 2, None)
+        && (is_ident(start - 3, Some("let"))
+            || (is_ident(start - 3, Some("mut")) && start >= 4 && is_ident(start - 4, Some("let"))))
+}
+
 /// Check if a token at the given index is preceded by a "base" expression.
 /// A `.` is a source-access dot if it's NOT preceded by:
 /// - A non-keyword identifier (like `foo.bar`)
@@ -246,23 +630,163 @@ fn is_preceded_by_base(tokens: &[proc_macro2::TokenTree], idx: usize) -> bool {
         TokenTree::Ident(ident) => !is_keyword(ident), // Keywords aren't bases
         TokenTree::Group(_) => true,                   // Groups end with implicit closing bracket
         TokenTree::Punct(p) => matches!(p.as_char(), ')' | ']' | '}' | '?'),
-        TokenTree::Literal(_) => false, // Literals like 2.5 are single tokens
+        TokenTree::Literal(_) => true, // A method on a literal: `"anon".to_string()`
+    }
+}
+
+/// Collect the distinct source fields a `with = expr` reads: `_` (the
+/// same-named field) and source-access `.ident` / `.0`.
+fn collect_source_fields(tokens: &TokenStream, field: &Member, fields: &mut Vec<Member>) {
+    use proc_macro2::TokenTree;
+    fn push(fields: &mut Vec<Member>, member: Member) {
+        if !fields.contains(&member) {
+            fields.push(member);
+        }
+    }
+    let tokens_vec: Vec<_> = tokens.clone().into_iter().collect();
+    let in_generics = generic_arg_mask(&tokens_vec);
+    for (i, tt) in tokens_vec.iter().enumerate() {
+        match tt {
+            _ if in_generics[i] => {}
+            TokenTree::Ident(ident) if ident == "_" => push(fields, field.clone()),
+            TokenTree::Punct(p) if p.as_char() == '.' && !is_preceded_by_base(&tokens_vec, i) => {
+                match tokens_vec.get(i + 1) {
+                    // `._` is handled when the `_` itself is visited
+                    Some(TokenTree::Ident(ident)) if ident != "_" => {
+                        push(fields, Member::Named(ident.clone()));
+                    }
+                    Some(TokenTree::Literal(lit)) => {
+                        if let Ok(index) = lit.to_string().parse::<u32>() {
+                            push(
+                                fields,
+                                Member::Unnamed(syn::Index {
+                                    index,
+                                    span: lit.span(),
+                                }),
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            TokenTree::Group(group) => collect_source_fields(&group.stream(), field, fields),
+            _ => {}
+        }
     }
 }
 
+/// Check if a `with = expr` reads the source at all: `_`, a source-access
+/// `.field`, or `$src`. One that doesn't is a constant, like a default.
+#[must_use]
+pub fn with_expr_reads_source(tokens: &TokenStream) -> bool {
+    // Any member stands in for the field `_` reads
+    let placeholder = Member::Unnamed(0.into());
+    let mut fields = Vec::new();
+    collect_source_fields(tokens, &placeholder, &mut fields);
+    !fields.is_empty() || contains_source_ref(tokens)
+}
+
+/// Get the target field named by a `@field` reference at `idx`.
+///
+/// `@` preceded by an identifier is a pattern binding (`x @ Some(_)`), not a
+/// reference.
+fn target_ref_at(tokens: &[proc_macro2::TokenTree], idx: usize) -> Option<&proc_macro2::Ident> {
+    use proc_macro2::TokenTree;
+
+    let is_at = matches!(&tokens[idx], TokenTree::Punct(p) if p.as_char() == '@');
+    let after_ident = idx > 0 && matches!(&tokens[idx - 1], TokenTree::Ident(_));
+    match tokens.get(idx + 1) {
+        Some(TokenTree::Ident(ident)) if is_at && !after_ident => Some(ident),
+        _ => None,
+    }
+}
+
+/// Check whether `$src` (the whole source) starts at `idx`.
+fn source_ref_at(tokens: &[proc_macro2::TokenTree], idx: usize) -> bool {
+    use proc_macro2::TokenTree;
+
+    matches!(&tokens[idx], TokenTree::Punct(p) if p.as_char() == '$')
+        && matches!(tokens.get(idx + 1), Some(TokenTree::Ident(ident)) if ident == "src")
+}
+
+/// Check whether a `with = expr` references the whole source as `$src`.
+#[must_use]
+pub fn contains_source_ref(tokens: &TokenStream) -> bool {
+    use proc_macro2::TokenTree;
+
+    let tokens_vec: Vec<_> = tokens.clone().into_iter().collect();
+    (0..tokens_vec.len()).any(|i| {
+        source_ref_at(&tokens_vec, i)
+            || matches!(&tokens_vec[i], TokenTree::Group(g) if contains_source_ref(&g.stream()))
+    })
+}
+
+/// Collect the target fields a `with = expr` references as `@field`.
+pub fn collect_target_refs(tokens: &TokenStream, refs: &mut Vec<proc_macro2::Ident>) {
+    use proc_macro2::TokenTree;
+
+    let tokens_vec: Vec<_> = tokens.clone().into_iter().collect();
+    for (i, tt) in tokens_vec.iter().enumerate() {
+        if let Some(ident) = target_ref_at(&tokens_vec, i) {
+            refs.push(ident.clone());
+        } else if let TokenTree::Group(group) = tt {
+            collect_target_refs(&group.stream(), refs);
+        }
+    }
+}
+
+/// The binding holding a target field's value, for `@field` references.
+#[must_use]
+pub fn target_ref_binding(field: &Member) -> proc_macro2::Ident {
+    // Use mixed_site for hygiene
+    proc_macro2::Ident::new(
+        &format!("__target_{}", member_name(field)),
+        proc_macro2::Span::mixed_site(),
+    )
+}
+
+/// The binding holding the `base = expr` value.
+#[must_use]
+pub fn base_binding() -> proc_macro2::Ident {
+    proc_macro2::Ident::new("__relate_base", proc_macro2::Span::mixed_site())
+}
+
 /// Transform `with = expr` tokens:
 /// - Replace `_` with `src.<field>`
-/// - Insert `src` before source-access `.ident` patterns
+/// - Insert `src` before source-access `.ident` (or tuple index `.0`) patterns
+/// - Replace `@field` with the binding of an earlier-computed target field
+/// - Replace `$src` with `(&src)`, a reference to the whole source in both the
+///   owned and ref impls
 ///
 /// A `.ident` is source-access if not preceded by an identifier, group, or `?`.
-pub fn transform_with_expr_tokens(tokens: &TokenStream, field: &Ident) -> TokenStream {
+pub fn transform_with_expr_tokens(tokens: &TokenStream, field: &Member) -> TokenStream {
     use proc_macro2::TokenTree;
     use quote::quote;
 
     let tokens_vec: Vec<_> = tokens.clone().into_iter().collect();
+    let in_generics = generic_arg_mask(&tokens_vec);
     let mut result = Vec::new();
+    let mut skip_next = false;
 
     for (i, tt) in tokens_vec.iter().enumerate() {
+        if std::mem::take(&mut skip_next) {
+            continue;
+        }
+        if in_generics[i] {
+            result.push(tt.clone());
+            continue;
+        }
+        if let Some(ident) = target_ref_at(&tokens_vec, i) {
+            let binding = target_ref_binding(&Member::Named(ident.clone()));
+            result.extend(quote! { #binding });
+            skip_next = true;
+            continue;
+        }
+        if source_ref_at(&tokens_vec, i) {
+            result.extend(quote! { (&src) });
+            skip_next = true;
+            continue;
+        }
         match tt {
             // Handle underscore → src.field
             TokenTree::Ident(ident) if ident == "_" => {
@@ -271,19 +795,23 @@ pub fn transform_with_expr_tokens(tokens: &TokenStream, field: &Ident) -> TokenS
 
                 if preceded_by_dot {
                     // `._` → just insert field name (src was already added before the dot)
-                    result.push(TokenTree::Ident(field.clone()));
+                    result.extend(quote! { #field });
                 } else {
                     // Standalone `_` → `src.field`
-                    result.extend(quote! { src.#field }.into_iter());
+                    result.extend(quote! { src.#field });
                 }
             }
-            // Handle source-access .ident → src.ident
+            // Handle source-access .ident (or tuple index .0) → src.ident
             TokenTree::Punct(p) if p.as_char() == '.' => {
-                let next_is_ident = matches!(tokens_vec.get(i + 1), Some(TokenTree::Ident(_)));
-                let is_source_access = next_is_ident && !is_preceded_by_base(&tokens_vec, i);
+                let next_is_member = match tokens_vec.get(i + 1) {
+                    Some(TokenTree::Ident(_)) => true,
+                    Some(TokenTree::Literal(lit)) => lit.to_string().parse::<u32>().is_ok(),
+                    _ => false,
+                };
+                let is_source_access = next_is_member && !is_preceded_by_base(&tokens_vec, i);
 
                 if is_source_access {
-                    result.extend(quote! { src }.into_iter());
+                    result.extend(quote! { src });
                 }
                 result.push(tt.clone());
             }
@@ -302,6 +830,143 @@ pub fn transform_with_expr_tokens(tokens: &TokenStream, field: &Ident) -> TokenS
     result.into_iter().collect()
 }
 
+/// `Option` and `Result` adapters that take `self` by value.
+const CONSUMING_ADAPTERS: &[&str] = &[
+    "ok",
+    "err",
+    "map",
+    "map_err",
+    "map_or",
+    "map_or_else",
+    "and_then",
+    "or_else",
+    "and",
+    "or",
+    "xor",
+    "ok_or",
+    "ok_or_else",
+    "filter",
+    "flatten",
+    "zip",
+    "unwrap",
+    "expect",
+    "unwrap_err",
+    "expect_err",
+    "unwrap_or",
+    "unwrap_or_else",
+    "unwrap_or_default",
+];
+
+/// Insert `.clone()` after each source field an `Option` or `Result` adapter
+/// consumes (`_.ok()`, `.score.unwrap_or_else(..)`), for conversions that
+/// can't move the field: the ref impl, or an owned impl that reads it again.
+///
+/// Works on `with = expr` tokens before `transform_with_expr_tokens`. A nested
+/// access (`.meta.status.ok()`) is cloned at the end of the path.
+#[must_use]
+pub fn clone_consumed_receivers(tokens: &TokenStream) -> TokenStream {
+    clone_source_paths(tokens, |tokens, _, end| consumed_by_adapter(tokens, end))
+}
+
+/// Insert `.clone()` after each source field a `with = expr` reads by value
+/// (`Pair::new(.a, .b)`, `vec![_]`) or hands to a consuming adapter, for
+/// fields marked `cloned`. Borrowed reads (`&.a`, `.a.len()`) stay as is.
+#[must_use]
+pub fn clone_field_reads(tokens: &TokenStream) -> TokenStream {
+    clone_source_paths(tokens, |tokens, start, end| {
+        let borrowed =
+            start > 0 && matches!(&tokens[start - 1], TokenTree::Punct(p) if p.as_char() == '&');
+        let continues = is_dot(tokens.get(end));
+        (!borrowed && !continues) || consumed_by_adapter(tokens, end)
+    })
+}
+
+fn is_dot(tt: Option<&TokenTree>) -> bool {
+    matches!(tt, Some(TokenTree::Punct(p)) if p.as_char() == '.')
+}
+
+fn is_member(tt: Option<&TokenTree>) -> bool {
+    match tt {
+        Some(TokenTree::Ident(_)) => true,
+        Some(TokenTree::Literal(lit)) => lit.to_string().parse::<u32>().is_ok(),
+        _ => false,
+    }
+}
+
+fn is_call(tt: Option<&TokenTree>) -> bool {
+    matches!(tt, Some(TokenTree::Group(g)) if g.delimiter() == proc_macro2::Delimiter::Parenthesis)
+}
+
+/// Check if the source path ending at `end` is the receiver of a consuming
+/// `Option` / `Result` adapter call.
+fn consumed_by_adapter(tokens: &[TokenTree], end: usize) -> bool {
+    is_dot(tokens.get(end))
+        && matches!(tokens.get(end + 1), Some(TokenTree::Ident(method))
+            if CONSUMING_ADAPTERS.contains(&method.to_string().as_str()))
+        && is_call(tokens.get(end + 2))
+}
+
+/// Insert `.clone()` after each source path (`_`, `.field`, `.a.b`) in
+/// `with = expr` tokens for which `clone` holds, given the tokens and the
+/// path's start and end indices.
+fn clone_source_paths(
+    tokens: &TokenStream,
+    clone: impl Fn(&[TokenTree], usize, usize) -> bool + Copy,
+) -> TokenStream {
+    use quote::quote;
+
+    let tokens_vec: Vec<_> = tokens.clone().into_iter().collect();
+    let in_generics = generic_arg_mask(&tokens_vec);
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < tokens_vec.len() {
+        let after_dot = i > 0 && is_dot(tokens_vec.get(i - 1));
+        let path_start = match &tokens_vec[i] {
+            _ if in_generics[i] => None,
+            TokenTree::Ident(ident) if ident == "_" && !after_dot => Some(i + 1),
+            TokenTree::Punct(p)
+                if p.as_char() == '.'
+                    && is_member(tokens_vec.get(i + 1))
+                    && !is_preceded_by_base(&tokens_vec, i) =>
+            {
+                Some(i + 2)
+            }
+            _ => None,
+        };
+        let Some(mut end) = path_start else {
+            match &tokens_vec[i] {
+                TokenTree::Group(group) => {
+                    let mut new_group = proc_macro2::Group::new(
+                        group.delimiter(),
+                        clone_source_paths(&group.stream(), clone),
+                    );
+                    new_group.set_span(group.span());
+                    result.push(TokenTree::Group(new_group));
+                }
+                other => result.push(other.clone()),
+            }
+            i += 1;
+            continue;
+        };
+
+        // Follow nested field accesses to the end of the path
+        while is_dot(tokens_vec.get(end))
+            && is_member(tokens_vec.get(end + 1))
+            && !is_call(tokens_vec.get(end + 2))
+        {
+            end += 2;
+        }
+        result.extend(tokens_vec[i..end].iter().cloned());
+        if clone(&tokens_vec, i, end) {
+            result.extend(quote! { .clone() });
+        }
+        i = end;
+    }
+
+    result.into_iter().collect()
+}
+
 /// How to transform a field value.
 #[derive(Debug, Clone)]
 pub enum Transform {
@@ -313,14 +978,76 @@ pub enum Transform {
     ///
     /// - `_` becomes the same-named source field value
     /// - `.field` becomes `src.field`
+    /// - `$src` becomes a reference to the whole source
     ///
     /// Bool indicates fallibility (trailing `?`).
     /// Syntax: `field: with = expr;`
     WithExpr(TokenStream, bool),
 
-    /// Map over a collection: `[_.id.clone()]`
+    /// Convert the same-named source field with `Into`.
+    ///
+    /// The ref impl converts from a reference (`From<&A>`), so nested
+    /// `relate` types compose without cloning.
+    /// Syntax: `field: into;`
+    Into,
+
+    /// Call a function on the same-named source field: `path(src.field)`,
+    /// or `path(&src.field)` in the ref impl.
+    /// Syntax: `field: with_fn = path;`
+    FnCall(syn::Path),
+
+    /// Call a fallible function on the same-named source field and apply
+    /// `?`: `path(src.field)?`, or `path(&src.field)?` in the ref impl.
+    /// Syntax: `field: try_into = path;`
+    TryFnCall(syn::Path),
+
+    /// Call a getter method on the source instead of reading a field:
+    /// `src.method()` in both impls. The method's return value is used as is,
+    /// so it is never cloned unless the field is `cloned`.
+    /// Syntax: `field: getter = method;`
+    Getter(syn::Ident),
+
+    /// Apply a closure to the same-named source field: `(|x| expr)(src.field)`.
+    /// A closure taking a reference (`|x: &T|` or `|&x|`) gets `&src.field`.
+    /// Syntax: `#[relate(|x| expr)]`
+    Closure(syn::ExprClosure),
+
+    /// Wrap the same-named source field in `Some`, for `T -> Option<T>`.
+    /// Syntax: `field: some;`
+    WrapSome,
+
+    /// Wrap the same-named source field in a smart pointer, for `T -> Box<T>`.
+    /// Syntax: `field: boxed;`, `field: rc;`, `field: arc;`
+    Wrap(Pointer),
+
+    /// Move the value out of a `Box<T>` source field, for `Box<T> -> T`.
+    /// Syntax: `field: unbox;`
+    Unbox,
+
+    /// Turn a `Cow` source field into its owned form, for `Cow<str> -> String`.
+    /// Uses `Cow::into_owned`, or `ToOwned::to_owned` on the borrowed value
+    /// when the field can't be moved.
+    /// Syntax: `field: into_owned;`
+    IntoOwned,
+
+    /// Copy an array or slice source field into a `Vec`, for `[T; N] ->
+    /// Vec<T>`. `to_vec` borrows the field, so it is never cloned first.
+    /// Syntax: `field: vec;`
+    ToVec,
+
+    /// Map over a collection: `[_.id.clone()]`, optionally binding each
+    /// element's position: `[idx, _ => (idx, _.id)]` enumerates the items.
     /// Syntax: `field: with = [_.id];`
-    CollectionMap(TokenStream),
+    CollectionMap(TokenStream, Option<syn::Ident>),
+
+    /// Map over a collection inside an `Option`: `opt[_.id.clone()]` maps the
+    /// items of `Some`, and `None` stays `None`
+    /// Syntax: `field: with = opt[_.id];`
+    OptionCollectionMap(TokenStream, Option<syn::Ident>),
+
+    /// Map over a map's values, keeping keys: `map[_.id.clone()]`
+    /// Syntax: `field: with = map[_.id];`
+    MapValues(TokenStream),
 
     /// Use `Default::default()`
     /// Syntax: `field: default;`
@@ -329,6 +1056,27 @@ pub enum Transform {
     /// Use a specific default expression
     /// Syntax: `field: default = expr;`
     DefaultExpr(Expr),
+
+    /// Call a default factory function: `path()`
+    /// Syntax: `field: default_with = path;`
+    DefaultFn(syn::Path),
+
+    /// Require an `Option` source field to be `Some`, converting the inner
+    /// value with `Into`. Fails with `ConversionError::MissingField` naming
+    /// the target field.
+    /// Syntax: `field: required;`
+    Required,
+
+    /// Conditional default: use the fallback (`Default::default()` when
+    /// `None`) if the predicate holds, otherwise read the same-named field.
+    /// The predicate uses the same `.field` and `_` syntax as `with = expr`.
+    /// Syntax: `field: skip_if = .is_deleted, default = expr;`
+    SkipIf(TokenStream, Option<Expr>),
+
+    /// Read an `Option` source field, falling back to another `Option` field
+    /// when it is `None`: `src.field.or_else(|| src.fallback)`.
+    /// Syntax: `field: default_if_none = .legacy_field;`
+    FallbackField(Member),
 }
 
 impl Transform {
@@ -337,7 +1085,6 @@ impl Transform {
     /// Identity transforms are the simplest: they just copy/move the field
     /// value without any modification.
     #[must_use]
-    #[allow(dead_code)]
     pub const fn is_identity(&self) -> bool { matches!(self, Self::Identity) }
 
     /// Check if this transform is a "default" type (doesn't read from source
@@ -347,43 +1094,107 @@ impl Transform {
     /// and don't read from any source field.
     #[must_use]
     pub const fn is_default_kind(&self) -> bool {
-        matches!(self, Self::Default | Self::DefaultExpr(_))
+        matches!(
+            self,
+            Self::Default | Self::DefaultExpr(_) | Self::DefaultFn(_)
+        )
     }
 
     /// Check if this transform contains fallible expressions (with `?`).
     ///
     /// A transform is fallible if it may fail at runtime, indicated by
-    /// a trailing `?` operator in the expression.
+    /// a `?` operator anywhere in the expression. For `WithExpr` the flag
+    /// records a stripped trailing `?`; a mid-expression `?` such as
+    /// `_.parse::<i32>()?.to_string()` stays in the tokens.
     #[must_use]
     pub fn is_fallible(&self) -> bool {
         match self {
-            Self::WithExpr(_, fallible) => *fallible,
-            Self::CollectionMap(tokens) => tokens_contain_question_mark(tokens),
+            Self::WithExpr(tokens, fallible) => *fallible || tokens_contain_question_mark(tokens),
+            Self::CollectionMap(tokens, _)
+            | Self::OptionCollectionMap(tokens, _)
+            | Self::MapValues(tokens) => tokens_contain_question_mark(tokens),
+            Self::Required | Self::TryFnCall(_) => true,
             _ => false,
         }
     }
 
     /// Get the token stream for transforms that contain tokens.
     ///
-    /// Returns `Some(&TokenStream)` for `WithExpr` and `CollectionMap`,
-    /// `None` for other variants.
+    /// Returns `Some(&TokenStream)` for `WithExpr`, `CollectionMap`,
+    /// `OptionCollectionMap` and `MapValues`, `None` for other variants.
     #[must_use]
     #[allow(dead_code)]
     pub fn tokens(&self) -> Option<&TokenStream> {
         match self {
-            Self::WithExpr(tokens, _) | Self::CollectionMap(tokens) => Some(tokens),
+            Self::WithExpr(tokens, _)
+            | Self::CollectionMap(tokens, _)
+            | Self::OptionCollectionMap(tokens, _)
+            | Self::MapValues(tokens) => Some(tokens),
             _ => None,
         }
     }
 }
 
 /// Check if tokens contain a `?` operator.
+///
+/// A `?` inside a closure body (`|s| s.parse().ok()?`) or an `async` block
+/// returns from that closure or block, not the conversion, so it doesn't
+/// count.
 #[must_use]
 pub fn tokens_contain_question_mark(tokens: &TokenStream) -> bool {
+    use proc_macro2::{Delimiter, TokenTree};
+
+    let tokens_vec: Vec<_> = tokens.clone().into_iter().collect();
+    let mut i = 0;
+    while i < tokens_vec.len() {
+        match &tokens_vec[i] {
+            TokenTree::Punct(p) if p.as_char() == '?' => return true,
+            TokenTree::Punct(p) if p.as_char() == '|' && !is_preceded_by_value(&tokens_vec, i) => {
+                // Skip the closure head, then its body up to the next `,` / `;`
+                i += 1;
+                while i < tokens_vec.len()
+                    && !matches!(&tokens_vec[i], TokenTree::Punct(p) if p.as_char() == '|')
+                {
+                    i += 1;
+                }
+                while i < tokens_vec.len()
+                    && !matches!(&tokens_vec[i], TokenTree::Punct(p) if matches!(p.as_char(), ',' | ';'))
+                {
+                    i += 1;
+                }
+                continue;
+            }
+            TokenTree::Ident(ident) if ident == "async" => {
+                // Skip `async { .. }` / `async move { .. }`
+                i += 1;
+                if matches!(tokens_vec.get(i), Some(TokenTree::Ident(ident)) if ident == "move") {
+                    i += 1;
+                }
+                if matches!(tokens_vec.get(i), Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Brace)
+                {
+                    i += 1;
+                }
+                continue;
+            }
+            TokenTree::Group(g) if tokens_contain_question_mark(&g.stream()) => return true,
+            _ => {}
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Check whether a value ends right before `idx`, making a `|` there the
+/// bitwise/logical or rather than a closure head.
+fn is_preceded_by_value(tokens: &[proc_macro2::TokenTree], idx: usize) -> bool {
     use proc_macro2::TokenTree;
-    tokens.clone().into_iter().any(|tt| match tt {
-        TokenTree::Punct(p) => p.as_char() == '?',
-        TokenTree::Group(g) => tokens_contain_question_mark(&g.stream()),
-        _ => false,
-    })
+
+    match idx.checked_sub(1).map(|prev| &tokens[prev]) {
+        None => false,
+        Some(TokenTree::Ident(ident)) => {
+            !is_keyword(ident) || matches!(ident.to_string().as_str(), "self" | "true" | "false")
+        }
+        Some(TokenTree::Group(_) | TokenTree::Literal(_)) => true,
+        Some(TokenTree::Punct(p)) => p.as_char() == '?',
+    }
 }
@@ -2,15 +2,20 @@
 
 use std::collections::{HashMap, HashSet};
 
-use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote};
-use syn::{Expr, Ident};
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{Expr, Ident, Member, PathArguments, Type, ext::IdentExt};
 
-use super::types::{CloneMode, ConversionMode, FromDeriveInput};
+use super::types::{
+    CloneMode, ConversionMode, DeriveBody, FromDeriveInput, VariantFields, VariantMapping,
+};
 use crate::core::{
-    FieldMapping, FieldUsage, ReverseStrategy, Transform, count_field_usage,
-    count_reverse_field_usage, generate_field_init, generate_reverse_field_init,
-    tokens_contain_call,
+    FieldMapping, FieldUsage, Transform, apply_map_err, base_binding, borrowed_lifetime,
+    count_field_usage, count_reverse_field_usage, error_type_tokens, field_tuple_elements,
+    field_tuple_value, generate_field_value, generate_reverse_field_init,
+    generate_reverse_field_value, localize_question_mark, member_name, roundtrip_check_fn,
+    source_param, split_trailing_question_mark, target_ref_binding, target_ref_order,
+    to_snake_case, tokens_compute_value, tokens_contain_call, tuple_element_clones,
 };
 
 /// Tracks default expressions that should be hoisted to let bindings.
@@ -29,14 +34,10 @@ impl DefaultBindings {
         // Count how many times each default expression is used
         // Only count expressions that are safe to hoist (function/method calls)
         for field in fields {
-            let Transform::DefaultExpr(expr) = &field.source.transform else {
+            let Some(call) = Self::hoistable_call(&field.source.transform) else {
                 continue;
             };
-            if !Self::is_hoistable(expr) {
-                continue;
-            }
-            let key = expr.to_token_stream().to_string();
-            *expr_counts.entry(key).or_insert(0) += 1;
+            *expr_counts.entry(call.to_string()).or_insert(0) += 1;
         }
 
         // Create bindings only for expressions used more than once
@@ -57,20 +58,28 @@ impl DefaultBindings {
         Self { bindings }
     }
 
-    /// Check if an expression is safe to hoist to a let binding.
+    /// Get the call a default transform evaluates, if it is safe to hoist to a
+    /// let binding.
     ///
     /// Function and method calls are hoistable because they return concrete
     /// types. Type-polymorphic expressions like `None`, `true`, `false`,
     /// literals are NOT safe because they could have different types for
-    /// different fields.
-    const fn is_hoistable(expr: &Expr) -> bool {
-        matches!(expr, Expr::Call(_) | Expr::MethodCall(_))
+    /// different fields. `default_with = path` is always a call, and shares
+    /// its binding with an identical `default = path()`.
+    fn hoistable_call(transform: &Transform) -> Option<TokenStream> {
+        match transform {
+            Transform::DefaultExpr(expr @ (Expr::Call(_) | Expr::MethodCall(_))) => {
+                Some(expr.to_token_stream())
+            }
+            Transform::DefaultFn(path) => Some(quote! { #path() }),
+            _ => None,
+        }
     }
 
-    /// Get the binding name and usage count for an expression, if it should be
-    /// hoisted.
-    fn get_binding_with_count(&self, expr: &Expr) -> Option<(&Ident, usize)> {
-        let key = expr.to_token_stream().to_string();
+    /// Get the binding name and usage count for a default transform, if it
+    /// should be hoisted.
+    fn get_binding_with_count(&self, transform: &Transform) -> Option<(&Ident, usize)> {
+        let key = Self::hoistable_call(transform)?.to_string();
         self.bindings.get(&key).map(|(name, count)| (name, *count))
     }
 
@@ -81,17 +90,17 @@ impl DefaultBindings {
 
         // Iterate in field order to emit bindings in a predictable order
         for field in fields {
-            let Transform::DefaultExpr(expr) = &field.source.transform else {
+            let Some(call) = Self::hoistable_call(&field.source.transform) else {
                 continue;
             };
-            let key = expr.to_token_stream().to_string();
+            let key = call.to_string();
             let Some((binding_name, _)) = self.bindings.get(&key) else {
                 continue;
             };
             if !seen.insert(key) {
                 continue; // Already emitted this binding
             }
-            bindings.push(quote! { let #binding_name = #expr; });
+            bindings.push(quote! { let #binding_name = #call; });
         }
 
         bindings
@@ -109,15 +118,21 @@ struct WithExprBindings {
 }
 
 impl WithExprBindings {
-    fn new(fields: &[FieldMapping]) -> Self {
+    /// Fields in `target_refs` are bound as `@field` targets instead, and
+    /// expressions that don't read the source are left in place.
+    fn new(fields: &[FieldMapping], target_refs: &[usize]) -> Self {
         let mut bindings = HashMap::new();
 
-        for field in fields {
-            if let Transform::WithExpr(_, _) = &field.source.transform {
-                let field_name = field.target_field.to_string();
-                // Use mixed_site for hygiene
-                let binding_name =
-                    Ident::new(&format!("__with_{}", field_name), Span::mixed_site());
+        for (idx, field) in fields.iter().enumerate() {
+            let reads_source = field.source.reads_field() || target_refs.contains(&idx);
+            if let (Transform::WithExpr(_, _), true) = (&field.source.transform, reads_source) {
+                let field_name = member_name(&field.target_field);
+                let binding_name = if target_refs.contains(&idx) {
+                    target_ref_binding(&field.target_field)
+                } else {
+                    // Use mixed_site for hygiene
+                    Ident::new(&format!("__with_{}", field_name), Span::mixed_site())
+                };
                 bindings.insert(field_name, binding_name);
             }
         }
@@ -128,52 +143,119 @@ impl WithExprBindings {
     /// Get the binding name for a field, if it's a WithExpr field.
     fn get_binding(&self, field_name: &str) -> Option<&Ident> { self.bindings.get(field_name) }
 
-    /// Generate let bindings for all WithExpr fields.
-    /// These must be evaluated BEFORE any fields are moved from src.
+    /// Generate let bindings for all WithExpr fields, except those in
+    /// `target_refs`. These must be evaluated BEFORE any fields are moved
+    /// from src.
     fn generate_let_bindings(
         &self,
         fields: &[FieldMapping],
+        target_refs: &[usize],
         is_ref: bool,
         field_usage: &HashMap<String, FieldUsage>,
+        collect_errors: bool,
     ) -> Vec<TokenStream> {
-        use crate::core::transform_with_expr_tokens;
-
-        let mut bindings = Vec::new();
-
         // Iterate in field order to emit bindings in a predictable order
-        for field in fields {
-            let Transform::WithExpr(tokens, fallible) = &field.source.transform else {
-                continue;
-            };
-            let field_name = field.target_field.to_string();
-            let Some(binding_name) = self.bindings.get(&field_name) else {
-                continue;
-            };
+        fields
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !target_refs.contains(idx))
+            .filter_map(|(_, field)| {
+                self.generate_let_binding(field, is_ref, field_usage, collect_errors)
+            })
+            .collect()
+    }
 
-            let transformed = transform_with_expr_tokens(tokens, &field.target_field);
+    /// Generate the let binding for one WithExpr field.
+    ///
+    /// With `collect_errors`, fallible bindings hold a `Result` instead of
+    /// returning early with `?`.
+    fn generate_let_binding(
+        &self,
+        field: &FieldMapping,
+        is_ref: bool,
+        field_usage: &HashMap<String, FieldUsage>,
+        collect_errors: bool,
+    ) -> Option<TokenStream> {
+        use crate::core::{
+            clone_consumed_receivers, clone_field_reads, transform_with_expr_tokens,
+        };
 
-            // Need to clone if:
-            // 1. ref impl with simple field access (no method calls), OR
-            // 2. owned impl where the source field is used multiple times
-            let is_simple_field = !tokens_contain_call(tokens);
-            let usage_key = field.source.get_usage_key(&field.target_field);
-            let is_multi_use = field_usage.get(&usage_key).is_some_and(|u| u.count > 1);
-            let needs_clone = is_simple_field && (is_ref || is_multi_use);
+        let Transform::WithExpr(tokens, fallible) = &field.source.transform else {
+            return None;
+        };
+        let binding_name = self.bindings.get(&member_name(&field.target_field))?;
 
-            let value = if needs_clone {
-                quote! { (#transformed).clone() }
-            } else {
-                transformed
-            };
-            let value = if *fallible {
-                quote! { #value? }
-            } else {
-                value
-            };
-            bindings.push(quote! { let #binding_name = #value; });
+        // A tuple of fields clones each element on its own. The binding is
+        // evaluated before any field is moved, so no read is the last use.
+        if let (false, Some(elements)) = (*fallible, field_tuple_elements(tokens)) {
+            let source_field = field.source.get_field_name(&field.target_field);
+            let clone_mode = field.source.clone_mode.unwrap_or(CloneMode::Auto);
+            let clones = tuple_element_clones(
+                &elements,
+                source_field,
+                usize::MAX,
+                is_ref,
+                field_usage,
+                clone_mode,
+            );
+            let value = field_tuple_value(&elements, &clones, source_field, clone_mode);
+            return Some(quote! { let #binding_name = #value; });
         }
 
-        bindings
+        let usage_key = field.source.get_usage_key(&field.target_field);
+        let is_multi_use = field_usage.get(&usage_key).is_some_and(|u| u.count > 1);
+        let source_field = field.source.get_field_name(&field.target_field);
+        // `cloned` clones each field the expression reads by value
+        let cloned = field.source.clone_mode == Some(CloneMode::Cloned);
+        let transformed = if cloned {
+            transform_with_expr_tokens(&clone_field_reads(tokens), source_field)
+        } else if is_ref || is_multi_use {
+            transform_with_expr_tokens(&clone_consumed_receivers(tokens), source_field)
+        } else {
+            transform_with_expr_tokens(tokens, source_field)
+        };
+
+        // Need to clone if:
+        // 1. ref impl with simple field access (no method calls), OR
+        // 2. owned impl where the source field is used multiple times
+        // Calls and operators (`_.len()`, `.a + .b`) only borrow or copy the
+        // field, so they never clone.
+        let is_simple_field = !tokens_contain_call(tokens) && !tokens_compute_value(tokens);
+        let needs_clone = is_simple_field && !cloned && (is_ref || is_multi_use);
+
+        let value = if needs_clone {
+            quote! { (#transformed).clone() }
+        } else {
+            transformed
+        };
+        let value = if *fallible {
+            let value = apply_map_err(&quote! { #value? }, field.source.map_err.as_ref());
+            localize_question_mark(&value, &field.target_field)
+        } else {
+            value
+        };
+        let value = if field.source.transform.is_fallible() && collect_errors {
+            catch_error(&value)
+        } else {
+            value
+        };
+        Some(quote! { let #binding_name = #value; })
+    }
+}
+
+/// Evaluate an expression using `?` into a `Result<_, ConversionError>`
+/// instead of returning early.
+fn catch_error(value: &TokenStream) -> TokenStream {
+    // A trailing `?` converts the error directly; any other `?` needs a closure
+    let (inner, trailing) = split_trailing_question_mark(value);
+    if trailing {
+        quote! {
+            (#inner).map_err(<::relate::ConversionError as ::core::convert::From<_>>::from)
+        }
+    } else {
+        quote! {
+            (|| ::core::result::Result::<_, ::relate::ConversionError>::Ok(#value))()
+        }
     }
 }
 
@@ -184,60 +266,216 @@ struct FieldGenerator<'a> {
     field_usage:        HashMap<String, FieldUsage>,
     default_bindings:   DefaultBindings,
     with_expr_bindings: WithExprBindings,
+    /// `collect_errors`: fallible non-`with` fields hoisted into `Result`
+    /// bindings (target field name -> binding name)
+    try_bindings:       Option<HashMap<String, Ident>>,
+    /// Fields involved in `@field` references, in dependency order
+    target_refs:        Vec<usize>,
+    /// Struct update base filling the skipped fields (`base = expr`)
+    base:               Option<&'a Expr>,
+    /// Whether the constructor ends in `..base`
+    fills_rest:         bool,
 }
 
 impl<'a> FieldGenerator<'a> {
     fn new(fields: &'a [FieldMapping], clone_mode: CloneMode) -> Self {
+        // References were validated by the parser
+        let target_refs = target_ref_order(fields).unwrap_or_default();
         Self {
             fields,
             clone_mode,
             field_usage: count_field_usage(fields),
             default_bindings: DefaultBindings::new(fields),
-            with_expr_bindings: WithExprBindings::new(fields),
+            with_expr_bindings: WithExprBindings::new(fields, &target_refs),
+            try_bindings: None,
+            target_refs,
+            base: None,
+            fills_rest: false,
         }
     }
 
-    /// Generate let bindings (WithExpr first, then defaults).
+    /// Bind `base = expr`, and fill the fields left out with `..base` when
+    /// `fills_rest`.
+    const fn with_base(mut self, base: Option<&'a Expr>, fills_rest: bool) -> Self {
+        self.base = base;
+        self.fills_rest = fills_rest;
+        self
+    }
+
+    /// Evaluate every fallible field into a `Result` so all errors can be
+    /// collected (`collect_errors`).
+    fn collecting_errors(mut self) -> Self {
+        let bindings = self
+            .fields
+            .iter()
+            .filter(|f| {
+                f.source.transform.is_fallible()
+                    && !matches!(f.source.transform, Transform::WithExpr(..))
+            })
+            .map(|f| {
+                let field_name = member_name(&f.target_field);
+                // Use mixed_site for hygiene
+                let binding_name = Ident::new(&format!("__try_{}", field_name), Span::mixed_site());
+                (field_name, binding_name)
+            })
+            .collect();
+        self.try_bindings = Some(bindings);
+        self
+    }
+
+    /// Generate let bindings (the base first, then WithExpr, then defaults,
+    /// then `@field` targets, then collected fallible fields).
     fn let_bindings(&self, is_ref: bool) -> Vec<TokenStream> {
-        let mut bindings =
-            self.with_expr_bindings
-                .generate_let_bindings(self.fields, is_ref, &self.field_usage);
+        let mut bindings: Vec<TokenStream> = self
+            .base
+            .map(|base| {
+                let binding = base_binding();
+                quote! { let #binding = #base; }
+            })
+            .into_iter()
+            .collect();
+        bindings.extend(self.with_expr_bindings.generate_let_bindings(
+            self.fields,
+            &self.target_refs,
+            is_ref,
+            &self.field_usage,
+            self.try_bindings.is_some(),
+        ));
         bindings.extend(self.default_bindings.generate_let_bindings(self.fields));
+
+        // `@field` targets, each after the fields it references
+        for &idx in &self.target_refs {
+            let field = &self.fields[idx];
+            if let Some(binding) = self.with_expr_bindings.generate_let_binding(
+                field,
+                is_ref,
+                &self.field_usage,
+                false,
+            ) {
+                bindings.push(binding);
+                continue;
+            }
+            let binding = target_ref_binding(&field.target_field);
+            // `usize::MAX` is never a field's last use, so multi-use reads clone
+            let value = generate_field_value(
+                field,
+                usize::MAX,
+                is_ref,
+                &self.field_usage,
+                self.clone_mode,
+            );
+            bindings.push(quote! { let #binding = #value; });
+        }
+
+        if let Some(try_bindings) = &self.try_bindings {
+            for (idx, field) in self.fields.iter().enumerate() {
+                let Some(binding) = try_bindings.get(&member_name(&field.target_field)) else {
+                    continue;
+                };
+                let value = catch_error(&generate_field_value(
+                    field,
+                    idx,
+                    is_ref,
+                    &self.field_usage,
+                    self.clone_mode,
+                ));
+                bindings.push(quote! { let #binding = #value; });
+            }
+        }
         bindings
     }
 
-    /// Generate field initializers for owned or ref conversion.
-    fn field_inits(&self, is_ref: bool) -> Vec<TokenStream> {
-        self.fields
+    /// Construct `Ok(path { .. })` once every collected fallible field
+    /// succeeded, or `Err(ConversionError::Multiple(..))` with all errors.
+    fn construct_collecting(&self, path: &TokenStream, is_ref: bool) -> TokenStream {
+        let value = self.construct(path, is_ref);
+        let results: Vec<&Ident> = self
+            .fields
+            .iter()
+            .filter(|f| f.source.transform.is_fallible())
+            .filter_map(|f| {
+                let field_name = member_name(&f.target_field);
+                self.with_expr_bindings
+                    .get_binding(&field_name)
+                    .or_else(|| {
+                        self.try_bindings
+                            .as_ref()
+                            .and_then(|bindings| bindings.get(&field_name))
+                    })
+            })
+            .collect();
+
+        if results.is_empty() {
+            return quote! { ::core::result::Result::Ok(#value) };
+        }
+
+        // Each `Ok` shadows its `Result` binding, which `construct` reads
+        quote! {
+            match (#(#results,)*) {
+                (#(::core::result::Result::Ok(#results),)*) => ::core::result::Result::Ok(#value),
+                (#(#results,)*) => ::core::result::Result::Err(::relate::ConversionError::Multiple(
+                    [#(#results.err()),*].into_iter().flatten().collect(),
+                )),
+            }
+        }
+    }
+
+    /// Construct `path { field: value, .. }`, or `path(value, ..)` for tuple
+    /// fields, for owned or ref conversion.
+    fn construct(&self, path: &TokenStream, is_ref: bool) -> TokenStream {
+        let values = self
+            .fields
             .iter()
             .enumerate()
-            .map(|(idx, f)| self.field_init(f, idx, is_ref))
-            .collect()
+            .map(|(idx, f)| self.field_value(f, idx, is_ref));
+
+        if is_tuple(self.fields) {
+            quote! { #path(#(#values),*) }
+        } else {
+            let targets = self.fields.iter().map(|f| &f.target_field);
+            let rest = self.fills_rest.then(|| {
+                let binding = base_binding();
+                quote! { ..#binding }
+            });
+            quote! { #path { #(#targets: #values,)* #rest } }
+        }
     }
 
-    /// Generate a single field init, using hoisted bindings for repeated
-    /// default expressions.
-    fn field_init(&self, mapping: &FieldMapping, field_index: usize, is_ref: bool) -> TokenStream {
+    /// Generate a single field value, using hoisted bindings for `with`
+    /// expressions and repeated default expressions.
+    fn field_value(&self, mapping: &FieldMapping, field_index: usize, is_ref: bool) -> TokenStream {
         let target = &mapping.target_field;
 
         // WithExpr fields might be hoisted - check for binding
-        if let Some(binding) = self.with_expr_bindings.get_binding(&target.to_string()) {
-            return quote! { #target: #binding };
+        if let Some(binding) = self.with_expr_bindings.get_binding(&member_name(target)) {
+            return quote! { #binding };
         }
 
-        // Hoisted default expressions - check if we need to clone the binding
-        let Transform::DefaultExpr(expr) = &mapping.source.transform else {
-            return generate_field_init(
-                mapping,
-                field_index,
-                is_ref,
-                &self.field_usage,
-                self.clone_mode,
-            );
-        };
+        // `@field` targets are hoisted too
+        if self
+            .target_refs
+            .iter()
+            .any(|&idx| self.fields[idx].target_field == *target)
+        {
+            let binding = target_ref_binding(target);
+            return quote! { #binding };
+        }
 
-        let Some((binding, count)) = self.default_bindings.get_binding_with_count(expr) else {
-            return generate_field_init(
+        // Collected fallible fields are hoisted too
+        if let Some(binding) = self
+            .try_bindings
+            .as_ref()
+            .and_then(|bindings| bindings.get(&member_name(target)))
+        {
+            return quote! { #binding };
+        }
+
+        // Hoisted default expressions - check if we need to clone the binding
+        let Some((binding, count)) = self
+            .default_bindings
+            .get_binding_with_count(&mapping.source.transform)
+        else {
+            return generate_field_value(
                 mapping,
                 field_index,
                 is_ref,
@@ -250,24 +488,496 @@ impl<'a> FieldGenerator<'a> {
         let needs_clone = match mode {
             CloneMode::Move | CloneMode::Copy => false,
             CloneMode::Cloned => true,
-            CloneMode::Auto => is_ref || count > 1,
+            CloneMode::Auto | CloneMode::ArcClone => is_ref || count > 1,
         };
 
-        if needs_clone {
-            quote! { #target: #binding.clone() }
+        if needs_clone && mode == CloneMode::ArcClone {
+            quote! { ::std::sync::Arc::clone(&#binding) }
+        } else if needs_clone {
+            quote! { #binding.clone() }
         } else {
-            quote! { #target: #binding }
+            quote! { #binding }
         }
     }
 }
 
-/// Generate the From or TryFrom implementations based on conversion mode.
+/// Check if fields belong to a tuple struct or tuple variant.
+fn is_tuple(fields: &[FieldMapping]) -> bool {
+    matches!(
+        fields.first().map(|f| &f.target_field),
+        Some(Member::Unnamed(_))
+    )
+}
+
+/// Generate the conversions from every source type on the derive, showing
+/// the ones marked `debug_expand` in a warning.
 #[must_use]
-pub fn generate_from_derive(input: &FromDeriveInput) -> TokenStream {
-    match &input.conversion_mode {
+pub fn generate_from_derive(inputs: &[FromDeriveInput]) -> TokenStream {
+    inputs
+        .iter()
+        .map(|input| {
+            let mut output = generate_relation(input);
+            if let Some(keyword) = &input.debug_expand {
+                output.extend(debug_expand_warning(input, keyword, &output));
+            }
+            output
+        })
+        .collect()
+}
+
+/// Report the generated code as a rustc warning at the `debug_expand` keyword,
+/// by using a `#[deprecated]` item whose note is the code. Cargo hides it in
+/// dependencies, unlike printing to stderr.
+fn debug_expand_warning(
+    input: &FromDeriveInput,
+    keyword: &Ident,
+    output: &TokenStream,
+) -> TokenStream {
+    let source_type = &input.source_type;
+    let note = format!(
+        "relate: generated for `{}` from `{}`:\n{output}",
+        input.target_name,
+        quote! { #source_type },
+    );
+    quote_spanned! {keyword.span()=>
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_camel_case_types)]
+            struct debug_expand;
+            let _ = #keyword;
+        };
+    }
+}
+
+/// Generate the From or TryFrom implementations based on conversion mode.
+fn generate_relation(input: &FromDeriveInput) -> TokenStream {
+    if let Some(method) = &input.method {
+        return generate_method_impl(input, method);
+    }
+
+    let mut output = match &input.conversion_mode {
         ConversionMode::Infallible => generate_from_impl(input),
         ConversionMode::Fallible(error_type) => generate_try_from_impl(input, error_type),
+        ConversionMode::CollectErrors => generate_try_from_impl(input, &None),
+    };
+    if input.also_arc {
+        output.extend(generate_arc_impl(input));
+    }
+    if input.also_option {
+        output.extend(generate_option_impl(input));
+    }
+    if input.const_fn {
+        output.extend(generate_const_impl(input));
     }
+    if input.try_into_self {
+        output.extend(generate_try_into_self_impl(input));
+    }
+    output
+}
+
+/// A generated conversion body: hoisted `let` bindings, then the value.
+struct ConversionBody {
+    let_bindings: Vec<TokenStream>,
+    value:        TokenStream,
+}
+
+/// Generate the forward conversion body (source -> derived type).
+fn forward_body(input: &FromDeriveInput, is_ref: bool) -> ConversionBody {
+    match &input.body {
+        DeriveBody::Struct(fields) => {
+            let field_gen = FieldGenerator::new(fields, input.clone_mode)
+                .with_base(input.base.as_ref(), input.fills_rest);
+            ConversionBody {
+                let_bindings: field_gen.let_bindings(is_ref),
+                value:        field_gen.construct(&quote! { Self }, is_ref),
+            }
+        }
+        DeriveBody::Enum(variants) if input.discriminant => ConversionBody {
+            let_bindings: Vec::new(),
+            value:        discriminant_match(input, variants, is_ref),
+        },
+        DeriveBody::Enum(variants) => {
+            let source_path = variant_path_prefix(&input.source_type);
+            let arms = variants.iter().map(|variant| {
+                forward_variant_arm(&source_path, variant, input.clone_mode, is_ref)
+            });
+            ConversionBody {
+                let_bindings: Vec::new(),
+                value:        quote! { match src { #(#arms)* } },
+            }
+        }
+    }
+}
+
+/// Generate the forward body for `collect_errors`: the value is a
+/// `Result<Self, ConversionError>` reporting every failed field.
+fn collecting_forward_body(
+    input: &FromDeriveInput,
+    fields: &[FieldMapping],
+    is_ref: bool,
+) -> ConversionBody {
+    let field_gen = FieldGenerator::new(fields, input.clone_mode)
+        .with_base(input.base.as_ref(), input.fills_rest)
+        .collecting_errors();
+    ConversionBody {
+        let_bindings: field_gen.let_bindings(is_ref),
+        value:        field_gen.construct_collecting(&quote! { Self }, is_ref),
+    }
+}
+
+/// Generate the forward body for a `TryFrom` conversion, whose value is a
+/// `Result<Self, _>`.
+fn try_forward_body(input: &FromDeriveInput, is_ref: bool) -> ConversionBody {
+    // `collect_errors` bodies already evaluate to a `Result`
+    let body = if let (ConversionMode::CollectErrors, DeriveBody::Struct(fields)) =
+        (&input.conversion_mode, &input.body)
+    {
+        let ConversionBody {
+            let_bindings,
+            value,
+        } = collecting_forward_body(input, fields, is_ref);
+        if input.validate.is_none() {
+            return with_context(
+                input,
+                ConversionBody {
+                    let_bindings,
+                    value,
+                },
+            );
+        }
+        ConversionBody {
+            let_bindings,
+            value: quote! { (#value)? },
+        }
+    } else {
+        forward_body(input, is_ref)
+    };
+    with_context(input, validated(input, body))
+}
+
+/// Wrap the value built by `body` in `Ok`, checking it first with the
+/// `validate = predicate` predicate, which fails with
+/// `ConversionError::Validation`.
+fn validated(input: &FromDeriveInput, body: ConversionBody) -> ConversionBody {
+    let ConversionBody {
+        mut let_bindings,
+        value,
+    } = body;
+    let Some(validate) = &input.validate else {
+        return ConversionBody {
+            let_bindings,
+            value: quote! { ::core::result::Result::Ok(#value) },
+        };
+    };
+    let target_name = input.target_name.to_string();
+    let_bindings.push(quote! {
+        let __relate_value: Self = #value;
+        if !(#validate)(&__relate_value) {
+            return ::core::result::Result::Err(::relate::ConversionError::Validation(#target_name));
+        }
+    });
+    ConversionBody {
+        let_bindings,
+        value: quote! { ::core::result::Result::Ok(__relate_value) },
+    }
+}
+
+/// Wrap a fallible body's error in `ConversionError::Context` (`context =
+/// "..."`).
+///
+/// The bindings propagate errors with `?`, so the whole body runs in a
+/// closure whose error is then labelled.
+fn with_context(input: &FromDeriveInput, body: ConversionBody) -> ConversionBody {
+    let Some(context) = &input.context else {
+        return body;
+    };
+    let ConversionBody {
+        let_bindings,
+        value,
+    } = body;
+    ConversionBody {
+        let_bindings: vec![quote! {
+            let __relate_convert =
+                || -> ::core::result::Result<Self, ::relate::ConversionError> {
+                    #(#let_bindings)*
+                    #value
+                };
+        }],
+        value:        quote! {
+            __relate_convert()
+                .map_err(|e| ::relate::ConversionError::with_context(#context, e))
+        },
+    }
+}
+
+/// Generate the reverse conversion body (derived type -> source).
+fn reverse_body(input: &FromDeriveInput, is_ref: bool) -> ConversionBody {
+    let value = match &input.body {
+        DeriveBody::Struct(fields) if matches!(input.source_type, Type::Tuple(_)) => {
+            reverse_tuple(fields, is_ref, input.reverse_mode)
+        }
+        DeriveBody::Struct(fields) => {
+            let reverse_fields = reverse_field_inits(fields, is_ref, input.reverse_mode);
+            quote! { Self { #(#reverse_fields),* } }
+        }
+        DeriveBody::Enum(variants) if input.discriminant => {
+            let target_name = &input.target_name;
+            let source_type = &input.source_type;
+            let variants = variants.iter().map(|v| &v.target_variant);
+            quote! {
+                match src {
+                    #(#target_name::#variants => #target_name::#variants as #source_type,)*
+                }
+            }
+        }
+        DeriveBody::Enum(variants) => {
+            let target_name = &input.target_name;
+            let arms = variants.iter().map(|variant| {
+                reverse_variant_arm(target_name, variant, is_ref, input.reverse_mode)
+            });
+            quote! { match src { #(#arms)* } }
+        }
+    };
+    ConversionBody {
+        let_bindings: Vec::new(),
+        value,
+    }
+}
+
+/// Generate reverse field initializers for bidirectional conversions.
+fn reverse_field_inits(
+    fields: &[FieldMapping],
+    is_ref: bool,
+    clone_mode: CloneMode,
+) -> Vec<TokenStream> {
+    let reverse_usage = count_reverse_field_usage(fields);
+    fields
+        .iter()
+        .filter_map(|f| generate_reverse_field_init(f, is_ref, &reverse_usage, clone_mode))
+        .collect()
+}
+
+/// Generate the reverse value for a plain tuple source: `(src.a, src.b)`,
+/// each field written back to the element it was read from.
+fn reverse_tuple(fields: &[FieldMapping], is_ref: bool, clone_mode: CloneMode) -> TokenStream {
+    let reverse_usage = count_reverse_field_usage(fields);
+    let mut elements: Vec<(u32, TokenStream)> = fields
+        .iter()
+        .filter_map(|f| {
+            let Some(Member::Unnamed(index)) = &f.source.field_name else {
+                return None;
+            };
+            let value = generate_reverse_field_value(f, is_ref, &reverse_usage, clone_mode)?;
+            Some((index.index, value))
+        })
+        .collect();
+    elements.sort_by_key(|(index, _)| *index);
+    let values = elements.into_iter().map(|(_, value)| value);
+    quote! { (#(#values,)*) }
+}
+
+/// Match an integer against each variant's discriminant, failing with
+/// `ConversionError::UnknownDiscriminant` (converted into a custom error type)
+/// when none has it.
+fn discriminant_match(
+    input: &FromDeriveInput,
+    variants: &[VariantMapping],
+    is_ref: bool,
+) -> TokenStream {
+    let source_type = &input.source_type;
+    let enum_name = input.target_name.to_string();
+    let variants = variants.iter().map(|v| &v.target_variant);
+    let scrutinee = if is_ref {
+        quote! { *src }
+    } else {
+        quote! { src }
+    };
+    quote! {
+        match #scrutinee {
+            #(value if value == Self::#variants as #source_type => Self::#variants,)*
+            value => {
+                return ::core::result::Result::Err(::core::convert::From::from(
+                    ::relate::ConversionError::UnknownDiscriminant {
+                        enum_name: #enum_name,
+                        value:     value as i128,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+/// Assert at compile time that every variant's discriminant fits the integer
+/// type, which `Variant as u8` would silently truncate. Keeping the sign
+/// catches `-1` wrapping to `u128::MAX`, which survives the `i128` round trip.
+fn discriminant_range_checks(input: &FromDeriveInput, variants: &[VariantMapping]) -> TokenStream {
+    let target_name = &input.target_name;
+    let source_type = &input.source_type;
+    let checks = variants.iter().map(|variant| {
+        let variant = &variant.target_variant;
+        let message = format!(
+            "the discriminant of `{target_name}::{variant}` doesn't fit in `{}`",
+            source_type.to_token_stream()
+        );
+        quote_spanned! {variant.span()=>
+            const _: () = {
+                let value = #target_name::#variant as #source_type;
+                let zero: #source_type = 0;
+                ::core::assert!(
+                    #target_name::#variant as i128 == value as i128
+                        && ((#target_name::#variant as i128) < 0) == (value < zero),
+                    #message,
+                );
+            };
+        }
+    });
+    quote! { #(#checks)* }
+}
+
+/// Generate a `match` arm converting one source variant into a target variant.
+fn forward_variant_arm(
+    source_path: &TokenStream,
+    variant: &VariantMapping,
+    clone_mode: CloneMode,
+    is_ref: bool,
+) -> TokenStream {
+    let source_variant = &variant.source_variant;
+    let target_variant = &variant.target_variant;
+
+    let fields = match &variant.fields {
+        VariantFields::Unit => {
+            return quote! { #source_path::#source_variant => Self::#target_variant, };
+        }
+        VariantFields::Tuple(fields) | VariantFields::Named(fields) => fields,
+    };
+
+    let field_gen = FieldGenerator::new(fields, clone_mode);
+    let let_bindings = field_gen.let_bindings(is_ref);
+    let value = field_gen.construct(&quote! { Self::#target_variant }, is_ref);
+    let (body, pattern) = bind_src_fields(&quote! { #(#let_bindings)* #value }, is_ref);
+    quote! { #source_path::#source_variant { #pattern } => { #body } }
+}
+
+/// Generate a `match` arm converting one target variant back into a source
+/// variant.
+fn reverse_variant_arm(
+    target_name: &Ident,
+    variant: &VariantMapping,
+    is_ref: bool,
+    clone_mode: CloneMode,
+) -> TokenStream {
+    let source_variant = &variant.source_variant;
+    let target_variant = &variant.target_variant;
+
+    let fields = match &variant.fields {
+        VariantFields::Unit => {
+            return quote! { #target_name::#target_variant => Self::#source_variant, };
+        }
+        VariantFields::Tuple(fields) | VariantFields::Named(fields) => fields,
+    };
+
+    let reverse_fields = reverse_field_inits(fields, is_ref, clone_mode);
+    let body = quote! { Self::#source_variant { #(#reverse_fields),* } };
+    let (body, pattern) = bind_src_fields(&body, is_ref);
+    quote! { #target_name::#target_variant { #pattern } => { #body } }
+}
+
+/// Rewrite `src.field` accesses to pattern bindings for a variant arm.
+///
+/// Field initializers are generated against `src.field`, as for structs.
+/// Inside a `match` arm the fields are bound by the pattern instead, so each
+/// access becomes a binding (`(*binding)` in ref impls, where bindings are
+/// references). Returns the rewritten tokens and the struct pattern body
+/// binding every accessed field.
+fn bind_src_fields(tokens: &TokenStream, is_ref: bool) -> (TokenStream, TokenStream) {
+    let mut accessed = Vec::new();
+    let rewritten = rewrite_src_access(tokens, is_ref, &mut accessed);
+
+    let pattern_fields = accessed
+        .iter()
+        .map(|(member, binding)| quote! { #member: #binding });
+    let pattern = quote! { #(#pattern_fields,)* .. };
+    (rewritten, pattern)
+}
+
+/// Recursively replace `src . member` with the member's binding.
+fn rewrite_src_access(
+    tokens: &TokenStream,
+    is_ref: bool,
+    accessed: &mut Vec<(TokenTree, Ident)>,
+) -> TokenStream {
+    let trees: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    let mut result = TokenStream::new();
+    let mut i = 0;
+
+    while i < trees.len() {
+        let is_src_access = matches!(&trees[i], TokenTree::Ident(ident) if ident == "src")
+            && matches!(trees.get(i + 1), Some(TokenTree::Punct(p)) if p.as_char() == '.')
+            && matches!(
+                trees.get(i + 2),
+                Some(TokenTree::Ident(_) | TokenTree::Literal(_))
+            );
+
+        if is_src_access {
+            let member = &trees[i + 2];
+            let key = match member {
+                TokenTree::Ident(ident) => ident.unraw().to_string(),
+                other => other.to_string(),
+            };
+            let binding = Ident::new(&format!("__field_{key}"), Span::mixed_site());
+            if !accessed.iter().any(|(_, existing)| *existing == binding) {
+                accessed.push((member.clone(), binding.clone()));
+            }
+            if is_ref {
+                result.extend(quote! { (*#binding) });
+            } else {
+                result.extend(quote! { #binding });
+            }
+            i += 3;
+            continue;
+        }
+
+        match &trees[i] {
+            TokenTree::Group(group) => {
+                let inner = rewrite_src_access(&group.stream(), is_ref, accessed);
+                let mut new_group = Group::new(group.delimiter(), inner);
+                new_group.set_span(group.span());
+                result.extend(std::iter::once(TokenTree::Group(new_group)));
+            }
+            other => result.extend(std::iter::once(other.clone())),
+        }
+        i += 1;
+    }
+
+    result
+}
+
+/// Build the path prefix for source enum variants.
+///
+/// Generic arguments need a turbofish in patterns and expressions:
+/// `Source<T>` becomes `Source::<T>` so `Source::<T>::Variant` parses.
+fn variant_path_prefix(ty: &Type) -> TokenStream {
+    let Type::Path(type_path) = ty else {
+        return quote! { <#ty> };
+    };
+    if type_path.qself.is_some() {
+        return quote! { <#ty> };
+    }
+    let mut path = type_path.path.clone();
+    for segment in &mut path.segments {
+        if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+            args.colon2_token = Some(Default::default());
+        }
+    }
+    quote! { #path }
+}
+
+/// The `&Source` parameter type of the ref impls: `&'a Source` when the
+/// derived type borrows from the source with a lifetime `'a` of its own.
+fn ref_source_type(input: &FromDeriveInput) -> TokenStream {
+    let source_type = &input.source_type;
+    let lifetime = borrowed_lifetime(&input.target_generics, &quote! { #source_type });
+    quote! { &#lifetime #source_type }
 }
 
 /// Generate From implementations (infallible conversion).
@@ -277,69 +987,97 @@ fn generate_from_impl(input: &FromDeriveInput) -> TokenStream {
     let target_name = &input.target_name;
     let source_type = &input.source_type;
     let (impl_generics, ty_generics, where_clause) = input.target_generics.split_for_impl();
+    let inline = input.inline.then(|| quote! { #[inline] });
+
+    if input.impls.owned() {
+        let ConversionBody {
+            let_bindings: owned_let_bindings,
+            value: owned_value,
+        } = forward_body(input, false);
+        let owned_body = quote! { #(#owned_let_bindings)* #owned_value };
+        let src = source_param(&owned_body);
 
-    let field_gen = FieldGenerator::new(&input.fields, input.clone_mode);
-    let owned_let_bindings = field_gen.let_bindings(false);
-    let ref_let_bindings = field_gen.let_bindings(true);
-    let owned_fields = field_gen.field_inits(false);
-    let ref_fields = field_gen.field_inits(true);
-
-    output.extend(quote! {
-        impl #impl_generics ::core::convert::From<#source_type> for #target_name #ty_generics #where_clause {
-            fn from(src: #source_type) -> Self {
-                #(#owned_let_bindings)*
-                Self { #(#owned_fields),* }
+        output.extend(quote! {
+            impl #impl_generics ::core::convert::From<#source_type> for #target_name #ty_generics #where_clause {
+                #inline
+                fn from(#src: #source_type) -> Self {
+                    #owned_body
+                }
             }
-        }
+        });
+    }
+
+    if input.impls.by_ref() {
+        let ConversionBody {
+            let_bindings: ref_let_bindings,
+            value: ref_value,
+        } = forward_body(input, true);
+        let ref_source = ref_source_type(input);
+        let ref_body = quote! { #(#ref_let_bindings)* #ref_value };
+        let src = source_param(&ref_body);
 
-        impl #impl_generics ::core::convert::From<&#source_type> for #target_name #ty_generics #where_clause {
-            fn from(src: &#source_type) -> Self {
-                #(#ref_let_bindings)*
-                Self { #(#ref_fields),* }
+        output.extend(quote! {
+            impl #impl_generics ::core::convert::From<#ref_source> for #target_name #ty_generics #where_clause {
+                #inline
+                fn from(#src: #ref_source) -> Self {
+                    #ref_body
+                }
             }
-        }
-    });
+        });
+    }
 
-    // Generate reverse impls if bidirectional
     if input.bidirectional {
-        let reverse_usage = count_reverse_field_usage(&input.fields);
+        output.extend(generate_reverse_impls(input));
+    }
 
-        let reverse_owned: Vec<_> = input
-            .fields
-            .iter()
-            .filter_map(|f| {
-                generate_reverse_field_init(
-                    f,
-                    false,
-                    &reverse_usage,
-                    ReverseStrategy::AllNonDefault,
-                )
-            })
-            .collect();
+    output
+}
 
-        let reverse_ref: Vec<_> = input
-            .fields
-            .iter()
-            .filter_map(|f| {
-                generate_reverse_field_init(f, true, &reverse_usage, ReverseStrategy::AllNonDefault)
-            })
-            .collect();
+/// Generate the reverse `From` impls (derived type -> source) for `both`.
+fn generate_reverse_impls(input: &FromDeriveInput) -> TokenStream {
+    let mut output = TokenStream::new();
+
+    let target_name = &input.target_name;
+    let source_type = &input.source_type;
+    let (impl_generics, ty_generics, where_clause) = input.target_generics.split_for_impl();
+    let inline = input.inline.then(|| quote! { #[inline] });
+
+    if input.impls.owned() {
+        let reverse_owned = reverse_body(input, false).value;
 
         output.extend(quote! {
             impl #impl_generics ::core::convert::From<#target_name #ty_generics> for #source_type #where_clause {
+                #inline
                 fn from(src: #target_name #ty_generics) -> Self {
-                    Self { #(#reverse_owned),* }
+                    #reverse_owned
                 }
             }
+        });
+    }
 
+    if input.impls.by_ref() {
+        let reverse_ref = reverse_body(input, true).value;
+
+        output.extend(quote! {
             impl #impl_generics ::core::convert::From<&#target_name #ty_generics> for #source_type #where_clause {
+                #inline
                 fn from(src: &#target_name #ty_generics) -> Self {
-                    Self { #(#reverse_ref),* }
+                    #reverse_ref
                 }
             }
         });
     }
 
+    if input.roundtrip_check {
+        output.extend(roundtrip_check_fn(
+            &quote! { #impl_generics },
+            &quote! { #target_name #ty_generics },
+            &quote! { #source_type },
+            &quote! { #where_clause },
+            !input.impls.owned(),
+        ));
+    }
+
     output
 }
 
@@ -350,37 +1088,267 @@ fn generate_try_from_impl(input: &FromDeriveInput, error_type: &Option<syn::Type
     let target_name = &input.target_name;
     let source_type = &input.source_type;
     let (impl_generics, ty_generics, where_clause) = input.target_generics.split_for_impl();
+    let inline = input.inline.then(|| quote! { #[inline] });
 
-    let error = error_type
-        .as_ref()
-        .map(|t| quote! { #t })
-        .unwrap_or_else(|| quote! { ::relate::ConversionError });
+    let error = error_type_tokens(error_type.as_ref());
 
-    let field_gen = FieldGenerator::new(&input.fields, input.clone_mode);
-    let owned_let_bindings = field_gen.let_bindings(false);
-    let ref_let_bindings = field_gen.let_bindings(true);
-    let owned_fields = field_gen.field_inits(false);
-    let ref_fields = field_gen.field_inits(true);
+    if input.impls.owned() {
+        let ConversionBody {
+            let_bindings: owned_let_bindings,
+            value: owned_value,
+        } = try_forward_body(input, false);
+        let owned_body = quote! { #(#owned_let_bindings)* #owned_value };
+        let src = source_param(&owned_body);
 
-    output.extend(quote! {
-        impl #impl_generics ::core::convert::TryFrom<#source_type> for #target_name #ty_generics #where_clause {
-            type Error = #error;
+        output.extend(quote! {
+            impl #impl_generics ::core::convert::TryFrom<#source_type> for #target_name #ty_generics #where_clause {
+                type Error = #error;
 
-            fn try_from(src: #source_type) -> ::core::result::Result<Self, Self::Error> {
-                #(#owned_let_bindings)*
-                ::core::result::Result::Ok(Self { #(#owned_fields),* })
+                #inline
+                fn try_from(#src: #source_type) -> ::core::result::Result<Self, Self::Error> {
+                    #owned_body
+                }
             }
-        }
+        });
+    }
+
+    if input.impls.by_ref() {
+        let ConversionBody {
+            let_bindings: ref_let_bindings,
+            value: ref_value,
+        } = try_forward_body(input, true);
+        let ref_source = ref_source_type(input);
+        let ref_body = quote! { #(#ref_let_bindings)* #ref_value };
+        let src = source_param(&ref_body);
 
-        impl #impl_generics ::core::convert::TryFrom<&#source_type> for #target_name #ty_generics #where_clause {
-            type Error = #error;
+        output.extend(quote! {
+            impl #impl_generics ::core::convert::TryFrom<#ref_source> for #target_name #ty_generics #where_clause {
+                type Error = #error;
 
-            fn try_from(src: &#source_type) -> ::core::result::Result<Self, Self::Error> {
-                #(#ref_let_bindings)*
-                ::core::result::Result::Ok(Self { #(#ref_fields),* })
+                #inline
+                fn try_from(#src: #ref_source) -> ::core::result::Result<Self, Self::Error> {
+                    #ref_body
+                }
             }
+        });
+    }
+
+    if let DeriveBody::Enum(variants) = &input.body {
+        if input.discriminant {
+            output.extend(discriminant_range_checks(input, variants));
         }
-    });
+    }
+
+    // Every variant converts back to its discriminant
+    if input.bidirectional && input.discriminant {
+        output.extend(generate_reverse_impls(input));
+    }
 
     output
 }
+
+/// Generate `Target::from_arc(Arc<Source>) -> Arc<Target>` (`also_arc`).
+///
+/// `impl From<Arc<Source>> for Arc<Target>` is ruled out by the orphan rules
+/// (`Arc` is not a local type), so this is an inherent function. It moves out
+/// of an unshared `Arc` through the owned impl and converts from a reference
+/// otherwise.
+fn generate_arc_impl(input: &FromDeriveInput) -> TokenStream {
+    let target_name = &input.target_name;
+    let source_type = &input.source_type;
+    let (impl_generics, ty_generics, where_clause) = input.target_generics.split_for_impl();
+
+    let error_type = match &input.conversion_mode {
+        ConversionMode::Infallible => None,
+        ConversionMode::Fallible(error_type) => Some(error_type_tokens(error_type.as_ref())),
+        ConversionMode::CollectErrors => Some(error_type_tokens(None)),
+    };
+    let (convert, return_type) = match &error_type {
+        None => (
+            quote! { ::core::convert::From::from },
+            quote! { ::std::sync::Arc<Self> },
+        ),
+        Some(error) => (
+            quote! { ::core::convert::TryFrom::try_from },
+            quote! { ::core::result::Result<::std::sync::Arc<Self>, #error> },
+        ),
+    };
+    let wrap = |value: TokenStream| match &error_type {
+        None => quote! { ::std::sync::Arc::new(#value) },
+        Some(_) => quote! { #value.map(::std::sync::Arc::new) },
+    };
+
+    let body = if input.impls.owned() {
+        let owned = wrap(quote! { #convert(src) });
+        let shared = wrap(quote! { #convert(&*src) });
+        quote! {
+            match ::std::sync::Arc::try_unwrap(src) {
+                ::core::result::Result::Ok(src) => #owned,
+                ::core::result::Result::Err(src) => #shared,
+            }
+        }
+    } else {
+        wrap(quote! { #convert(&*src) })
+    };
+
+    quote! {
+        impl #impl_generics #target_name #ty_generics #where_clause {
+            pub fn from_arc(src: ::std::sync::Arc<#source_type>) -> #return_type {
+                #body
+            }
+        }
+    }
+}
+
+/// Generate `Target::from_option(Option<Source>) -> Option<Target>`
+/// (`also_option`).
+///
+/// `impl From<Option<Source>> for Option<Target>` is ruled out by the orphan
+/// rules and overlaps core's `From<T> for Option<T>`, so this is an inherent
+/// function. With `ref_only` it takes `Option<&Source>`; a fallible
+/// conversion returns `Result<Option<Target>, E>`.
+fn generate_option_impl(input: &FromDeriveInput) -> TokenStream {
+    let target_name = &input.target_name;
+    let source_type = &input.source_type;
+    let (impl_generics, ty_generics, where_clause) = input.target_generics.split_for_impl();
+
+    let param = if input.impls.owned() {
+        quote! { ::core::option::Option<#source_type> }
+    } else {
+        let ref_source = ref_source_type(input);
+        quote! { ::core::option::Option<#ref_source> }
+    };
+    let error_type = match &input.conversion_mode {
+        ConversionMode::Infallible => None,
+        ConversionMode::Fallible(error_type) => Some(error_type_tokens(error_type.as_ref())),
+        ConversionMode::CollectErrors => Some(error_type_tokens(None)),
+    };
+    let (body, return_type) = match &error_type {
+        None => (
+            quote! { src.map(::core::convert::From::from) },
+            quote! { ::core::option::Option<Self> },
+        ),
+        Some(error) => (
+            quote! { src.map(::core::convert::TryFrom::try_from).transpose() },
+            quote! { ::core::result::Result<::core::option::Option<Self>, #error> },
+        ),
+    };
+
+    quote! {
+        impl #impl_generics #target_name #ty_generics #where_clause {
+            pub fn from_option(src: #param) -> #return_type {
+                #body
+            }
+        }
+    }
+}
+
+/// Generate `Source::try_into_<target>(self) -> Result<Target, E>`
+/// (`try_into_self`).
+///
+/// `TryInto` already comes with `TryFrom`, but a generic target often can't
+/// be inferred from `.try_into()`; the method names the target. An explicit
+/// `impl TryInto` would overlap core's blanket impl, so it is an inherent
+/// method on the source, which must be a local type. With `ref_only` it
+/// takes `&self`.
+fn generate_try_into_self_impl(input: &FromDeriveInput) -> TokenStream {
+    let target_name = &input.target_name;
+    let source_type = &input.source_type;
+    let (impl_generics, ty_generics, where_clause) = input.target_generics.split_for_impl();
+    let inline = input.inline.then(|| quote! { #[inline] });
+    let method = Ident::new(
+        &format!(
+            "try_into_{}",
+            to_snake_case(&target_name.unraw().to_string())
+        ),
+        target_name.span(),
+    );
+    let receiver = if input.impls.owned() {
+        quote! { self }
+    } else {
+        let ref_source = ref_source_type(input);
+        quote! { self: #ref_source }
+    };
+    let error = match &input.conversion_mode {
+        ConversionMode::Fallible(error_type) => error_type_tokens(error_type.as_ref()),
+        ConversionMode::Infallible | ConversionMode::CollectErrors => error_type_tokens(None),
+    };
+
+    quote! {
+        impl #impl_generics #source_type #where_clause {
+            #inline
+            pub fn #method(#receiver) -> ::core::result::Result<#target_name #ty_generics, #error> {
+                ::core::convert::TryFrom::try_from(self)
+            }
+        }
+    }
+}
+
+/// Generate `Target::const_from(Source) -> Target` as a `const fn` (`const`).
+///
+/// Trait methods can't be `const` on stable Rust, so the `From` impls stay as
+/// they are and the const conversion is an inherent function next to them.
+/// The parser only allows `const` when every field is copied unchanged.
+fn generate_const_impl(input: &FromDeriveInput) -> TokenStream {
+    let target_name = &input.target_name;
+    let source_type = &input.source_type;
+    let (impl_generics, ty_generics, where_clause) = input.target_generics.split_for_impl();
+    let inline = input.inline.then(|| quote! { #[inline] });
+    let ConversionBody {
+        let_bindings,
+        value,
+    } = forward_body(input, false);
+
+    quote! {
+        impl #impl_generics #target_name #ty_generics #where_clause {
+            #inline
+            pub const fn const_from(src: #source_type) -> Self {
+                #(#let_bindings)*
+                #value
+            }
+        }
+    }
+}
+
+/// Generate an inherent `pub fn <method>(src: &Source)` on the derived type
+/// (`method = name`).
+///
+/// Unlike a `From` impl, an inherent method is allowed when the source type is
+/// foreign, so only the ref-based body is generated.
+fn generate_method_impl(input: &FromDeriveInput, method: &Ident) -> TokenStream {
+    let target_name = &input.target_name;
+    let (impl_generics, ty_generics, where_clause) = input.target_generics.split_for_impl();
+    let inline = input.inline.then(|| quote! { #[inline] });
+    let constness = input.const_fn.then(|| quote! { const });
+
+    let error_type = match &input.conversion_mode {
+        ConversionMode::Infallible => None,
+        ConversionMode::Fallible(error_type) => Some(error_type.as_ref()),
+        ConversionMode::CollectErrors => Some(None),
+    };
+    let (body, return_type) = match error_type {
+        None => (forward_body(input, true), quote! { Self }),
+        Some(error_type) => {
+            let error = error_type_tokens(error_type);
+            (
+                try_forward_body(input, true),
+                quote! { ::core::result::Result<Self, #error> },
+            )
+        }
+    };
+    let ConversionBody {
+        let_bindings,
+        value,
+    } = body;
+    let ref_source = ref_source_type(input);
+
+    quote! {
+        impl #impl_generics #target_name #ty_generics #where_clause {
+            #inline
+            pub #constness fn #method(src: #ref_source) -> #return_type {
+                #(#let_bindings)*
+                #value
+            }
+        }
+    }
+}
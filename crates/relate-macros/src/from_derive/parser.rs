@@ -1,54 +1,927 @@
 //! Parser for the Relate derive macro attributes.
 
 use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
 use syn::{
-    Attribute, DeriveInput, Error, Expr, Fields, Ident, Meta, Result, Token, Type, parse::Parse,
+    Attribute, DeriveInput, Error, Expr, Fields, Ident, LitStr, Member, Meta, Result, Token, Type,
+    WherePredicate,
+    parse::{Parse, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
 };
 
 use super::types::{
-    CloneMode, ConversionMode, FieldMapping, FieldSource, FromDeriveInput, Transform,
+    CloneMode, ConversionMode, DeriveBody, FieldMapping, FieldSource, FromDeriveInput, ImplSet,
+    Transform, VariantFields, VariantMapping,
+};
+use crate::core::{
+    Pointer, base_binding, collect_target_refs, contains_source_ref, implicit_owned_clone,
+    is_placeholder, member_name, parse_collection_body, parse_default_if_none,
+    parse_sequence_map_body, parse_skip_if, parse_tokens_until_terminator,
+    parse_trailing_clone_mode, parse_trailing_map_err, peek_map_values, peek_option_collection,
+    peek_trailing_option, target_ref_order,
 };
-use crate::core::{parse_tokens_until_terminator, parse_trailing_clone_mode};
 
-/// Parse a `DeriveInput` into `FromDeriveInput`.
-pub fn parse_from_derive(input: DeriveInput) -> Result<FromDeriveInput> {
-    let target_name = input.ident;
-    let target_generics = input.generics;
+/// Parse a `DeriveInput` into one `FromDeriveInput` per `#[relate(Source)]`
+/// attribute.
+pub fn parse_from_derive(input: DeriveInput) -> Result<Vec<FromDeriveInput>> {
+    // Parse every #[relate(SourceType, ...)] attribute on the type
+    let relate_attrs = parse_from_attrs(&input.attrs, &input.ident)?;
+    let sources: Vec<Type> = relate_attrs.iter().map(|a| a.source_type.clone()).collect();
 
-    // Parse #[relate(SourceType)] or #[relate(SourceType, both, cloned)] attribute
-    let relate_attr = parse_from_attr(&input.attrs)?;
+    // `__relate_roundtrip` is an inherent function, so only one source can have it
+    if let Some(second) = relate_attrs
+        .iter()
+        .filter_map(|a| a.roundtrip.as_ref())
+        .nth(1)
+    {
+        return Err(Error::new_spanned(
+            second,
+            "`roundtrip_check` can only be used on one source type per derive",
+        ));
+    }
 
-    // Parse fields
-    let fields = match input.data {
-        syn::Data::Struct(data) => parse_fields(data.fields)?,
-        _ => {
+    relate_attrs
+        .into_iter()
+        .map(|relate_attr| parse_relation(&input, relate_attr, &sources))
+        .collect()
+}
+
+/// Parse the conversion from a single source type.
+///
+/// Field and variant attributes scoped to another source with `from = Other`
+/// are ignored.
+fn parse_relation(
+    input: &DeriveInput,
+    relate_attr: RelateAttr,
+    sources: &[Type],
+) -> Result<FromDeriveInput> {
+    let target_name = input.ident.clone();
+    let mut target_generics = input.generics.clone();
+    if !relate_attr.bounds.is_empty() {
+        target_generics
+            .make_where_clause()
+            .predicates
+            .extend(relate_attr.bounds.iter().cloned());
+    }
+    let scope = SourceScope {
+        source: &relate_attr.source_type,
+        sources,
+    };
+
+    if let Some(allow_missing) = &relate_attr.allow_missing {
+        if !matches!(input.data, syn::Data::Struct(_)) {
+            return Err(Error::new_spanned(
+                allow_missing,
+                "`allow_missing` is only supported on structs",
+            ));
+        }
+        if !relate_attr.flatten.is_empty() {
+            return Err(Error::new_spanned(
+                allow_missing,
+                "`allow_missing` defaults every field without an attribute and can't be \
+                 combined with `flatten`; map those fields with `#[relate(.outer.field)]`",
+            ));
+        }
+    }
+
+    if let Some(base) = &relate_attr.base {
+        let named = match &input.data {
+            syn::Data::Struct(data) => matches!(data.fields, Fields::Named(_)),
+            _ => false,
+        };
+        if !named {
+            return Err(Error::new_spanned(
+                base,
+                "`base = expr` fills the remaining fields with `..expr` and is only supported on \
+                 structs with named fields",
+            ));
+        }
+    }
+
+    // Parse struct fields or enum variants
+    let rules = FieldRules {
+        missing:   if relate_attr.allow_missing.is_some() {
+            FieldSource::default_value
+        } else {
+            FieldSource::auto
+        },
+        skippable: relate_attr.base.is_some(),
+    };
+    let mut body = match &input.data {
+        syn::Data::Struct(data) if matches!(data.fields, Fields::Unit) => {
             return Err(Error::new_spanned(
                 target_name,
-                "Relate derive only supports structs",
+                "Relate derive does not support unit structs",
             ));
         }
+        syn::Data::Struct(data) => DeriveBody::Struct(parse_fields(&data.fields, &scope, &rules)?),
+        syn::Data::Enum(data) => DeriveBody::Enum(
+            data.variants
+                .iter()
+                .map(|variant| parse_variant(variant, &scope))
+                .collect::<Result<_>>()?,
+        ),
+        syn::Data::Union(_) => {
+            return Err(Error::new_spanned(
+                target_name,
+                "Relate derive only supports structs and enums",
+            ));
+        }
+    };
+
+    // `..base` only when `skip` left a field out; otherwise it has no effect
+    let fills_rest = match (&input.data, &body) {
+        (syn::Data::Struct(data), DeriveBody::Struct(fields)) => fields.len() < data.fields.len(),
+        _ => false,
     };
 
-    // Determine conversion mode: explicit try_from/error type, auto-detect from
-    // fields, or infallible
-    let conversion_mode =
-        determine_conversion_mode(&fields, relate_attr.error_type, relate_attr.force_try_from);
+    if let DeriveBody::Enum(_) = &body {
+        if let Some(field) = body.all_fields().find(|f| match &f.source.transform {
+            Transform::WithExpr(tokens, _) => contains_source_ref(tokens),
+            _ => false,
+        }) {
+            return Err(Error::new_spanned(
+                &field.target_field,
+                "`$src` is only supported on structs; enum variant fields are matched out of \
+                 the source",
+            ));
+        }
+    }
+
+    let getter = body
+        .all_fields()
+        .find(|f| matches!(f.source.transform, Transform::Getter(_)));
+    if let Some(field) = getter {
+        if matches!(body, DeriveBody::Enum(_)) {
+            return Err(Error::new_spanned(
+                &field.target_field,
+                "`getter` is only supported on structs; enum variant fields are matched out of \
+                 the source",
+            ));
+        }
+        if relate_attr.bidirectional {
+            return Err(Error::new_spanned(
+                &field.target_field,
+                "`getter` reads a method, so `both` has no source field to write back to",
+            ));
+        }
+    }
+
+    let discriminant = is_integer_type(&relate_attr.source_type);
+    if let (DeriveBody::Enum(variants), true) = (&body, discriminant) {
+        check_discriminant_variants(variants, &relate_attr.source_type)?;
+    }
+
+    apply_flatten(&mut body, &relate_attr)?;
+    apply_rename_fields(&mut body, &relate_attr.rename_fields)?;
+    apply_strip_prefix(&mut body, relate_attr.strip_prefix.as_ref())?;
+    apply_optionalize(&mut body, &relate_attr)?;
+    apply_patch(&mut body, &relate_attr)?;
+    if is_json_value(&relate_attr.source_type) {
+        apply_json_source(&mut body, &relate_attr)?;
+    }
+    apply_tuple_source(&mut body, &relate_attr.source_type);
+    if let Some(delimiter) = &relate_attr.delimiter {
+        apply_delimited_source(&mut body, &relate_attr, delimiter)?;
+    }
+
+    // Determine conversion mode: collected errors, explicit try_from/error
+    // type, auto-detect from fields, or infallible
+    let conversion_mode = if let Some(collect_errors) = relate_attr.collect_errors {
+        if let Some(error_type) = &relate_attr.error_type {
+            return Err(Error::new_spanned(
+                error_type,
+                "`collect_errors` reports failures as `ConversionError::Multiple` and can't be \
+                 combined with a custom error type",
+            ));
+        }
+        if matches!(body, DeriveBody::Enum(_)) {
+            return Err(Error::new_spanned(
+                collect_errors,
+                "`collect_errors` is only supported on structs",
+            ));
+        }
+        if let Some(field) = body.all_fields().find(|f| {
+            let mut refs = Vec::new();
+            if let Transform::WithExpr(tokens, _) = &f.source.transform {
+                collect_target_refs(tokens, &mut refs);
+            }
+            !refs.is_empty()
+        }) {
+            return Err(Error::new_spanned(
+                &field.target_field,
+                "`@field` references can't be combined with `collect_errors`",
+            ));
+        }
+        ConversionMode::CollectErrors
+    } else {
+        // Integers without a matching variant fail, so discriminants are always
+        // `TryFrom`
+        let force_try_from =
+            relate_attr.force_try_from || (matches!(body, DeriveBody::Enum(_)) && discriminant);
+        determine_conversion_mode(&body, relate_attr.error_type, force_try_from)
+    };
+
+    if let Some(context) = &relate_attr.context {
+        match &conversion_mode {
+            ConversionMode::Infallible => {
+                return Err(Error::new_spanned(
+                    context,
+                    "`context` labels conversion errors and requires a fallible conversion; \
+                     add `try_from`",
+                ));
+            }
+            ConversionMode::Fallible(Some(error_type)) => {
+                return Err(Error::new_spanned(
+                    error_type,
+                    "`context` wraps errors in `ConversionError::Context` and can't be combined \
+                     with a custom error type",
+                ));
+            }
+            ConversionMode::Fallible(None) | ConversionMode::CollectErrors => {}
+        }
+    }
+    if let Some((keyword, wrapped)) = &relate_attr.wraps {
+        let ConversionMode::Fallible(Some(error_type)) = &conversion_mode else {
+            return Err(Error::new_spanned(
+                keyword,
+                "`wraps(..)` converts field errors into a custom error type; add `error = Type`",
+            ));
+        };
+        apply_wraps(&mut body, &mut target_generics, error_type, wrapped);
+    }
+    if let Some(validate) = &relate_attr.validate {
+        match &conversion_mode {
+            ConversionMode::Infallible => {
+                return Err(Error::new_spanned(
+                    validate,
+                    "`validate` rejects a converted value with an error and requires a fallible \
+                     conversion; add `try_from`",
+                ));
+            }
+            ConversionMode::Fallible(Some(error_type)) => {
+                return Err(Error::new_spanned(
+                    error_type,
+                    "`validate` fails with `ConversionError::Validation` and can't be combined \
+                     with a custom error type",
+                ));
+            }
+            ConversionMode::Fallible(None) | ConversionMode::CollectErrors => {}
+        }
+    }
+
+    // Without an owned impl there is nothing to check
+    let owned = !matches!(relate_attr.impls, Some((_, ImplSet::RefOnly)));
+    if relate_attr.no_clone && owned {
+        check_no_clone(&body, relate_attr.clone_mode)?;
+    }
+
+    if let (Some(method), true) = (&relate_attr.method, relate_attr.bidirectional) {
+        return Err(Error::new_spanned(
+            method,
+            "`method` generates an inherent method on the derived type and can't be combined \
+             with `both`",
+        ));
+    }
+    if let (Some(_), Some((keyword, _))) = (&relate_attr.method, &relate_attr.impls) {
+        return Err(Error::new_spanned(
+            keyword,
+            format!(
+                "`method` generates an inherent method instead of `From` impls and can't be \
+                 combined with `{keyword}`"
+            ),
+        ));
+    }
+    if let Some(also_arc) = &relate_attr.also_arc {
+        if relate_attr.method.is_some() {
+            return Err(Error::new_spanned(
+                also_arc,
+                "`also_arc` adds `from_arc` next to the `From` impls and can't be combined with \
+                 `method`",
+            ));
+        }
+        if let Some((_, ImplSet::OwnedOnly)) = &relate_attr.impls {
+            return Err(Error::new_spanned(
+                also_arc,
+                "`also_arc` converts a shared `Arc<Source>` through `From<&Source>`, which \
+                 `owned_only` doesn't generate",
+            ));
+        }
+    }
+    if let (Some(also_option), Some(_)) = (&relate_attr.also_option, &relate_attr.method) {
+        return Err(Error::new_spanned(
+            also_option,
+            "`also_option` adds `from_option` next to the `From` impls and can't be combined \
+             with `method`",
+        ));
+    }
+    if let (Some((keyword, _)), false) = (&relate_attr.reverse_mode, relate_attr.bidirectional) {
+        return Err(Error::new_spanned(
+            keyword,
+            format!("`{keyword}` sets the clone mode of the reverse impls and requires `both`"),
+        ));
+    }
+    if let Some(roundtrip) = &relate_attr.roundtrip {
+        if !relate_attr.bidirectional {
+            return Err(Error::new_spanned(
+                roundtrip,
+                "`roundtrip_check` converts to the derived type and back and requires `both`",
+            ));
+        }
+        if !matches!(conversion_mode, ConversionMode::Infallible) {
+            return Err(Error::new_spanned(
+                roundtrip,
+                "`roundtrip_check` requires `From` in both directions; this conversion is \
+                 fallible",
+            ));
+        }
+    }
+    if let Some(try_into_self) = &relate_attr.try_into_self {
+        if relate_attr.method.is_some() {
+            return Err(Error::new_spanned(
+                try_into_self,
+                "`try_into_self` calls the generated `TryFrom` impl and can't be combined with \
+                 `method`",
+            ));
+        }
+        if matches!(conversion_mode, ConversionMode::Infallible) {
+            return Err(Error::new_spanned(
+                try_into_self,
+                "`try_into_self` adds a method calling the `TryFrom` impl and requires a \
+                 fallible conversion; add `try_from`, or call `.into()` for a `From` conversion",
+            ));
+        }
+    }
+    if let Some(const_fn) = &relate_attr.const_fn {
+        check_const(const_fn, &body, relate_attr.clone_mode, &conversion_mode)?;
+    }
+    if let (CloneMode::Move, Some((keyword, ImplSet::RefOnly))) =
+        (relate_attr.clone_mode, &relate_attr.impls)
+    {
+        return Err(Error::new_spanned(
+            keyword,
+            "`move` only affects the owned impl, which `ref_only` doesn't generate",
+        ));
+    }
 
     Ok(FromDeriveInput {
         target_name,
         target_generics,
         source_type: relate_attr.source_type,
         bidirectional: relate_attr.bidirectional,
-        fields,
+        discriminant: discriminant && matches!(body, DeriveBody::Enum(_)),
+        body,
         clone_mode: relate_attr.clone_mode,
+        reverse_mode: relate_attr.reverse_mode.map_or(CloneMode::Auto, |(_, m)| m),
         conversion_mode,
+        method: relate_attr.method,
+        context: relate_attr.context,
+        validate: relate_attr.validate,
+        base: relate_attr.base,
+        fills_rest,
+        // A `&str` row only converts by value; `&&str` would be pointless
+        impls: if relate_attr.delimiter.is_some() {
+            ImplSet::OwnedOnly
+        } else {
+            relate_attr.impls.map_or(ImplSet::Both, |(_, impls)| impls)
+        },
+        also_arc: relate_attr.also_arc.is_some(),
+        also_option: relate_attr.also_option.is_some(),
+        roundtrip_check: relate_attr.roundtrip.is_some(),
+        inline: relate_attr.inline,
+        const_fn: relate_attr.const_fn.is_some(),
+        try_into_self: relate_attr.try_into_self.is_some(),
+        debug_expand: relate_attr.debug_expand.clone(),
     })
 }
 
+/// Reject fields the owned impl would clone implicitly (`no_clone`).
+///
+/// The ref impl still clones, since it can't move out of a reference.
+fn check_no_clone(body: &DeriveBody, clone_mode: CloneMode) -> Result<()> {
+    let field_lists: Vec<&[FieldMapping]> = match body {
+        DeriveBody::Struct(fields) => vec![fields],
+        DeriveBody::Enum(variants) => variants
+            .iter()
+            .filter_map(|v| match &v.fields {
+                VariantFields::Tuple(fields) | VariantFields::Named(fields) => {
+                    Some(fields.as_slice())
+                }
+                VariantFields::Unit => None,
+            })
+            .collect(),
+    };
+    for fields in field_lists {
+        if let Some(field) = implicit_owned_clone(fields, clone_mode) {
+            return Err(Error::new_spanned(
+                &field.target_field,
+                format!(
+                    "Field `{}` reads a source field that is used more than once, so the owned \
+                     conversion would clone it, which `no_clone` forbids.\n\
+                     Read each source field once, or mark the field `cloned` to clone explicitly",
+                    member_name(&field.target_field)
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that a `const` conversion only copies source fields unchanged.
+///
+/// Clones, transforms and the `?` of a `TryFrom` body can't run in a
+/// `const fn` on stable Rust, so only `copy` fields that read a source field
+/// unchanged qualify.
+fn check_const(
+    const_fn: &Ident,
+    body: &DeriveBody,
+    clone_mode: CloneMode,
+    conversion_mode: &ConversionMode,
+) -> Result<()> {
+    let DeriveBody::Struct(fields) = body else {
+        return Err(Error::new_spanned(
+            const_fn,
+            "`const` is only supported on structs",
+        ));
+    };
+    if !matches!(conversion_mode, ConversionMode::Infallible) {
+        return Err(Error::new_spanned(
+            const_fn,
+            "`const` requires an infallible conversion; a `TryFrom` body can't run in a \
+             `const fn`",
+        ));
+    }
+    for field in fields {
+        let name = member_name(&field.target_field);
+        // A plain `.field` rename reads the source field unchanged
+        let copied = match &field.source.transform {
+            Transform::Identity => true,
+            Transform::WithExpr(tokens, false) => plain_source_field(tokens).is_some(),
+            _ => false,
+        };
+        if !copied {
+            return Err(Error::new_spanned(
+                &field.target_field,
+                format!(
+                    "Field `{name}` uses a transform, which can't run in a `const fn`.\n\
+                     `const` only supports fields that copy their source field unchanged"
+                ),
+            ));
+        }
+        if field.source.clone_mode.unwrap_or(clone_mode) != CloneMode::Copy {
+            return Err(Error::new_spanned(
+                &field.target_field,
+                format!(
+                    "Field `{name}` isn't read in `copy` mode, so `const` can't tell whether \
+                     it can be copied in a `const fn`.\n\
+                     Add `copy` to the struct attribute, or mark the field `copy`"
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Read `flatten(outer: a, b)` fields from `src.outer.a` / `src.outer.b`.
+///
+/// Only fields that would otherwise map by name are rewritten; a field with
+/// its own mapping keeps it.
+fn apply_flatten(body: &mut DeriveBody, relate_attr: &RelateAttr) -> Result<()> {
+    let Some(group) = relate_attr.flatten.first() else {
+        return Ok(());
+    };
+    let DeriveBody::Struct(fields) = body else {
+        return Err(Error::new_spanned(
+            &group.outer,
+            "`flatten` is only supported on structs",
+        ));
+    };
+    if relate_attr.bidirectional {
+        return Err(Error::new_spanned(
+            &group.outer,
+            "`flatten` can't be combined with `both`: the nested source struct can't be \
+             rebuilt from the flattened fields",
+        ));
+    }
+
+    for FlattenGroup {
+        outer,
+        fields: names,
+    } in &relate_attr.flatten
+    {
+        for name in names {
+            let Some(field) = fields
+                .iter_mut()
+                .find(|f| matches!(&f.target_field, Member::Named(ident) if ident == name))
+            else {
+                return Err(Error::new_spanned(
+                    name,
+                    format!("`flatten` field `{name}` is not a field of this struct"),
+                ));
+            };
+            let source = &mut field.source;
+            if source.field_name.is_none() && source.transform.is_identity() {
+                source.transform = Transform::WithExpr(quote! { .#outer.#name }, false);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Map the fields listed in `rename_fields(old = new)` from their old source
+/// names.
+///
+/// Only fields that would otherwise map by name are rewritten; a field with
+/// its own mapping keeps it.
+fn apply_rename_fields(body: &mut DeriveBody, renames: &[RenamedField]) -> Result<()> {
+    let Some(first) = renames.first() else {
+        return Ok(());
+    };
+    let DeriveBody::Struct(fields) = body else {
+        return Err(Error::new_spanned(
+            &first.source,
+            "`rename_fields` is only supported on structs",
+        ));
+    };
+
+    for (index, RenamedField { source, target }) in renames.iter().enumerate() {
+        if renames[..index].iter().any(|r| r.target == *target) {
+            return Err(Error::new_spanned(
+                target,
+                format!("`rename_fields` renames `{target}` more than once"),
+            ));
+        }
+        let Some(field) = fields
+            .iter_mut()
+            .find(|f| matches!(&f.target_field, Member::Named(ident) if ident == target))
+        else {
+            return Err(Error::new_spanned(
+                target,
+                format!("`rename_fields` field `{target}` is not a field of this struct"),
+            ));
+        };
+        let mapping = &mut field.source;
+        if mapping.field_name.is_none() && mapping.transform.is_identity() {
+            mapping.field_name = Some(Member::Named(source.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Map by-name fields from `<prefix><name>` in the source
+/// (`strip_prefix = "db_"`); only structs have named fields to prefix.
+fn apply_strip_prefix(body: &mut DeriveBody, prefix: Option<&LitStr>) -> Result<()> {
+    let Some(prefix) = prefix else {
+        return Ok(());
+    };
+    let DeriveBody::Struct(fields) = body else {
+        return Err(Error::new_spanned(
+            prefix,
+            "`strip_prefix` is only supported on structs",
+        ));
+    };
+
+    crate::core::apply_strip_prefix(fields, prefix)
+}
+
+/// Convert fallible fields' errors into the custom error type with
+/// `From::from` (`wraps(ParseIntError, ..)`).
+///
+/// Each listed type becomes an `Error: From<Type>` bound on the impls, so a
+/// missing `From` impl is reported at the attribute rather than at a field.
+/// Fields with their own `map_err` keep it.
+fn apply_wraps(
+    body: &mut DeriveBody,
+    generics: &mut syn::Generics,
+    error_type: &Type,
+    wrapped: &[Type],
+) {
+    let bounds = wrapped.iter().map(|ty| -> WherePredicate {
+        syn::parse_quote_spanned! {ty.span()=> #error_type: ::core::convert::From<#ty> }
+    });
+    generics.make_where_clause().predicates.extend(bounds);
+
+    for field in body.all_fields_mut() {
+        if field.source.transform.is_fallible() && field.source.map_err.is_none() {
+            let span = field.target_field.span();
+            field.source.map_err = Some(syn::parse_quote_spanned! {span=>
+                <#error_type as ::core::convert::From<_>>::from
+            });
+        }
+    }
+}
+
+/// Wrap every by-name field in `Some` (`optionalize`), or unwrap every one
+/// with `unwrap_or_default` (`deoptionalize`), for "patch" structs whose
+/// fields are all `Option`s of the base struct's.
+///
+/// Fields with their own transform keep it.
+fn apply_optionalize(body: &mut DeriveBody, relate_attr: &RelateAttr) -> Result<()> {
+    let (keyword, transform) = match (&relate_attr.optionalize, &relate_attr.deoptionalize) {
+        (None, None) => return Ok(()),
+        (Some(_), Some(deoptionalize)) => {
+            return Err(Error::new_spanned(
+                deoptionalize,
+                "`optionalize` and `deoptionalize` can't be combined",
+            ));
+        }
+        (Some(keyword), None) => (keyword, Transform::WrapSome),
+        (None, Some(keyword)) => (keyword, FieldSource::unwrap_or_default().transform),
+    };
+    let DeriveBody::Struct(fields) = body else {
+        return Err(Error::new_spanned(
+            keyword,
+            format!("`{keyword}` is only supported on structs"),
+        ));
+    };
+    if relate_attr.bidirectional {
+        return Err(Error::new_spanned(
+            keyword,
+            format!(
+                "`{keyword}` can't be combined with `both`; derive the reverse on the other \
+                 struct with `{}`",
+                if keyword == "optionalize" {
+                    "deoptionalize"
+                } else {
+                    "optionalize"
+                }
+            ),
+        ));
+    }
+
+    for field in fields {
+        if field.source.transform.is_identity() {
+            field.source.transform = transform.clone();
+        }
+    }
+    Ok(())
+}
+
+/// Fill every by-name field from an `Option` of the patch source, falling back
+/// to the same field of the `base = expr` value (`apply_patch`): each becomes
+/// `src.field.unwrap_or(base.field)`.
+///
+/// Fields with their own transform keep it.
+fn apply_patch(body: &mut DeriveBody, relate_attr: &RelateAttr) -> Result<()> {
+    let Some(keyword) = &relate_attr.apply_patch else {
+        return Ok(());
+    };
+    if let Some(other) = relate_attr
+        .optionalize
+        .as_ref()
+        .or(relate_attr.deoptionalize.as_ref())
+    {
+        return Err(Error::new_spanned(
+            other,
+            format!("`{other}` can't be combined with `apply_patch`"),
+        ));
+    }
+    if relate_attr.base.is_none() {
+        return Err(Error::new_spanned(
+            keyword,
+            "`apply_patch` falls back to the base value for unset fields; add `base = expr`",
+        ));
+    }
+    if relate_attr.bidirectional {
+        return Err(Error::new_spanned(
+            keyword,
+            "`apply_patch` can't be combined with `both`",
+        ));
+    }
+    // `base = expr` already requires a struct with named fields
+    let DeriveBody::Struct(fields) = body else {
+        return Ok(());
+    };
+
+    let base = base_binding();
+    let placeholder = Ident::new("_", proc_macro2::Span::call_site());
+    for field in fields {
+        if field.source.transform.is_identity() {
+            let target = &field.target_field;
+            field.source.transform =
+                Transform::WithExpr(quote! { #placeholder.unwrap_or(#base.#target) }, false);
+        }
+    }
+    Ok(())
+}
+
+/// Check for a `serde_json::Value` source, read as a JSON object.
+fn is_json_value(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let segments: Vec<_> = type_path.path.segments.iter().map(|s| &s.ident).collect();
+    matches!(
+        segments.as_slice(),
+        [.., module, value] if *module == "serde_json" && *value == "Value"
+    )
+}
+
+/// Check for a primitive integer source, which an enum converts from by
+/// discriminant.
+fn is_integer_type(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path.qself.is_none()
+        && type_path.path.get_ident().is_some_and(|ident| {
+            matches!(
+                ident.to_string().as_str(),
+                "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize"
+            )
+        })
+}
+
+/// Check that an enum converted from an integer is fieldless and doesn't
+/// rename variants, since it matches the integer against each variant's
+/// discriminant.
+fn check_discriminant_variants(variants: &[VariantMapping], source_type: &Type) -> Result<()> {
+    for variant in variants {
+        if !matches!(variant.fields, VariantFields::Unit) {
+            return Err(Error::new_spanned(
+                &variant.target_variant,
+                format!(
+                    "An enum converted from `{}` maps each variant to its discriminant, so every \
+                     variant must be a unit variant",
+                    source_type.to_token_stream()
+                ),
+            ));
+        }
+        if variant.source_variant != variant.target_variant {
+            return Err(Error::new_spanned(
+                &variant.source_variant,
+                "An enum converted from an integer matches discriminants and has no source \
+                 variants to rename",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Read each field of a struct converted from a `serde_json::Value` from the
+/// object key of the same name, or its rename, with `relate::json_field`.
+///
+/// Defaulted fields are left alone; other transforms have no source field to
+/// read and are an error.
+fn apply_json_source(body: &mut DeriveBody, relate_attr: &RelateAttr) -> Result<()> {
+    let source_type = &relate_attr.source_type;
+    if !cfg!(feature = "serde") {
+        return Err(Error::new_spanned(
+            source_type,
+            "`serde_json::Value` sources need the `serde` feature of `relate`",
+        ));
+    }
+    let fields = match body {
+        DeriveBody::Struct(fields)
+            if fields
+                .iter()
+                .all(|f| matches!(f.target_field, Member::Named(_))) =>
+        {
+            fields
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                source_type,
+                "`serde_json::Value` sources are only supported on structs with named fields",
+            ));
+        }
+    };
+    if relate_attr.bidirectional {
+        return Err(Error::new_spanned(
+            source_type,
+            "`serde_json::Value` sources can't be combined with `both`",
+        ));
+    }
+
+    for field in fields {
+        let source = &mut field.source;
+        let renamed = match &source.transform {
+            Transform::WithExpr(tokens, false) => plain_source_field(tokens),
+            _ => None,
+        };
+        let key = match (&source.transform, renamed) {
+            (_, Some(member)) => member_name(&member),
+            (Transform::Identity, None) => member_name(source.get_field_name(&field.target_field)),
+            (transform, None) if transform.is_default_kind() => continue,
+            _ => {
+                return Err(Error::new_spanned(
+                    &field.target_field,
+                    "Fields of a `serde_json::Value` source read the object key of the same \
+                     name.\n\
+                     Rename it with `#[relate(.key)]`, or use `#[relate(default)]`",
+                ));
+            }
+        };
+        *source = FieldSource::with_transform(Transform::WithExpr(
+            quote! { ::relate::json_field($src, #key) },
+            true,
+        ));
+    }
+    Ok(())
+}
+
+/// Parse each field of a struct converted from a delimited `&str` row from
+/// its column, in order, with `relate::delimited_field`.
+///
+/// Defaulted fields take no column; other transforms have no source field to
+/// read and are an error.
+fn apply_delimited_source(
+    body: &mut DeriveBody,
+    relate_attr: &RelateAttr,
+    delimiter: &LitStr,
+) -> Result<()> {
+    if !is_str_ref(&relate_attr.source_type) {
+        return Err(Error::new_spanned(
+            delimiter,
+            "`delimiter` splits a `&str` row into columns; convert from \
+             `#[relate(&str, delimiter = \",\")]`",
+        ));
+    }
+    let DeriveBody::Struct(fields) = body else {
+        return Err(Error::new_spanned(
+            delimiter,
+            "`delimiter` is only supported on structs",
+        ));
+    };
+    if relate_attr.bidirectional {
+        return Err(Error::new_spanned(
+            delimiter,
+            "`delimiter` can't be combined with `both`: a struct can't be converted back into a \
+             `&str` row",
+        ));
+    }
+    if let Some((keyword, ImplSet::RefOnly)) = &relate_attr.impls {
+        return Err(Error::new_spanned(
+            keyword,
+            "`delimiter` converts the `&str` row by value and can't be combined with `ref_only`",
+        ));
+    }
+    if let Some(also_arc) = &relate_attr.also_arc {
+        return Err(Error::new_spanned(
+            also_arc,
+            "`also_arc` converts through `TryFrom<&Source>`, which `delimiter` doesn't generate",
+        ));
+    }
+
+    let mut index = 0usize;
+    for field in fields {
+        let source = &mut field.source;
+        if source.transform.is_default_kind() {
+            continue;
+        }
+        if !source.transform.is_identity() || source.field_name.is_some() {
+            return Err(Error::new_spanned(
+                &field.target_field,
+                "Fields of a delimited `&str` source parse their column, in order.\n\
+                 Use `#[relate(default)]` for a field without a column",
+            ));
+        }
+        let name = member_name(&field.target_field);
+        *source = FieldSource::with_transform(Transform::WithExpr(
+            quote! { ::relate::delimited_field($src, #delimiter, #index, #name) },
+            true,
+        ));
+        index += 1;
+    }
+    Ok(())
+}
+
+/// Check for a `&str` source, which `delimiter = "..."` splits into columns.
+fn is_str_ref(ty: &Type) -> bool {
+    let Type::Reference(reference) = ty else {
+        return false;
+    };
+    matches!(&*reference.elem, Type::Path(path) if path.path.is_ident("str"))
+}
+
+/// Map the fields of a struct converted from a plain tuple
+/// (`#[relate((String, i32))]`) positionally: the Nth field that reads the
+/// source reads `src.N`.
+///
+/// Fields with their own source field are left alone, and defaulted fields
+/// take no position.
+fn apply_tuple_source(body: &mut DeriveBody, source_type: &Type) {
+    let (Type::Tuple(_), DeriveBody::Struct(fields)) = (source_type, body) else {
+        return;
+    };
+    let mut index = 0usize;
+    for field in fields {
+        let source = &mut field.source;
+        if !source.reads_field() {
+            continue;
+        }
+        if source.field_name.is_none() {
+            source.field_name = Some(Member::Unnamed(index.into()));
+        }
+        index += 1;
+    }
+}
+
 /// Determine the conversion mode based on explicit markers, fields, and error
 /// type.
 fn determine_conversion_mode(
-    fields: &[FieldMapping],
+    body: &DeriveBody,
     explicit_error: Option<Type>,
     force_try_from: bool,
 ) -> ConversionMode {
@@ -63,7 +936,7 @@ fn determine_conversion_mode(
     }
 
     // Auto-detect: scan for fallible transforms (containing `?`)
-    let has_fallible = fields.iter().any(|f| f.source.transform.is_fallible());
+    let has_fallible = body.all_fields().any(|f| f.source.transform.is_fallible());
 
     if has_fallible {
         ConversionMode::Fallible(None) // Use default ConversionError
@@ -72,20 +945,118 @@ fn determine_conversion_mode(
     }
 }
 
-/// Parse the #[relate(...)] attribute on the struct.
-fn parse_from_attr(attrs: &[Attribute]) -> Result<RelateAttr> {
+/// Parse every #[relate(...)] attribute on the struct, one per source type.
+fn parse_from_attrs(attrs: &[Attribute], target_name: &Ident) -> Result<Vec<RelateAttr>> {
+    let mut relate_attrs: Vec<RelateAttr> = Vec::new();
     for attr in attrs {
-        if attr.path().is_ident("relate") {
-            return attr.parse_args();
+        if !attr.path().is_ident("relate") {
+            continue;
+        }
+        let relate_attr: RelateAttr = attr.parse_args()?;
+        if relate_attrs
+            .iter()
+            .any(|existing| same_type(&existing.source_type, &relate_attr.source_type))
+        {
+            return Err(Error::new_spanned(
+                &relate_attr.source_type,
+                "Duplicate source type: each #[relate(SourceType)] attribute needs a distinct \
+                 source",
+            ));
         }
+        relate_attrs.push(relate_attr);
     }
 
-    Err(Error::new(
-        proc_macro2::Span::call_site(),
-        "Missing #[relate(SourceType)] attribute.\n\
-         Add `#[relate(SourceType)]` above your struct, where SourceType is the struct to convert from.\n\
-         Example: #[relate(User)] or #[relate(User, both)] for bidirectional",
-    ))
+    if relate_attrs.is_empty() {
+        return Err(Error::new_spanned(
+            target_name,
+            "Missing #[relate(SourceType)] attribute.\n\
+             Add `#[relate(SourceType)]` above your struct, where SourceType is the struct to convert from.\n\
+             Example: #[relate(User)] or #[relate(User, both)] for bidirectional",
+        ));
+    }
+    Ok(relate_attrs)
+}
+
+/// Check whether two types are written the same way.
+fn same_type(a: &Type, b: &Type) -> bool {
+    a.to_token_stream().to_string() == b.to_token_stream().to_string()
+}
+
+/// The source a conversion is being parsed for, among all derived sources.
+struct SourceScope<'a> {
+    /// The source type of the current conversion
+    source:  &'a Type,
+    /// Every source type on the derive, for validating `from = Source`
+    sources: &'a [Type],
+}
+
+impl SourceScope<'_> {
+    /// Select the #[relate(...)] attribute content that applies to this source.
+    ///
+    /// An attribute scoped with `#[relate(from = Source, ...)]` overrides an
+    /// unscoped one for that source; attributes scoped to other sources are
+    /// skipped. Returns `None` if no attribute applies.
+    fn select(&self, attrs: &[Attribute]) -> Result<Option<TokenStream>> {
+        let mut unscoped = None;
+        let mut scoped = None;
+
+        for attr in attrs {
+            if !attr.path().is_ident("relate") {
+                continue;
+            }
+            let (from, tokens) = match &attr.meta {
+                Meta::List(list) => split_source_scope.parse2(list.tokens.clone())?,
+                // #[relate] with no args
+                _ => (None, TokenStream::new()),
+            };
+
+            let slot = match from {
+                None => &mut unscoped,
+                Some(from) => {
+                    if !self.sources.iter().any(|source| same_type(source, &from)) {
+                        return Err(Error::new_spanned(
+                            from,
+                            "`from = ...` must name one of the #[relate(SourceType)] sources",
+                        ));
+                    }
+                    if !same_type(self.source, &from) {
+                        continue;
+                    }
+                    &mut scoped
+                }
+            };
+            if slot.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Duplicate #[relate(...)] attribute.\n\
+                     Use `#[relate(from = Source, ...)]` to give each source its own mapping",
+                ));
+            }
+            *slot = Some(tokens);
+        }
+
+        Ok(scoped.or(unscoped))
+    }
+}
+
+/// Split a leading `from = Source,` off attribute content.
+fn split_source_scope(input: syn::parse::ParseStream) -> Result<(Option<Type>, TokenStream)> {
+    let is_scoped = input
+        .fork()
+        .parse::<Ident>()
+        .is_ok_and(|ident| ident == "from")
+        && input.peek2(Token![=]);
+    if !is_scoped {
+        return Ok((None, input.parse()?));
+    }
+
+    input.parse::<Ident>()?;
+    input.parse::<Token![=]>()?;
+    let from: Type = input.parse()?;
+    if !input.is_empty() {
+        input.parse::<Token![,]>()?;
+    }
+    Ok((Some(from), input.parse()?))
 }
 
 /// Parsed struct-level #[relate(...)] attribute.
@@ -97,6 +1068,31 @@ fn parse_from_attr(attrs: &[Attribute]) -> Result<RelateAttr> {
 /// - `#[relate(SourceType, move)]`
 /// - `#[relate(SourceType, try_from)]`
 /// - `#[relate(SourceType, error = MyError)]`
+/// - `#[relate(SourceType, collect_errors)]`
+/// - `#[relate(SourceType, method = to_dto)]`
+/// - `#[relate(SourceType, where = "T: Into<U>")]`
+/// - `#[relate(SourceType, flatten(meta: created_at, updated_at))]`
+/// - `#[relate(SourceType, strip_prefix = "db_")]`
+/// - `#[relate(SourceType, try_from, context = "parsing Config")]`
+/// - `#[relate(SourceType, try_from, validate = |t: &Target| t.port != 0)]`
+/// - `#[relate(SourceType, no_clone)]`
+/// - `#[relate(SourceType, owned_only)]` / `#[relate(SourceType, ref_only)]`
+/// - `#[relate(SourceType, also_arc)]`
+/// - `#[relate(SourceType, also_option)]`
+/// - `#[relate(SourceType, both, roundtrip_check)]`
+/// - `#[relate(SourceType, inline)]`
+/// - `#[relate(SourceType, copy, const)]`
+/// - `#[relate(&str, delimiter = ",")]`
+/// - `#[relate(SourceType, try_from, try_into_self)]`
+/// - `#[relate(SourceType, allow_missing)]`
+/// - `#[relate(SourceType, base = Self::default())]`
+/// - `#[relate(SourceType, debug_expand)]`
+/// - `#[relate(SourceType, rename_fields(user_name = name, mail = email))]`
+/// - `#[relate(SourceType, optionalize)]` / `#[relate(SourceType,
+///   deoptionalize)]`
+/// - `#[relate(Patch, apply_patch, base = Self::default())]`
+/// - `#[relate(SourceType, both, reverse_cloned)]` (also `reverse_copy`,
+///   `reverse_move`)
 /// - Combinations: `#[relate(SourceType, both, cloned, error = MyError)]`
 struct RelateAttr {
     source_type:    Type,
@@ -104,6 +1100,91 @@ struct RelateAttr {
     clone_mode:     CloneMode,
     error_type:     Option<Type>,
     force_try_from: bool,
+    /// The `collect_errors` keyword, if present
+    collect_errors: Option<Ident>,
+    /// Inherent method name from `method = name`
+    method:         Option<Ident>,
+    /// Extra bounds from `where = "..."`, added to the generated impls
+    bounds:         Vec<WherePredicate>,
+    /// Nested source fields from `flatten(outer: a, b)`
+    flatten:        Vec<FlattenGroup>,
+    /// Source-to-target field renames from `rename_fields(old = new, ..)`
+    rename_fields:  Vec<RenamedField>,
+    /// Source field prefix from `strip_prefix = "..."`
+    strip_prefix:   Option<LitStr>,
+    /// Error label from `context = "..."`
+    context:        Option<LitStr>,
+    /// Whole-value check from `validate = predicate`
+    validate:       Option<Expr>,
+    /// Struct update base from `base = expr`
+    base:           Option<Expr>,
+    /// Forbid implicit clones in the owned impl (`no_clone`)
+    no_clone:       bool,
+    /// The `owned_only` / `ref_only` keyword, if present
+    impls:          Option<(Ident, ImplSet)>,
+    /// The `also_arc` keyword, if present
+    also_arc:       Option<Ident>,
+    /// The `also_option` keyword, if present
+    also_option:    Option<Ident>,
+    /// The `roundtrip_check` keyword, if present
+    roundtrip:      Option<Ident>,
+    /// Mark the generated functions `#[inline]` (`inline`)
+    inline:         bool,
+    /// The `const` keyword, if present
+    const_fn:       Option<Ident>,
+    /// Column separator of a `&str` source from `delimiter = "..."`
+    delimiter:      Option<LitStr>,
+    /// The `try_into_self` keyword, if present
+    try_into_self:  Option<Ident>,
+    /// The `allow_missing` keyword, if present
+    allow_missing:  Option<Ident>,
+    /// The `debug_expand` keyword, if present
+    debug_expand:   Option<Ident>,
+    /// The `optionalize` keyword, if present
+    optionalize:    Option<Ident>,
+    /// The `deoptionalize` keyword, if present
+    deoptionalize:  Option<Ident>,
+    /// The `apply_patch` keyword, if present
+    apply_patch:    Option<Ident>,
+    /// The `reverse_cloned` / `reverse_copy` / `reverse_move` keyword and the
+    /// reverse impls' clone mode
+    reverse_mode:   Option<(Ident, CloneMode)>,
+    /// The `wraps(..)` keyword and the field error types converted into the
+    /// custom error type
+    wraps:          Option<(Ident, Vec<Type>)>,
+}
+
+/// A `flatten(outer: a, b)` group: target fields read from `src.outer`.
+struct FlattenGroup {
+    outer:  Ident,
+    fields: Vec<Ident>,
+}
+
+impl Parse for FlattenGroup {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let outer: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let fields = Punctuated::<Ident, Token![,]>::parse_separated_nonempty(input)?;
+        Ok(Self {
+            outer,
+            fields: fields.into_iter().collect(),
+        })
+    }
+}
+
+/// A `rename_fields(old = new)` entry: target field `new` reads `src.old`.
+struct RenamedField {
+    source: Ident,
+    target: Ident,
+}
+
+impl Parse for RenamedField {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let source = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let target = input.parse()?;
+        Ok(Self { source, target })
+    }
 }
 
 impl Parse for RelateAttr {
@@ -112,8 +1193,36 @@ impl Parse for RelateAttr {
 
         let mut bidirectional = false;
         let mut clone_mode = CloneMode::Auto;
+        let mut clone_keyword = None;
         let mut error_type = None;
+        let mut error_keyword = None;
         let mut force_try_from = false;
+        let mut collect_errors = None;
+        let mut method = None;
+        let mut bounds = Vec::new();
+        let mut flatten = Vec::new();
+        let mut rename_fields = Vec::new();
+        let mut strip_prefix = None;
+        let mut context = None;
+        let mut validate = None;
+        let mut base = None;
+        let mut no_clone = false;
+        let mut impls: Option<(Ident, ImplSet)> = None;
+        let mut also_arc = None;
+        let mut also_option = None;
+        let mut roundtrip = None;
+        let mut inline = false;
+        let mut const_fn = None;
+        let mut delimiter = None;
+        let mut try_into_self = None;
+        let mut allow_missing = None;
+        let mut debug_expand = None;
+        let mut optionalize = None;
+        let mut deoptionalize = None;
+        let mut apply_patch = None;
+        let mut reverse_mode = CloneMode::Auto;
+        let mut reverse_keyword = None;
+        let mut wraps = None;
 
         // Parse optional modifiers
         while input.peek(Token![,]) {
@@ -121,8 +1230,32 @@ impl Parse for RelateAttr {
 
             // Handle `move` keyword specially since it's a reserved keyword
             if input.peek(Token![move]) {
-                input.parse::<Token![move]>()?;
-                clone_mode = CloneMode::Move;
+                let token = input.parse::<Token![move]>()?;
+                let keyword = Ident::new("move", token.span);
+                set_clone_mode(
+                    &mut clone_keyword,
+                    &mut clone_mode,
+                    keyword,
+                    CloneMode::Move,
+                )?;
+                continue;
+            }
+
+            // Handle `const` keyword specially since it's a reserved keyword
+            if input.peek(Token![const]) {
+                let token = input.parse::<Token![const]>()?;
+                const_fn = Some(Ident::new("const", token.span));
+                continue;
+            }
+
+            // Handle `where = "..."` specially since `where` is a reserved keyword
+            if input.peek(Token![where]) {
+                input.parse::<Token![where]>()?;
+                input.parse::<Token![=]>()?;
+                let lit: LitStr = input.parse()?;
+                let predicates =
+                    lit.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+                bounds.extend(predicates);
                 continue;
             }
 
@@ -132,19 +1265,104 @@ impl Parse for RelateAttr {
                 let ident_str = ident.to_string();
 
                 match ident_str.as_str() {
+                    "allow_missing" => allow_missing = Some(ident),
+                    "also_arc" => also_arc = Some(ident),
+                    "also_option" => also_option = Some(ident),
+                    "apply_patch" => apply_patch = Some(ident),
+                    "base" => {
+                        input.parse::<Token![=]>()?;
+                        base = Some(input.parse()?);
+                    }
                     "both" => bidirectional = true,
-                    "cloned" => clone_mode = CloneMode::Cloned,
-                    "copy" => clone_mode = CloneMode::Copy,
+                    "collect_errors" => collect_errors = Some(ident),
+                    "cloned" | "copy" => {
+                        let mode = if ident == "cloned" {
+                            CloneMode::Cloned
+                        } else {
+                            CloneMode::Copy
+                        };
+                        set_clone_mode(&mut clone_keyword, &mut clone_mode, ident, mode)?;
+                    }
+                    "context" => {
+                        input.parse::<Token![=]>()?;
+                        context = Some(input.parse()?);
+                    }
+                    "debug_expand" => debug_expand = Some(ident),
+                    "delimiter" => {
+                        input.parse::<Token![=]>()?;
+                        delimiter = Some(input.parse()?);
+                    }
+                    "deoptionalize" => deoptionalize = Some(ident),
                     "error" => {
                         input.parse::<Token![=]>()?;
-                        error_type = Some(input.parse()?);
+                        set_error_type(&mut error_keyword, &mut error_type, ident, input.parse()?)?;
+                    }
+                    "no_clone" => no_clone = true,
+                    "optionalize" => optionalize = Some(ident),
+                    "roundtrip_check" => roundtrip = Some(ident),
+                    "reverse_cloned" | "reverse_copy" | "reverse_move" => {
+                        let mode = match ident_str.as_str() {
+                            "reverse_cloned" => CloneMode::Cloned,
+                            "reverse_copy" => CloneMode::Copy,
+                            _ => CloneMode::Move,
+                        };
+                        set_clone_mode(&mut reverse_keyword, &mut reverse_mode, ident, mode)?;
+                    }
+                    "owned_only" | "ref_only" => {
+                        let set = if ident == "owned_only" {
+                            ImplSet::OwnedOnly
+                        } else {
+                            ImplSet::RefOnly
+                        };
+                        if let Some((previous, _)) = &impls {
+                            if *previous != ident {
+                                return Err(Error::new_spanned(
+                                    ident,
+                                    "`owned_only` and `ref_only` can't be combined",
+                                ));
+                            }
+                        }
+                        impls = Some((ident, set));
+                    }
+                    "inline" => inline = true,
+                    "try_into_self" => try_into_self = Some(ident),
+                    "flatten" => {
+                        let content;
+                        syn::parenthesized!(content in input);
+                        flatten.push(content.parse()?);
+                    }
+                    "rename_fields" => {
+                        let content;
+                        syn::parenthesized!(content in input);
+                        rename_fields.extend(
+                            Punctuated::<RenamedField, Token![,]>::parse_terminated(&content)?,
+                        );
+                    }
+                    "strip_prefix" => {
+                        input.parse::<Token![=]>()?;
+                        strip_prefix = Some(input.parse()?);
+                    }
+                    "method" => {
+                        input.parse::<Token![=]>()?;
+                        method = Some(input.parse()?);
+                    }
+                    "validate" => {
+                        input.parse::<Token![=]>()?;
+                        validate = Some(input.parse()?);
+                    }
+                    "wraps" => {
+                        let content;
+                        syn::parenthesized!(content in input);
+                        let types = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+                        wraps = Some((ident, types.into_iter().collect()));
                     }
                     "try_from" => {
                         // Explicit try_from marker forces TryFrom generation
                         // Optionally with `= ErrorType` for custom error
                         if input.peek(Token![=]) {
                             input.parse::<Token![=]>()?;
-                            error_type = Some(input.parse()?);
+                            let ty = input.parse()?;
+                            set_error_type(&mut error_keyword, &mut error_type, ident, ty)?;
                         }
                         // Always force TryFrom when keyword is present
                         force_try_from = true;
@@ -152,7 +1370,16 @@ impl Parse for RelateAttr {
                     _ => {
                         let msg = format!(
                             "Unknown option `{ident}`.\n\
-                             Valid options: `both`, `cloned`, `copy`, `move`, `try_from`, `error = Type`\n\
+                             Valid options: `both`, `cloned`, `copy`, `move`, `try_from`, `error = Type`,\n\
+                             `collect_errors`, `method = name`, `where = \"T: Bound\"`,\n\
+                             `flatten(field: nested, ..)`, `strip_prefix = \"prefix\"`,\n\
+                             `context = \"label\"`, `no_clone`, `owned_only`, `ref_only`,\n\
+                             `also_arc`, `also_option`, `roundtrip_check`, `inline`, `const`,\n\
+                             `allow_missing`, `debug_expand`, `validate = predicate`,\n\
+                             `base = expr`, `rename_fields(old = new, ..)`, `optionalize`,\n\
+                             `deoptionalize`, `reverse_cloned`, `reverse_copy`, `reverse_move`,\n\
+                             `wraps(ErrorType, ..)`, `delimiter = \",\"`, `try_into_self`,\n\
+                             `apply_patch`\n\
                              Example: #[relate(SourceType, both, cloned)]"
                         );
                         return Err(Error::new_spanned(ident, msg));
@@ -169,66 +1396,213 @@ impl Parse for RelateAttr {
             clone_mode,
             error_type,
             force_try_from,
+            collect_errors,
+            method,
+            bounds,
+            flatten,
+            rename_fields,
+            strip_prefix,
+            context,
+            validate,
+            base,
+            no_clone,
+            impls,
+            also_arc,
+            also_option,
+            roundtrip,
+            inline,
+            const_fn,
+            delimiter,
+            try_into_self,
+            allow_missing,
+            debug_expand,
+            optionalize,
+            deoptionalize,
+            apply_patch,
+            reverse_mode: reverse_keyword.map(|keyword| (keyword, reverse_mode)),
+            wraps,
         })
     }
 }
 
-/// Parse struct fields and their #[relate(...)] attributes.
-fn parse_fields(fields: Fields) -> Result<Vec<FieldMapping>> {
-    let Fields::Named(named) = fields else {
-        return Err(Error::new(
-            proc_macro2::Span::call_site(),
-            "Relate derive only supports structs with named fields",
+/// Record a struct-level clone mode (`cloned`, `copy`, `move`), rejecting a
+/// second, different one.
+fn set_clone_mode(
+    keyword: &mut Option<Ident>,
+    clone_mode: &mut CloneMode,
+    new_keyword: Ident,
+    mode: CloneMode,
+) -> Result<()> {
+    if let Some(previous) = keyword
+        .as_ref()
+        .filter(|previous| **previous != new_keyword)
+    {
+        return Err(Error::new_spanned(
+            &new_keyword,
+            format!("`{previous}` and `{new_keyword}` are conflicting clone modes; keep only one"),
+        ));
+    }
+    *clone_mode = mode;
+    *keyword = Some(new_keyword);
+    Ok(())
+}
+
+/// Record the error type from `error = Type` or `try_from = Type`, rejecting
+/// a second one and naming both.
+fn set_error_type(
+    keyword: &mut Option<Ident>,
+    error_type: &mut Option<Type>,
+    new_keyword: Ident,
+    ty: Type,
+) -> Result<()> {
+    if let (Some(previous), Some(previous_ty)) = (keyword.as_ref(), error_type.as_ref()) {
+        return Err(Error::new_spanned(
+            &ty,
+            format!(
+                "Conflicting error types: `{previous} = {}` and `{new_keyword} = {}`.\n\
+                 Specify the error type only once",
+                previous_ty.to_token_stream(),
+                ty.to_token_stream(),
+            ),
         ));
+    }
+    *error_type = Some(ty);
+    *keyword = Some(new_keyword);
+    Ok(())
+}
+
+/// How `parse_fields` treats fields without a mapping of their own.
+struct FieldRules {
+    /// The source of a field without an attribute: auto-mapped by name, or
+    /// defaulted under `allow_missing`
+    missing:   fn() -> FieldSource,
+    /// `skip` fields are left out, for `..base` to fill (`base = expr`)
+    skippable: bool,
+}
+
+impl FieldRules {
+    /// Enum variants map unattributed fields by name and can't skip.
+    const VARIANT: Self = Self {
+        missing:   FieldSource::auto,
+        skippable: false,
     };
+}
 
-    named
-        .named
-        .into_iter()
-        .map(|field| {
-            // SAFETY: We validated this is a named struct above, so ident is always present
-            let target_field = field.ident.expect("named fields always have identifiers");
-            let source = parse_field_from_attr(&field.attrs)?;
-
-            Ok(FieldMapping {
-                target_field,
-                source,
-            })
-        })
-        .collect()
+/// Parse struct fields and their #[relate(...)] attributes.
+///
+/// Tuple fields are mapped positionally: element `N` reads `src.N`. Fields
+/// marked `skip` are left out of the result. Unit structs are rejected
+/// before this, and unit variants have no fields to parse.
+fn parse_fields(
+    fields: &Fields,
+    scope: &SourceScope,
+    rules: &FieldRules,
+) -> Result<Vec<FieldMapping>> {
+    let fields = match fields {
+        Fields::Named(named) => &named.named,
+        Fields::Unnamed(unnamed) => &unnamed.unnamed,
+        Fields::Unit => return Ok(Vec::new()),
+    };
+
+    let mut mappings = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let target_field = field
+            .ident
+            .clone()
+            .map_or_else(|| Member::Unnamed(index.into()), Member::Named);
+        let Some(mut source) = parse_field_from_attr(field, scope, rules.missing)? else {
+            if rules.skippable {
+                continue;
+            }
+            return Err(Error::new_spanned(
+                &target_field,
+                "`skip` leaves the field out, for `..base` to fill; add `base = expr` to the \
+                 struct's `#[relate(Source, ..)]`, or use `#[relate(default)]`",
+            ));
+        };
+        resolve_map_collection(&target_field, &field.ty, &mut source)?;
+
+        mappings.push(FieldMapping {
+            target_field,
+            source,
+        });
+    }
+    target_ref_order(&mappings)?;
+    Ok(mappings)
 }
 
-/// Parse the #[relate(...)] attribute on a field.
-fn parse_field_from_attr(attrs: &[Attribute]) -> Result<FieldSource> {
-    for attr in attrs {
-        if attr.path().is_ident("relate") {
-            return parse_field_source(attr);
+/// Parse an enum variant and its optional `#[relate(SourceVariant)]` rename.
+fn parse_variant(variant: &syn::Variant, scope: &SourceScope) -> Result<VariantMapping> {
+    let source_variant = parse_variant_rename(&variant.attrs, scope)?;
+
+    let fields = match &variant.fields {
+        Fields::Unit => VariantFields::Unit,
+        unnamed @ Fields::Unnamed(_) => {
+            VariantFields::Tuple(parse_fields(unnamed, scope, &FieldRules::VARIANT)?)
         }
-    }
+        named @ Fields::Named(_) => {
+            VariantFields::Named(parse_fields(named, scope, &FieldRules::VARIANT)?)
+        }
+    };
 
-    // No attribute = auto-map by same name
-    Ok(FieldSource::auto())
+    Ok(VariantMapping {
+        source_variant: source_variant.unwrap_or_else(|| variant.ident.clone()),
+        target_variant: variant.ident.clone(),
+        fields,
+    })
 }
 
-/// Parse the content of a field's #[relate(...)] attribute.
-fn parse_field_source(attr: &Attribute) -> Result<FieldSource> {
-    let Meta::List(list) = &attr.meta else {
-        // #[relate] with no args = auto
-        return Ok(FieldSource::auto());
+/// Parse `#[relate(SourceVariant)]` on an enum variant.
+fn parse_variant_rename(attrs: &[Attribute], scope: &SourceScope) -> Result<Option<Ident>> {
+    let Some(tokens) = scope.select(attrs)? else {
+        return Ok(None);
     };
+    syn::parse2(tokens).map(Some).map_err(|err| {
+        Error::new(
+            err.span(),
+            "Invalid variant attribute.\n\
+             Use #[relate(SourceVariant)] to map from a differently named source variant",
+        )
+    })
+}
+
+/// Parse the #[relate(...)] attribute on a field, `None` for `skip`.
+fn parse_field_from_attr(
+    field: &syn::Field,
+    scope: &SourceScope,
+    missing: fn() -> FieldSource,
+) -> Result<Option<FieldSource>> {
+    match scope.select(&field.attrs)? {
+        Some(tokens) => parse_field_source(tokens),
+        // A `PhantomData` marker has no source field to read
+        None if is_phantom_data(&field.ty) => Ok(Some(FieldSource::default_expr(
+            syn::parse_quote!(::core::marker::PhantomData),
+        ))),
+        // No attribute = auto-map by same name, or default (`allow_missing`)
+        None => Ok(Some(missing())),
+    }
+}
 
-    let tokens = &list.tokens;
+/// Parse the content of a field's #[relate(...)] attribute, `None` for
+/// `skip`.
+fn parse_field_source(tokens: TokenStream) -> Result<Option<FieldSource>> {
+    // #[relate] with no args = auto
+    if tokens.is_empty() {
+        return Ok(Some(FieldSource::auto()));
+    }
 
     // Handle special single-token keywords using structured parsing
-    // Both "default" and "skip" mean the same: use Default::default()
     if let Ok(ident) = syn::parse2::<Ident>(tokens.clone()) {
-        if ident == "default" || ident == "skip" {
-            return Ok(FieldSource::default_value());
+        if ident == "default" {
+            return Ok(Some(FieldSource::default_value()));
+        }
+        if ident == "skip" {
+            return Ok(None);
         }
     }
 
     // Parse the content
-    syn::parse2::<FieldSourceContent>(tokens.clone()).map(|c| c.source)
+    syn::parse2::<FieldSourceContent>(tokens).map(|c| Some(c.source))
 }
 
 /// Helper to parse field source content.
@@ -238,7 +1612,30 @@ struct FieldSourceContent {
 
 impl Parse for FieldSourceContent {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        // Handle `move` keyword first (reserved, needs special handling)
+        let Self { mut source } = Self::parse_source(input)?;
+        source.map_err = parse_trailing_map_err(input, &source.transform)?;
+        Ok(Self { source })
+    }
+}
+
+impl FieldSourceContent {
+    /// Parse the field source itself, before any trailing `, map_err = ...`.
+    fn parse_source(input: syn::parse::ParseStream) -> Result<Self> {
+        // Closure applied to the same-named field: `|x| expr`
+        if input.peek(Token![|]) || (input.peek(Token![move]) && input.peek2(Token![|])) {
+            let closure: syn::ExprClosure = input.parse()?;
+            if closure.inputs.len() != 1 {
+                return Err(Error::new_spanned(
+                    &closure.inputs,
+                    "A field closure takes exactly one parameter, the same-named source field",
+                ));
+            }
+            let mut source = FieldSource::with_transform(Transform::Closure(closure));
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        // Handle `move` keyword (reserved, needs special handling)
         if input.peek(Token![move]) {
             input.parse::<Token![move]>()?;
             let mut source = FieldSource::auto();
@@ -251,9 +1648,52 @@ impl Parse for FieldSourceContent {
             return parse_collection_map(input);
         }
 
+        // Check for map values: map[_.field] keeps keys, maps values
+        if peek_map_values(input) {
+            return parse_map_values(input);
+        }
+
+        // Check for an optional collection: opt[_.field] keeps `None`
+        if peek_option_collection(input) {
+            input.parse::<Ident>()?; // consume "opt"
+            let (tokens, index) = parse_sequence_map_body(input)?;
+            let mut source =
+                FieldSource::with_transform(Transform::OptionCollectionMap(tokens, index));
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        // Cast: `as Type` is `with = _ as Type`
+        if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            let ty: Type = input.parse()?;
+            let mut source =
+                FieldSource::with_transform(Transform::WithExpr(quote! { _ as #ty }, false));
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
         // Check for chained access: `.path.field` or `_.method()` or `.path._`
         if input.peek(Token![.]) || input.peek(Token![_]) {
             let (tokens, fallible) = parse_tokens_until_terminator(input, false)?;
+            // `.primary, default_if_none = .fallback`
+            if peek_trailing_option(input, "default_if_none") {
+                let primary = plain_source_field(&tokens)
+                    .filter(|_| !fallible)
+                    .ok_or_else(|| {
+                        Error::new_spanned(
+                            &tokens,
+                            "`default_if_none` needs a plain source field to read first, like \
+                         `.primary_id`",
+                        )
+                    })?;
+                input.parse::<Token![,]>()?;
+                input.parse::<Ident>()?;
+                let mut source = FieldSource::with_transform(parse_default_if_none(input)?);
+                source.field_name = Some(primary);
+                source.clone_mode = parse_trailing_clone_mode(input, false)?;
+                return Ok(Self { source });
+            }
             let clone_mode = parse_trailing_clone_mode(input, false)?;
             let mut source = FieldSource::with_transform(Transform::WithExpr(tokens, fallible));
             source.clone_mode = clone_mode;
@@ -269,8 +1709,26 @@ impl Parse for FieldSourceContent {
                  - `.field` or `.nested.field` - access source field\n\
                  - `_.method()` - call method on same-named field\n\
                  - `with = expr` - complex expression using `.field` or `_`\n\
+                 - `with_fn = path` - call `path(field)`, or `path(&field)` from a reference\n\
+                 - `try_into = path` - call a fallible `path(field)?` (triggers TryFrom)\n\
+                 - `getter = method` - call `src.method()` instead of reading a field\n\
+                 - `|x| expr` - apply a closure to the same-named field\n\
+                 - `as Type` - cast the same-named field, e.g. an enum to its discriminant\n\
                  - `default` or `default = expr` - use default value\n\
+                 - `default_with = path` - call a factory function `path()`\n\
+                 - `default_if_none = .field` - fall back to another `Option` field\n\
+                 - `required` - require an `Option` source field to be `Some`\n\
+                 - `into` - convert the same-named field with `Into`\n\
+                 - `into_owned` - turn a `Cow` field into its owned value\n\
+                 - `vec` - copy an array or slice field into a `Vec`\n\
+                 - `some` - wrap the same-named field in `Some`\n\
+                 - `boxed`, `rc`, `arc` - wrap in a smart pointer; `unbox` - move out of a `Box`\n\
+                 - `unwrap_or_default` - unwrap an `Option` field or use the default\n\
+                 - `secs_to_duration`, `duration_to_secs` - `u64` seconds to `Duration`, and back\n\
+                 - `skip_if = .cond, default = expr` - default when the condition holds\n\
                  - `[_.field]` - map over collection\n\
+                 - `map[_.field]` - map over map values, keeping keys\n\
+                 - `opt[_.field]` - map over an `Option` collection, keeping `None`\n\
                  Example: #[relate(.data.name)] or #[relate(with = .a + .b)]",
             ));
         }
@@ -291,6 +1749,22 @@ impl Parse for FieldSourceContent {
             });
         }
 
+        if ident == "default_with" {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let path: syn::Path = input.parse()?;
+            return Ok(Self {
+                source: FieldSource::default_fn(path),
+            });
+        }
+
+        if ident == "default_if_none" {
+            input.parse::<Ident>()?;
+            let mut source = FieldSource::with_transform(parse_default_if_none(input)?);
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
         if ident == "with" {
             input.parse::<Ident>()?; // consume "with"
             input.parse::<Token![=]>()?;
@@ -301,6 +1775,101 @@ impl Parse for FieldSourceContent {
             return Ok(Self { source });
         }
 
+        if ident == "with_fn" {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let path: syn::Path = input.parse()?;
+            let mut source = FieldSource::with_transform(Transform::FnCall(path));
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "getter" {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let mut source = FieldSource::with_transform(Transform::Getter(input.parse()?));
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "try_into" {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let path: syn::Path = input.parse()?;
+            let mut source = FieldSource::with_transform(Transform::TryFnCall(path));
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "required" {
+            input.parse::<Ident>()?;
+            let mut source = FieldSource::with_transform(Transform::Required);
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "into" {
+            input.parse::<Ident>()?;
+            let mut source = FieldSource::with_transform(Transform::Into);
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "into_owned" {
+            input.parse::<Ident>()?;
+            let mut source = FieldSource::with_transform(Transform::IntoOwned);
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "vec" {
+            input.parse::<Ident>()?;
+            let mut source = FieldSource::with_transform(Transform::ToVec);
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "some" {
+            input.parse::<Ident>()?;
+            let mut source = FieldSource::with_transform(Transform::WrapSome);
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        let pointer = Pointer::from_keyword(&ident.to_string());
+        if pointer.is_some() || ident == "unbox" {
+            input.parse::<Ident>()?;
+            let transform = pointer.map_or(Transform::Unbox, Transform::Wrap);
+            let mut source = FieldSource::with_transform(transform);
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "unwrap_or_default" {
+            input.parse::<Ident>()?;
+            let mut source = FieldSource::unwrap_or_default();
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "secs_to_duration" || ident == "duration_to_secs" {
+            input.parse::<Ident>()?;
+            let mut source = if ident == "secs_to_duration" {
+                FieldSource::secs_to_duration()
+            } else {
+                FieldSource::duration_to_secs()
+            };
+            source.clone_mode = parse_trailing_clone_mode(input, false)?;
+            return Ok(Self { source });
+        }
+
+        if ident == "skip_if" {
+            input.parse::<Ident>()?;
+            return Ok(Self {
+                source: FieldSource::with_transform(parse_skip_if(input, false)?),
+            });
+        }
+
         if ident == "cloned" {
             input.parse::<Ident>()?;
             let mut source = FieldSource::auto();
@@ -315,35 +1884,121 @@ impl Parse for FieldSourceContent {
             return Ok(Self { source });
         }
 
+        if ident == "arc_clone" {
+            input.parse::<Ident>()?;
+            let mut source = FieldSource::auto();
+            source.clone_mode = Some(CloneMode::ArcClone);
+            return Ok(Self { source });
+        }
+
         // Unknown identifier
         Err(Error::new_spanned(
             &ident,
             format!(
                 "Unknown modifier `{}`.\n\
-                 Valid options: `default`, `with`, `cloned`, `copy`",
+                 Valid options: `default`, `default_with`, `default_if_none`, `with`, `with_fn`,\n\
+                 `try_into`, `getter`, `required`, `into`, `into_owned`, `vec`, `some`,\n\
+                 `unwrap_or_default`, `boxed`, `rc`, `arc`, `unbox`, `skip_if`, `cloned`, `copy`,\n\
+                 `arc_clone`, `secs_to_duration`, `duration_to_secs`",
                 ident
             ),
         ))
     }
 }
 
+/// Get the field of a plain `.field` access, the primary read of
+/// `default_if_none`.
+fn plain_source_field(tokens: &TokenStream) -> Option<Member> {
+    let parser = |input: syn::parse::ParseStream| {
+        input.parse::<Token![.]>()?;
+        input.parse::<Member>()
+    };
+    parser.parse2(tokens.clone()).ok()
+}
+
 /// Parse collection map syntax: `[_.field]` or `[.field]` shorthand
 fn parse_collection_map(input: syn::parse::ParseStream) -> Result<FieldSourceContent> {
-    let content;
-    syn::bracketed!(content in input);
-    let inner: TokenStream = content.parse()?;
-    let inner_str = inner.to_string();
-
-    // If it starts with `.`, it's shorthand: [.id.clone()] -> [_.id.clone()]
-    let tokens = if inner_str.starts_with('.') {
-        let underscore = syn::Ident::new("_", proc_macro2::Span::call_site());
-        quote::quote! { #underscore #inner }
-    } else {
-        inner
-    };
+    let (tokens, index) = parse_sequence_map_body(input)?;
+    let clone_mode = parse_trailing_clone_mode(input, false)?;
+    let mut source = FieldSource::with_transform(Transform::CollectionMap(tokens, index));
+    source.clone_mode = clone_mode;
+    Ok(FieldSourceContent { source })
+}
 
+/// Parse map value syntax: `map[_.field]` or `map[.field]` shorthand
+fn parse_map_values(input: syn::parse::ParseStream) -> Result<FieldSourceContent> {
+    input.parse::<Ident>()?; // consume "map"
+    let tokens = parse_collection_body(input)?;
     let clone_mode = parse_trailing_clone_mode(input, false)?;
-    let mut source = FieldSource::with_transform(Transform::CollectionMap(tokens));
+    let mut source = FieldSource::with_transform(Transform::MapValues(tokens));
     source.clone_mode = clone_mode;
     Ok(FieldSourceContent { source })
 }
+
+/// Resolve `[...]` on map-typed fields.
+///
+/// Iterating a map yields `(&K, &V)` pairs, so `[_.value]` would treat the
+/// entry tuple as the value; that's rejected, since `map[...]` is almost
+/// always what was meant. A bare `[_]` is the same as `map[_]`: with
+/// `cloned`, keys are cloned and values converted with `Into`.
+fn resolve_map_collection(
+    target_field: &Member,
+    ty: &Type,
+    source: &mut FieldSource,
+) -> Result<()> {
+    let Transform::CollectionMap(tokens, index) = &source.transform else {
+        return Ok(());
+    };
+    if !is_map_type(ty) || builds_entry_tuple(tokens) {
+        return Ok(());
+    }
+    if index.is_none() && is_placeholder(tokens) {
+        source.transform = Transform::MapValues(tokens.clone());
+        return Ok(());
+    }
+    Err(Error::new_spanned(
+        tokens,
+        format!(
+            "`[...]` on map field `{}` maps over `(key, value)` entries.\n\
+             Use `map[...]` to transform values and keep keys, e.g. #[relate(map[_.clone().into()])]",
+            member_name(target_field)
+        ),
+    ))
+}
+
+/// Check if a type's last path segment names a standard map type.
+fn is_map_type(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "HashMap" || segment.ident == "BTreeMap")
+}
+
+/// Check if a type's last path segment is `PhantomData`.
+fn is_phantom_data(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "PhantomData")
+}
+
+/// Check if collection tokens are a single `(key, value)` tuple expression.
+fn builds_entry_tuple(tokens: &TokenStream) -> bool {
+    let mut iter = tokens.clone().into_iter();
+    match (iter.next(), iter.next()) {
+        (Some(proc_macro2::TokenTree::Group(group)), None) => {
+            group.delimiter() == proc_macro2::Delimiter::Parenthesis
+                && group
+                    .stream()
+                    .into_iter()
+                    .any(|tt| matches!(tt, proc_macro2::TokenTree::Punct(p) if p.as_char() == ','))
+        }
+        _ => false,
+    }
+}
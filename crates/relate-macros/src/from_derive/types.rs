@@ -2,10 +2,10 @@
 //!
 //! Re-exports core types and adds derive-specific input types.
 
-use syn::{Generics, Ident, Type};
+use syn::{Expr, Generics, Ident, LitStr, Type};
 
 // Re-export core types
-pub use crate::core::{CloneMode, FieldMapping, FieldSource, Transform};
+pub use crate::core::{CloneMode, FieldMapping, FieldSource, ImplSet, Transform};
 
 /// How the conversion should be generated.
 #[derive(Debug, Clone, Default)]
@@ -17,6 +17,9 @@ pub enum ConversionMode {
     /// Generate `TryFrom` with specified error type (None = use
     /// ConversionError)
     Fallible(Option<Type>),
+    /// Generate `TryFrom` with ConversionError, evaluating every fallible
+    /// field and returning all failures as `ConversionError::Multiple`
+    CollectErrors,
 }
 
 /// Parsed input for the Relate derive macro.
@@ -24,16 +27,116 @@ pub enum ConversionMode {
 pub struct FromDeriveInput {
     /// The target struct name (the one being derived)
     pub target_name:     Ident,
-    /// The target struct's generics
+    /// The target struct's generics, with any `where = "..."` bounds merged
+    /// into its where clause
     pub target_generics: Generics,
     /// The source type to convert from
     pub source_type:     Type,
     /// Whether to generate bidirectional impls
     pub bidirectional:   bool,
-    /// Field mappings
-    pub fields:          Vec<FieldMapping>,
+    /// Struct fields or enum variants
+    pub body:            DeriveBody,
+    /// A fieldless enum converted from its integer discriminant (the source
+    /// type is a primitive integer)
+    pub discriminant:    bool,
     /// Struct-level clone mode (default for all fields)
     pub clone_mode:      CloneMode,
+    /// Clone mode of the reverse impls for `both` (`reverse_cloned`,
+    /// `reverse_copy`, `reverse_move`)
+    pub reverse_mode:    CloneMode,
     /// Conversion mode (From vs TryFrom)
     pub conversion_mode: ConversionMode,
+    /// Generate an inherent `pub fn <method>(src: &Source)` instead of trait
+    /// impls
+    pub method:          Option<Ident>,
+    /// Label wrapped around every conversion error (`context = "..."`)
+    pub context:         Option<LitStr>,
+    /// Predicate the converted value must pass (`validate = |t: &T| ..`)
+    pub validate:        Option<Expr>,
+    /// Fills the fields left out with `skip` (`base = expr`)
+    pub base:            Option<Expr>,
+    /// Whether `skip` left fields out, so the constructor ends in `..base`
+    pub fills_rest:      bool,
+    /// Which of the owned and `&Source` impls to generate (`owned_only`,
+    /// `ref_only`)
+    pub impls:           ImplSet,
+    /// Also generate `Target::from_arc(Arc<Source>) -> Arc<Target>`
+    /// (`also_arc`)
+    pub also_arc:        bool,
+    /// Also generate `Target::from_option(Option<Source>) -> Option<Target>`
+    /// (`also_option`)
+    pub also_option:     bool,
+    /// Also generate `Target::__relate_roundtrip(Source) -> bool`
+    /// (`roundtrip_check`)
+    pub roundtrip_check: bool,
+    /// Mark the generated conversion functions `#[inline]` (`inline`)
+    pub inline:          bool,
+    /// Generate `Target::const_from(Source)` as a `const fn`, or mark the
+    /// `method = name` function `const` (`const`)
+    pub const_fn:        bool,
+    /// Also generate `Source::try_into_<target>(self) -> Result<Target, E>`
+    /// (`try_into_self`)
+    pub try_into_self:   bool,
+    /// Show the generated code in a compiler warning (`debug_expand`), spanned
+    /// at the keyword
+    pub debug_expand:    Option<Ident>,
+}
+
+/// The shape being derived: a struct's fields or an enum's variants.
+#[derive(Debug)]
+pub enum DeriveBody {
+    /// Struct fields (named, or positional for tuple structs)
+    Struct(Vec<FieldMapping>),
+    /// Enum variants, matched against same-named (or renamed) source variants
+    Enum(Vec<VariantMapping>),
+}
+
+impl DeriveBody {
+    /// Iterate over every field mapping, across all variants for enums.
+    pub fn all_fields(&self) -> Box<dyn Iterator<Item = &FieldMapping> + '_> {
+        match self {
+            Self::Struct(fields) => Box::new(fields.iter()),
+            Self::Enum(variants) => Box::new(variants.iter().flat_map(|v| match &v.fields {
+                VariantFields::Tuple(fields) | VariantFields::Named(fields) => fields.as_slice(),
+                VariantFields::Unit => &[],
+            })),
+        }
+    }
+
+    /// Iterate mutably over every field mapping, across all variants for enums.
+    pub fn all_fields_mut(&mut self) -> Box<dyn Iterator<Item = &mut FieldMapping> + '_> {
+        match self {
+            Self::Struct(fields) => Box::new(fields.iter_mut()),
+            Self::Enum(variants) => {
+                Box::new(variants.iter_mut().flat_map(|v| match &mut v.fields {
+                    VariantFields::Tuple(fields) | VariantFields::Named(fields) => {
+                        fields.as_mut_slice()
+                    }
+                    VariantFields::Unit => &mut [],
+                }))
+            }
+        }
+    }
+}
+
+/// Mapping from a source enum variant to a target enum variant.
+#[derive(Debug)]
+pub struct VariantMapping {
+    /// The target variant name (the one being derived)
+    pub target_variant: Ident,
+    /// The source variant name (same as target unless renamed)
+    pub source_variant: Ident,
+    /// The variant's fields
+    pub fields:         VariantFields,
+}
+
+/// Fields of an enum variant.
+#[derive(Debug)]
+pub enum VariantFields {
+    /// `Variant`
+    Unit,
+    /// `Variant(A, B)` - mapped positionally
+    Tuple(Vec<FieldMapping>),
+    /// `Variant { a: A }` - mapped like struct fields
+    Named(Vec<FieldMapping>),
 }
@@ -52,7 +52,10 @@ use proc_macro::TokenStream;
 /// - **Semicolon terminator**: Fields end with `;` not `,`
 /// - **Unified `with =` syntax**: All transforms use `field: with = expr;`
 /// - **Collection mapping**: `field: with = [_.id];` - map over collections
-/// - **Generics support**: Works with generic structs (need `Clone` bound)
+/// - **Generics support**: Works with generic structs (need `Clone` bound);
+///   when every field is a plain identity mapping, only the ref impls require
+///   `T: Clone`. A `where` clause after the target adds bounds to every
+///   generated impl: `Batch<T: Clone> ~> Labels where T: Into<String> { .. }`
 ///
 /// ## Direction Operators
 ///
@@ -60,6 +63,42 @@ use proc_macro::TokenStream;
 /// - `~` : Generate all 4 impls (both directions, owned + ref) (bidirectional)
 /// - `~>?` : Generate `TryFrom<Source>` + `TryFrom<&Source>` (fallible forward)
 /// - `~>?[E]` : Same as `~>?` but with custom error type `E`
+/// - `~>?[Option]` : `TryFrom` with error `()`, where `?` applies to `Option`s
+///   (`_.first()?`) and `None` becomes `Err(())`
+/// - `~?` : Generate `TryFrom` in both directions (fallible bidirectional)
+/// - `~?[E]` : Same as `~?` but with custom error type `E`
+///
+/// A leading `#![default_error(MyError)]` makes `MyError` the error type of
+/// every fallible relation in the invocation that doesn't name its own.
+///
+/// Either side can be a full path (`db::User ~> api::User`, `crate::a::B<T>`).
+/// A target from another crate can't get a `From` impl; with `#![into_fn]`
+/// at the start of the body, `A ~> some_crate::UserProfile` generates a free
+/// `pub fn into_user_profile(src: A) -> UserProfile` (or
+/// `Result<UserProfile, E>`) instead of the forward impls; with `#![ref_only]`
+/// it takes `&A`.
+///
+/// ## Inline Definitions
+///
+/// Either side can be a full struct definition, emitted alongside the impls:
+///
+/// - `#[attrs] struct A ~ #[attrs] struct B { id: i32, }` - Define both with a
+///   shared field list, mapped by name
+/// - `A ~> #[attrs] struct B { id: String, } { id: with = _.to_string(); }` -
+///   Define one side; its fields come first, then the relation body
+///
+/// Field attributes (`#[serde(..)]`, doc comments) and visibility are kept on
+/// the emitted structs; a shared field list is emitted on both sides as is.
+///
+/// Without a relation body, every field of a defined target maps by name.
+/// With a defined target, `..` in the body maps the remaining fields by name:
+/// `A ~> struct B { id: String, name: String, } { id: with = _.to_string(); ..
+/// }`
+///
+/// The body is checked against the defined structs: a mapping naming a field
+/// the defined target lacks, an unmapped target field (without `..` or
+/// `base = expr;`), or a read of a field the defined source lacks is an error
+/// pointing at that field.
 ///
 /// ## Field Syntax
 ///
@@ -68,19 +107,108 @@ use proc_macro::TokenStream;
 /// - `field;` - Copy field with same name
 /// - `field: cloned;` - Same-name with clone mode
 /// - `field: copy;` - Same-name, no clone (asserts Copy)
-/// - `field: move;` - Same-name, explicit move
+/// - `field: arc_clone;` - Same-name, cloned with `Arc::clone(&src.field)`
+/// - `field: move;` - Same-name, explicit move; a field read several times
+///   moves on its last use, and `with = .field.method(), move` borrows it first
 /// - `field: default;` - Use `Default::default()`
 /// - `field: default = expr;` - Use specific default value
+/// - `field: default_with = path;` - Use `path()` as the default value
+/// - `field: default_if_none = .legacy;` - Read the `Option` field, falling
+///   back to `.legacy` when it is `None`
+/// - `field: into;` - Convert with `Into` (the ref impl converts from `&A`)
+/// - `field: with_fn = path;` - Call `path(src.field)`, or `path(&src.field)`
+///   in the ref impl
+/// - `field: try_into = path;` - Call a fallible `path(src.field)?` (or
+///   `path(&src.field)?` by reference); triggers TryFrom
+/// - `field: getter = method;` - Call `src.method()` instead of reading a
+///   field, for sources with private fields; the result isn't cloned unless
+///   `cloned`
+/// - `field: some;` - Wrap `A` in `Some` for an `Option<A>` target
+/// - `field: boxed;` / `rc;` / `arc;` - Wrap in `Box`, `Rc`, or `Arc` (the ref
+///   impl clones first)
+/// - `field: unbox;` - Move the value out of a `Box<A>` source
+/// - `field: into_owned;` - Turn a `Cow<'a, str>` source into a `String`
+///   (`Cow::into_owned`, or `to_owned` on the borrowed value by reference)
+/// - `field: vec;` - Copy a `[A; N]` array or `&[A]` slice source into a
+///   `Vec<A>`
+/// - `field: unwrap_or_default;` - Unwrap `Option<A>` into `A`, or
+///   `A::default()`
+/// - `field: secs_to_duration;` / `field: duration_to_secs;` - `u64` seconds to
+///   a `std::time::Duration` and back; `~` relations reverse them without a
+///   `reverse = expr`
+/// - `field: required;` - Unwrap `Option<A>` into `B` where `A: Into<B>`
+///   (fallible)
+/// - `field: skip_if = .cond, default = expr;` - Default when `cond` holds,
+///   otherwise same-named field (`, default = expr` optional)
 /// - `tgt: with = .src;` - Rename (access different source field)
-/// - `field: with = _.method();` - Method call on same-named field
+/// - `{a, b}: with = .shared;` - One source into several target fields, cloned
+///   for every target but the last
+/// - `field: with = _.method();` - Method call on same-named field; the ref
+///   impl clones a field an `Option`/`Result` adapter consumes (`_.ok()`)
 /// - `field: with = .x + .y;` - Expression with source field access
-/// - `field: with = expr?;` - Fallible transform (triggers TryFrom)
+/// - `field: as i64;` - Numeric cast, short for `with = _ as i64`
+/// - `field: with = <i64 as From<i32>>::from(.x);` - Qualified paths and
+///   turbofish work too; a `_` in generic arguments (`collect::<Vec<_>>()`)
+///   stays an inferred type
+/// - `field: with = expr?;` - Fallible transform (triggers TryFrom); a `?`
+///   mid-expression such as `_.parse::<i32>()?.to_string()` counts too, one
+///   inside a closure body doesn't
+/// - `field: with = { let t = .a.trim(); t.parse()? };` - A block for
+///   transforms that need locals; `.field`, `_` and `?` work inside it
+/// - `field: with = expr?, map_err = |e| MyError::Field(e);` - Adapt this
+///   field's error before `?`
 /// - `field: with = [_.x];` - Collection map
-/// - `field: with = expr, cloned;` - Transform with clone mode
+/// - `field: with = [_];` - Convert each item with `Into`
+/// - `field: with = [_.key.clone(), _.value];` - Collect key-value pairs into a
+///   `HashMap`/`BTreeMap`
+/// - `field: with = [relate::arc_into(_)];` - `Vec<Arc<A>>` to `Vec<Arc<B>>`
+///   through `From<&A>`
+/// - `field: with = [_.parse()?];` - Fallible collection map, stops at the
+///   first error
+/// - `field: with = [idx, _ => (idx, _.name.clone())];` - Collection map
+///   binding each element's position to `idx`
+/// - `field: with = map[_.x];` - Map over `HashMap`/`BTreeMap` values, keeping
+///   keys
+/// - `field: with = opt[_.x];` - Map over an `Option<Vec<_>>`, keeping `None`
+/// - `field: with = expr, cloned;` - Transform with clone mode; each field the
+///   expression reads by value is cloned (`FullName::new(.first, .last)`)
+/// - `field: with = expr, reverse = expr;` - Inverse for `~` / `~?`, writing
+///   the source field the `with` expression reads; `_` is the target field
+/// - `error = MyError;` - Leading statement; same as `~>?[MyError]`
+/// - `#![owned_only]` / `#![ref_only]` - Leading inner attribute; generate only
+///   the owning or only the `&Source` impls. A target lifetime the source
+///   doesn't have, as in `Source ~> View<'a>`, borrows from it, giving
+///   `From<&'a Source> for View<'a>`
+/// - `#![into_fn]` - Leading inner attribute; generate a free `into_<target>`
+///   function instead of the forward impls
+/// - `#![roundtrip_check]` - Leading inner attribute for `~`; also generate
+///   `Target::__relate_roundtrip(a: Source) -> bool`, checking that converting
+///   to the target and back gives `a` again
+/// - `#![inline]` - Leading inner attribute; mark the generated `from` /
+///   `try_from` (or `into_` function) `#[inline]`
+/// - `#![reverse_cloned]` / `#![reverse_copy]` / `#![reverse_move]` - Leading
+///   inner attribute for `~` / `~?`; the clone mode of every field in the
+///   backward impls, which otherwise follow each field's forward clone mode
+/// - `base = expr;` - Leading statement; build the target as `Target {
+///   fields.., ..expr }`, so unlisted fields come from `expr` (forward
+///   direction only)
+/// - `strip_prefix = "db_";` - Leading statement; identity fields read
+///   `db_<name>`, and `~` / `~?` write them back there
+///
+/// In `~` / `~?` relations, identity fields and plain renames (`with = .src`)
+/// are reversed automatically and default fields are skipped; every other
+/// field needs `reverse = expr` or it is a compile error.
 ///
 /// Inside `with = expr`:
 /// - `_` expands to `src.<target_field_name>` (same-named source field)
 /// - `.field` accesses `src.field` (any source field by name)
+/// - `@field` is the already-computed value of another target field, e.g.
+///   `full: with = format!("{} {}", @first, @last);` (use it by reference or
+///   clone it, since the field itself still takes the value)
+/// - `$src` is a reference to the whole source, e.g. `checksum: with =
+///   compute($src);` (in both the owned and ref impls)
+/// - A tuple of fields, `point: with = (.x, .y);`, builds a tuple field and
+///   clones each element on its own where needed
 ///
 /// ## Examples
 ///
@@ -154,7 +282,8 @@ pub fn relate_structs(input: TokenStream) -> TokenStream {
     }
 }
 
-/// Derive macro for generating `From` implementations between related structs.
+/// Derive macro for generating `From` implementations between related structs
+/// and enums.
 ///
 /// Place on the target struct with `#[relate(SourceType)]` to generate
 /// `From<Source>` and `From<&Source>` implementations.
@@ -162,8 +291,111 @@ pub fn relate_structs(input: TokenStream) -> TokenStream {
 /// ## Features
 ///
 /// - **Auto-mapping**: Fields without `#[relate(...)]` are mapped by same name
+/// - **Marker fields**: `PhantomData` fields without `#[relate(...)]` are
+///   filled with `PhantomData`, without a `Default` bound on the marked type
 /// - **Auto dual impl**: Generates both `From<T>` and `From<&T>`
 /// - **Bidirectional**: Use `#[relate(Source, both)]` for both directions
+/// - **Reverse clone mode**: `#[relate(Source, both, reverse_cloned)]` (or
+///   `reverse_copy`, `reverse_move`) sets the reverse impls' clone mode apart
+///   from the forward one; by default they clone only in the ref impl
+/// - **Wrapped errors**: `#[relate(Source, error = MyError,
+///   wraps(ParseIntError))]` converts every fallible field's error with
+///   `MyError::from`, requiring `MyError: From<ParseIntError>` for each listed
+///   type on the impls
+/// - **Collected errors**: `#[relate(Source, collect_errors)]` tries every
+///   fallible field and returns all failures as `ConversionError::Multiple`
+/// - **Owned only**: `#[relate(Source, owned_only)]` skips the `From<&Source>`
+///   impls, for fields that can't or shouldn't be cloned
+/// - **Ref only**: `#[relate(Source, ref_only)]` generates only the
+///   `From<&Source>` impls, for sources that are never given up; a lifetime of
+///   the derived type that the source doesn't have borrows from it, giving
+///   `impl<'a> From<&'a Source> for View<'a>`
+/// - **Shared sources**: `#[relate(Source, also_arc)]` adds
+///   `Target::from_arc(Arc<Source>) -> Arc<Target>`, moving out of an unshared
+///   `Arc` and converting from a reference otherwise
+/// - **Roundtrip check**: `#[relate(Source, both, roundtrip_check)]` adds
+///   `Target::__relate_roundtrip(a: Source) -> bool` for your tests, checking
+///   `Source::from(Target::from(a.clone())) == a`
+/// - **Struct update**: `#[relate(Source, base = Self::detached())]` builds
+///   `Self { fields.., ..base }`, filling the `#[relate(skip)]` fields from
+///   `base`, which don't need `Default`
+/// - **Unlisted fields**: `#[relate(Source, allow_missing)]` fills every field
+///   without a `#[relate]` attribute with `Default::default()` instead of
+///   reading a same-named source field; write `#[relate]` to map one by name
+/// - **Debugging**: `#[relate(Source, debug_expand)]` shows the code generated
+///   for that source in a deprecation warning at the keyword; remove it when
+///   done
+/// - **Inlining**: `#[relate(Source, inline)]` marks every generated `from` /
+///   `try_from` (owned, ref, and reverse) `#[inline]`
+/// - **Const conversions**: `#[relate(Source, copy, const)]` adds
+///   `Target::const_from(Source) -> Target` as a `const fn` (trait methods
+///   can't be `const`); with `method = name`, that method is `const` instead.
+///   Every field must read its source field unchanged in `copy` mode
+/// - **Named `try_into`**: `#[relate(Source, try_from, try_into_self)]` adds
+///   `Source::try_into_<target>(self) -> Result<Target, E>` (taking `&self`
+///   with `ref_only`), so a generic target needs no annotation at the call
+///   site; the source must be a type of this crate
+/// - **Delimited rows**: `#[relate(&str, delimiter = ",")]` generates
+///   `TryFrom<&str>` that splits the row and parses each field from its trimmed
+///   column, in declaration order, with `FromStr`; a short row fails with
+///   `ConversionError::MissingField`, and `default` fields take no column
+/// - **Optional sources**: `#[relate(Source, also_option)]` adds
+///   `Target::from_option(Option<Source>) -> Option<Target>` (taking
+///   `Option<&Source>` with `ref_only`)
+/// - **No implicit clones**: `#[relate(Source, no_clone)]` makes a source field
+///   the owned impl would clone (read more than once) a compile error; the ref
+///   impl still clones, and `cloned` fields are allowed
+/// - **Error context**: `#[relate(Source, try_from, context = "parsing
+///   Config")]` wraps every error in `ConversionError::Context` with that label
+/// - **Validation**: `#[relate(Source, try_from, validate = |t: &Self| t.min <=
+///   t.max)]` checks the converted value and fails with
+///   `ConversionError::Validation` when the predicate returns `false`
+/// - **Multiple sources**: Stack `#[relate(A)]` and `#[relate(B)]`, each with
+///   its own options, to convert from several sources; scope a field or variant
+///   attribute to one of them with `#[relate(from = A, ...)]`
+/// - **Inherent method**: `#[relate(Source, method = to_dto)]` generates `pub
+///   fn to_dto(src: &Source) -> Self` (or `Result<Self, E>`) instead of trait
+///   impls, for source types the orphan rules keep out of `From`
+/// - **Extra bounds**: `#[relate(Source, where = "T: Into<U>")]` adds bounds to
+///   the generated impls that the struct itself doesn't declare
+/// - **Flatten**: `#[relate(Source, flatten(meta: created_at, updated_at))]`
+///   maps those fields from `src.meta` instead of `src`
+/// - **Bulk renames**: `#[relate(Source, rename_fields(user_name = name))]`
+///   maps target field `name` from `src.user_name`, listing every rename in one
+///   place; fields with their own mapping keep it
+/// - **Patch structs**: `#[relate(Base, optionalize)]` wraps every by-name
+///   field in `Some`, for a struct whose fields are all `Option`s of the
+///   base's; `#[relate(Patch, deoptionalize)]` unwraps them back with
+///   `unwrap_or_default()`. Fields with their own transform keep it
+/// - **Applying patches**: `#[relate(Patch, apply_patch, base = expr)]` fills
+///   each by-name field with `src.field.unwrap_or(base.field)`, so only the
+///   patch's `Some` fields replace the base value's
+/// - **Prefixed sources**: `#[relate(Source, strip_prefix = "db_")]` maps
+///   by-name fields from `db_<name>`; fields with their own mapping keep it
+/// - **Tuple sources**: `#[relate((String, i32), both)]` converts from and to a
+///   plain tuple; the Nth field reads `src.N` (`default` fields take no
+///   position), and `both` builds the tuple back
+/// - **Unit sources**: `#[relate(Empty)]` converts from a unit struct when
+///   every field is `default`; the source is never read
+/// - **JSON objects**: With the `serde` feature, `#[relate(serde_json::Value)]`
+///   generates `TryFrom<serde_json::Value>`, reading each field from the object
+///   key of the same name (or its rename) with `relate::json_field`
+/// - **Enums**: Variants map to same-named source variants via `match`
+/// - **Tuple structs**: Elements map positionally (`src.0`, `src.1`, ...), and
+///   accept the same attributes as named fields; `.0` reads another element
+///
+/// ## Enum Variants
+///
+/// Unit variants map directly, tuple variants map positionally, and both
+/// tuple and struct variants accept the same field attributes as structs.
+///
+/// - `#[relate(SourceVariant)]` on a variant: map from a differently named
+///   source variant
+/// - `#[relate(i32)]` (any primitive integer up to 64 bits) on a fieldless
+///   enum: generate `TryFrom<i32>`, matching each variant's discriminant and
+///   failing with `ConversionError::UnknownDiscriminant`; `both` adds
+///   `From<Enum> for i32` (`variant as i32`). A discriminant that doesn't fit
+///   the integer type is a compile error
 ///
 /// ## Field Attributes
 ///
@@ -172,11 +404,65 @@ pub fn relate_structs(input: TokenStream) -> TokenStream {
 /// - `#[relate(.method())]`: Transform with method call
 /// - `#[relate(source_field, .method())]`: Rename + transform
 /// - `#[relate([.field.clone()])]`: Collection map
-/// - `#[relate(|x: T| expr)]`: Transform with closure
+/// - `#[relate([_])]`: Convert each item with `Into`, moving the items in the
+///   owned impl and cloning them in the ref impl
+/// - `#[relate([relate::arc_into(_)])]`: `Vec<Arc<A>>` to `Vec<Arc<B>>` through
+///   `From<&A>`
+/// - `#[relate([_.key.clone(), _.value])]`: Collect key-value pairs into a
+///   `HashMap`/`BTreeMap`
+/// - `#[relate([idx, _ => (idx, _.name.clone())])]`: Collection map that also
+///   binds each element's position to `idx`
+/// - `#[relate(map[_.clone().into()])]`: Map over map values, keeping keys
+/// - `#[relate(opt[_.to_string()])]`: Map over a collection inside an `Option`,
+///   keeping `None`
+/// - `#[relate([_], cloned)]` on a `HashMap`/`BTreeMap`: Clone keys and convert
+///   values with `Into` (same as `map[_]`)
+/// - `#[relate(|x| expr)]`: Apply a closure to the same-named field; a closure
+///   taking a reference (`|x: &str|` or `|&x|`) borrows it in both impls
 /// - `#[relate(path::to::fn)]`: Transform with function
+/// - `#[relate(with_fn = path::to::fn)]`: Call `fn(src.field)`, or
+///   `fn(&src.field)` in the ref impl (by value there with `cloned`)
+/// - `#[relate(try_into = path::to::fn)]`: Like `with_fn` for a function
+///   returning `Result`, applying `?` (triggers `TryFrom`)
+/// - `#[relate(getter = method)]`: Call `src.method()` instead of reading a
+///   field, for sources with private fields (structs only, not with `both`)
 /// - `#[relate(default)]`: Use `Default::default()`
 /// - `#[relate(default = expr)]`: Use specific default
-/// - `#[relate(skip)]`: Same as default
+/// - `#[relate(default_with = path)]`: Use `path()` as the default; repeated
+///   uses of the same factory call it once
+/// - `#[relate(.primary_id, default_if_none = .legacy_id)]`: Read an `Option`
+///   field, falling back to another `Option` field when it is `None`
+/// - `#[relate(into)]`: Convert with `Into` (the ref impl converts from `&A`)
+/// - `#[relate(as i32)]`: Cast with `as`, e.g. a `Copy` enum field to its
+///   discriminant
+/// - `#[relate(some)]`: Wrap in `Some` for an `Option<A>` target
+/// - `#[relate(boxed)]` / `#[relate(rc)]` / `#[relate(arc)]`: Wrap in a smart
+///   pointer
+/// - `#[relate(unbox)]`: Move the value out of a `Box<A>` source
+/// - `#[relate(into_owned)]`: Turn a `Cow<'a, str>` source into a `String`
+/// - `#[relate(vec)]`: Copy a `[A; N]` array or `&[A]` slice source into a
+///   `Vec<A>`
+/// - `#[relate(arc_clone)]`: Clone an `Arc` field with `Arc::clone(&src.field)`
+///   where a clone is needed, instead of `src.field.clone()`
+/// - `#[relate(unwrap_or_default)]`: Unwrap `Option<A>` into `A`, using
+///   `A::default()` for `None`
+/// - `#[relate(secs_to_duration)]` / `#[relate(duration_to_secs)]`: `u64`
+///   seconds to a `std::time::Duration` and back, reversed the other way under
+///   `both`
+/// - `#[relate(required)]`: Unwrap `Option<A>` into `B` where `A: Into<B>`,
+///   failing with `ConversionError::MissingField` (triggers `TryFrom`)
+/// - `#[relate(_.parse()?, map_err = |e| MyError::Port(e))]`: Adapt a fallible
+///   field's error before `?`
+/// - `#[relate(skip)]`: Leave the field out for `..base` to fill; requires
+///   `base = expr` on the struct attribute
+/// - `#[relate(with = format!("{} {}", @first, @last))]`: `@field` is the
+///   computed value of another target field
+/// - `#[relate(with = compute($src))]`: `$src` is a reference to the whole
+///   source (structs only)
+/// - `#[relate(from = Source, ...)]`: Apply only to the conversion from
+///   `Source`, overriding an unscoped attribute on the same field
+/// - `#[relate(skip_if = .is_deleted, default = 0)]`: Use the default when the
+///   condition holds, otherwise map the same-named field (`default` optional)
 ///
 /// ## Examples
 ///
@@ -211,6 +497,21 @@ pub fn relate_structs(input: TokenStream) -> TokenStream {
 ///     #[relate(default = false)]
 ///     pub should_sync: bool,
 /// }
+///
+/// // Enums
+/// #[derive(Relate)]
+/// #[relate(DbStatus, both)]
+/// pub enum Status {
+///     Active,
+///     #[relate(Suspended)]  // Rename: DbStatus::Suspended -> Status::Paused
+///     Paused(String),
+///     Deleted { at: u64 },
+/// }
+///
+/// // Newtypes
+/// #[derive(Relate)]
+/// #[relate(DbUserId)]
+/// pub struct UserId(i64);
 /// ```
 #[proc_macro_derive(Relate, attributes(relate))]
 pub fn derive_relate(input: TokenStream) -> TokenStream {
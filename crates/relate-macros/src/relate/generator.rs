@@ -1,13 +1,14 @@
 //! Code generator for the `relate_structs!` macro.
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::quote;
-use syn::{Error, Result};
+use syn::{Error, Expr, Result, Type};
 
 use super::types::*;
 use crate::core::{
-    CloneMode, ReverseStrategy, count_field_usage, count_reverse_field_usage, generate_field_init,
-    generate_reverse_field_init,
+    CloneMode, base_binding, borrowed_lifetime, contains_source_ref, count_field_usage,
+    error_type_tokens, generate_field_init, generate_field_value, member_name, roundtrip_check_fn,
+    source_param, target_ref_binding, target_ref_order, to_snake_case, tokens_contain_call,
 };
 
 /// Check if any field mapping has a fallible transform.
@@ -15,128 +16,515 @@ fn has_fallible_fields(fields: &[FieldMapping]) -> bool {
     fields.iter().any(|f| f.source.transform.is_fallible())
 }
 
-/// Get the effective direction, auto-upgrading to TryForward if fallible
-/// transforms detected. For Bidirectional, only upgrades the forward direction;
-/// backward stays as From.
-fn effective_direction(direction: &Direction, fields: &[FieldMapping]) -> Direction {
-    if has_fallible_fields(fields) {
-        match direction {
-            Direction::Forward => Direction::TryForward(None),
-            Direction::TryForward(e) => Direction::TryForward(e.clone()),
-            Direction::Bidirectional => Direction::TryForward(None), // Forward becomes TryFrom
+/// Get the effective forward direction, auto-upgrading to TryForward if
+/// fallible transforms detected. Bidirectional only describes the forward
+/// half here; the backward half is handled by `backward_error_type`.
+fn effective_direction(
+    direction: &Direction,
+    fields: &[FieldMapping],
+    default_error: Option<&Type>,
+) -> Direction {
+    let default_error = || default_error.map(|t| Box::new(t.clone()));
+    match direction {
+        Direction::TryForward(e) | Direction::TryBidirectional(e) => {
+            Direction::TryForward(e.clone().or_else(default_error))
         }
-    } else {
-        direction.clone()
+        Direction::Forward | Direction::Bidirectional if has_fallible_fields(fields) => {
+            Direction::TryForward(default_error())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Get the error type of the backward impls, or `None` for infallible `From`.
+///
+/// `~?` always generates `TryFrom`; `~` upgrades to `TryFrom` when a
+/// `reverse = expr` is fallible.
+fn backward_error_type(
+    direction: &Direction,
+    reversed: &[FieldMapping],
+    default_error: Option<&Type>,
+) -> Option<TokenStream> {
+    match direction {
+        Direction::TryBidirectional(custom_error) => {
+            Some(error_type_tokens(custom_error.as_deref().or(default_error)))
+        }
+        Direction::Bidirectional if has_fallible_fields(reversed) => {
+            Some(error_type_tokens(default_error))
+        }
+        _ => None,
+    }
+}
+
+/// Check if `with = expr` tokens are a plain rename: `.field` or `.0`.
+fn is_rename(tokens: &TokenStream) -> bool {
+    let trees: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    match trees.as_slice() {
+        [TokenTree::Punct(dot), TokenTree::Ident(_)] => dot.as_char() == '.',
+        [TokenTree::Punct(dot), TokenTree::Literal(lit)] => {
+            dot.as_char() == '.' && lit.to_string().parse::<u32>().is_ok()
+        }
+        _ => false,
     }
 }
 
+/// Invert field mappings for the backward impls (target becomes source).
+///
+/// Identity fields and plain renames (`with = .field`) map straight back;
+/// fields with `reverse = expr` use that expression, reading the target and
+/// writing the source field the forward transform read. Default fields have
+/// no source value and are skipped. Any other field is an error: there's no
+/// automatic way to reverse an arbitrary transform.
+///
+/// `reverse_mode` (`#![reverse_cloned]` and friends) overrides every field's
+/// clone mode, which otherwise carries over from the forward mapping.
+fn reverse_mappings(
+    fields: &[FieldMapping],
+    reverse_mode: Option<CloneMode>,
+) -> Result<Vec<FieldMapping>> {
+    let mut reversed = Vec::new();
+    for mapping in fields {
+        let target = &mapping.target_field;
+        let transform = match (&mapping.source.reverse, &mapping.source.transform) {
+            (Some(reverse), _) => (**reverse).clone(),
+            (None, Transform::Identity) => Transform::Identity,
+            (None, Transform::WithExpr(tokens, false)) if is_rename(tokens) => Transform::Identity,
+            (None, transform) if transform.is_default_kind() => continue,
+            (None, _) => {
+                return Err(Error::new_spanned(
+                    target,
+                    format!(
+                        "Field `{}` can't be reversed automatically in a bidirectional relation.\n\
+                         Add `reverse = expr` to map it back (e.g. `with = _.to_string(), \
+                         reverse = _.parse()?`),\n\
+                         or use `~>` for a forward-only relation",
+                        member_name(target)
+                    ),
+                ));
+            }
+        };
+        let Some(destination) = mapping.source.reverse_field(target) else {
+            return Err(Error::new_spanned(
+                target,
+                "Cannot tell which source field `reverse` writes to.\n\
+                 The `with` expression must read exactly one source field (`_` or `.field`)",
+            ));
+        };
+
+        let mut source = FieldSource::with_transform(transform);
+        source.field_name = Some(target.clone());
+        if mapping.source.reverse.is_none() {
+            source.clone_mode = mapping.source.clone_mode;
+        }
+        if reverse_mode.is_some() {
+            source.clone_mode = reverse_mode;
+        }
+        reversed.push(FieldMapping {
+            target_field: destination,
+            source,
+        });
+    }
+    Ok(reversed)
+}
+
+/// Reject target fields mapped more than once, pointing at the second mapping.
+fn check_duplicate_fields(fields: &[FieldMapping]) -> Result<()> {
+    for (idx, field) in fields.iter().enumerate() {
+        let Some(first) = fields[..idx]
+            .iter()
+            .find(|f| f.target_field == field.target_field)
+        else {
+            continue;
+        };
+        let name = member_name(&field.target_field);
+        let is_default = |f: &FieldMapping| f.source.transform.is_default_kind();
+        let is_with = |f: &FieldMapping| matches!(f.source.transform, Transform::WithExpr(..));
+        let msg = if (is_default(first) && is_with(field)) || (is_with(first) && is_default(field))
+        {
+            format!(
+                "Field `{name}` is given both a `default` and a `with` expression; keep only one"
+            )
+        } else {
+            format!("Duplicate field `{name}`: each target field can only be mapped once")
+        };
+        return Err(Error::new_spanned(&field.target_field, msg));
+    }
+    Ok(())
+}
+
+/// A generated conversion body: hoisted `let` bindings, then the value.
+struct ConversionBody {
+    let_bindings: Vec<TokenStream>,
+    value:        TokenStream,
+    /// Mark the generated function `#[inline]` (`#![inline]`)
+    inline:       bool,
+    /// The lifetime of the `&Source` a borrowing target ties itself to, for
+    /// the ref impls of `Source ~> View<'a>`
+    borrow:       Option<syn::Lifetime>,
+    /// `?` short-circuits on `Option`s (`~>?[Option]`)
+    option:       bool,
+}
+
+/// Generate the body constructing `path` (`Self` in trait impls) from `fields`.
+///
+/// Fields read through `@field` references are computed into `let` bindings
+/// first, in dependency order; they are evaluated before the struct literal,
+/// so they never take the last-use move of a source field. Fields reading
+/// `$src`, and `move` fields calling a method, are bound before those.
+/// A `base` expression is bound first of all and fills the unlisted fields.
+fn conversion_body(
+    fields: &[FieldMapping],
+    path: &TokenStream,
+    is_ref: bool,
+    inline: bool,
+    base: Option<&Expr>,
+) -> Result<ConversionBody> {
+    let field_usage = count_field_usage(fields);
+    // `$src` borrows the whole source, so those fields are computed before
+    // any other field moves out of it. So are `move` method calls, which
+    // borrow the field before its last use moves it.
+    let mut hoisted: Vec<usize> = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| match &f.source.transform {
+            Transform::WithExpr(tokens, _) => {
+                let borrows = f.source.clone_mode == Some(CloneMode::Move);
+                contains_source_ref(tokens) || (borrows && tokens_contain_call(tokens))
+            }
+            _ => false,
+        })
+        .map(|(idx, _)| idx)
+        .collect();
+    for idx in target_ref_order(fields)? {
+        if !hoisted.contains(&idx) {
+            hoisted.push(idx);
+        }
+    }
+
+    let base_binding = base_binding();
+    let base_let = base.map(|base| quote! { let #base_binding = #base; });
+    let let_bindings = base_let
+        .into_iter()
+        .chain(hoisted.iter().map(|&idx| {
+            let binding = target_ref_binding(&fields[idx].target_field);
+            // `usize::MAX` is never a field's last use, so multi-use reads clone
+            let value = generate_field_value(
+                &fields[idx],
+                usize::MAX,
+                is_ref,
+                &field_usage,
+                CloneMode::Auto,
+            );
+            quote! { let #binding = #value; }
+        }))
+        .collect();
+    let rest = base.map(|_| quote! { ..#base_binding });
+
+    // relate_structs! macro uses Auto clone mode (default behavior)
+    let inits = fields.iter().enumerate().map(|(idx, f)| {
+        if hoisted.contains(&idx) {
+            let target = &f.target_field;
+            let binding = target_ref_binding(target);
+            quote! { #target: #binding }
+        } else {
+            generate_field_init(f, idx, is_ref, &field_usage, CloneMode::Auto)
+        }
+    });
+
+    Ok(ConversionBody {
+        let_bindings,
+        value: quote! { #path { #(#inits,)* #rest } },
+        inline,
+        borrow: None,
+        option: false,
+    })
+}
+
 /// Generate a pair of From implementations (owned and reference).
 ///
 /// Generates:
-/// - `impl From<source_type> for target_type`
-/// - `impl From<&source_type> for target_type`
+/// - `impl From<source_type> for target_type`, unless `owned` is `None`
+///   (`#![ref_only]`)
+/// - `impl From<&source_type> for target_type`, unless `by_ref` is `None`
+///   (`#![owned_only]`), with `ref_where_clause` instead of `where_clause` when
+///   given
 fn generate_from_impl_pair(
     source_type: &TokenStream,
     target_type: &TokenStream,
     impl_generics: &TokenStream,
     where_clause: &TokenStream,
-    owned_fields: &[TokenStream],
-    ref_fields: &[TokenStream],
+    ref_where_clause: Option<&TokenStream>,
+    owned: Option<&ConversionBody>,
+    by_ref: Option<&ConversionBody>,
 ) -> TokenStream {
-    quote! {
-        impl #impl_generics ::core::convert::From<#source_type> for #target_type #where_clause {
-            fn from(src: #source_type) -> Self {
-                Self {
-                    #(#owned_fields),*
+    let mut output = TokenStream::new();
+    if let Some(owned) = owned {
+        let (owned_lets, owned_value) = (&owned.let_bindings, &owned.value);
+        let owned_body = quote! { #(#owned_lets)* #owned_value };
+        let src = source_param(&owned_body);
+        let inline = owned.inline.then(|| quote! { #[inline] });
+        output.extend(quote! {
+            impl #impl_generics ::core::convert::From<#source_type> for #target_type #where_clause {
+                #inline
+                fn from(#src: #source_type) -> Self {
+                    #owned_body
                 }
             }
-        }
-
-        impl #impl_generics ::core::convert::From<&#source_type> for #target_type #where_clause {
-            fn from(src: &#source_type) -> Self {
-                Self {
-                    #(#ref_fields),*
+        });
+    }
+    if let Some(by_ref) = by_ref {
+        let (ref_lets, ref_value) = (&by_ref.let_bindings, &by_ref.value);
+        let ref_body = quote! { #(#ref_lets)* #ref_value };
+        let src = source_param(&ref_body);
+        let inline = by_ref.inline.then(|| quote! { #[inline] });
+        let where_clause = ref_where_clause.unwrap_or(where_clause);
+        let lifetime = &by_ref.borrow;
+        output.extend(quote! {
+            impl #impl_generics ::core::convert::From<&#lifetime #source_type> for #target_type #where_clause {
+                #inline
+                fn from(#src: &#lifetime #source_type) -> Self {
+                    #ref_body
                 }
             }
-        }
+        });
+    }
+    output
+}
+
+/// Generate the statements of a fallible conversion returning `output`: the
+/// `let` bindings, then `Ok(value)`.
+///
+/// For `~>?[Option]`, `?` short-circuits on `Option`s instead (`_.get(0)?`):
+/// the body runs in a closure returning `Option<output>`, and `None` becomes
+/// `Err(())`.
+fn try_statements(body: &ConversionBody, output: &TokenStream) -> TokenStream {
+    let (lets, value) = (&body.let_bindings, &body.value);
+    if !body.option {
+        return quote! {
+            #(#lets)*
+            ::core::result::Result::Ok(#value)
+        };
+    }
+    quote! {
+        (|| -> ::core::option::Option<#output> {
+            #(#lets)*
+            ::core::option::Option::Some(#value)
+        })()
+        .ok_or(())
     }
 }
 
-/// Generate a pair of TryFrom implementations (owned and reference).
+/// Generate a pair of TryFrom implementations (owned and reference, each
+/// skipped when its body is `None`).
 fn generate_try_from_impl_pair(
     source_type: &TokenStream,
     target_type: &TokenStream,
     impl_generics: &TokenStream,
     where_clause: &TokenStream,
     error_type: &TokenStream,
-    owned_fields: &[TokenStream],
-    ref_fields: &[TokenStream],
+    owned: Option<&ConversionBody>,
+    by_ref: Option<&ConversionBody>,
 ) -> TokenStream {
-    quote! {
-        impl #impl_generics ::core::convert::TryFrom<#source_type> for #target_type #where_clause {
-            type Error = #error_type;
-
-            fn try_from(src: #source_type) -> ::core::result::Result<Self, Self::Error> {
-                ::core::result::Result::Ok(Self {
-                    #(#owned_fields),*
-                })
+    let mut output = TokenStream::new();
+    if let Some(owned) = owned {
+        let owned_stmts = try_statements(owned, &quote! { Self });
+        let src = source_param(&owned_stmts);
+        let inline = owned.inline.then(|| quote! { #[inline] });
+        output.extend(quote! {
+            impl #impl_generics ::core::convert::TryFrom<#source_type> for #target_type #where_clause {
+                type Error = #error_type;
+
+                #inline
+                fn try_from(#src: #source_type) -> ::core::result::Result<Self, Self::Error> {
+                    #owned_stmts
+                }
             }
-        }
-
-        impl #impl_generics ::core::convert::TryFrom<&#source_type> for #target_type #where_clause {
-            type Error = #error_type;
+        });
+    }
+    if let Some(by_ref) = by_ref {
+        let ref_stmts = try_statements(by_ref, &quote! { Self });
+        let src = source_param(&ref_stmts);
+        let inline = by_ref.inline.then(|| quote! { #[inline] });
+        let lifetime = &by_ref.borrow;
+        output.extend(quote! {
+            impl #impl_generics ::core::convert::TryFrom<&#lifetime #source_type> for #target_type #where_clause {
+                type Error = #error_type;
+
+                #inline
+                fn try_from(#src: &#lifetime #source_type) -> ::core::result::Result<Self, Self::Error> {
+                    #ref_stmts
+                }
+            }
+        });
+    }
+    output
+}
 
-            fn try_from(src: &#source_type) -> ::core::result::Result<Self, Self::Error> {
-                ::core::result::Result::Ok(Self {
-                    #(#ref_fields),*
-                })
+/// Generate a free `pub fn into_<target>(src: Source) -> Target` instead of the
+/// forward impls (`#![into_fn]`), for a target in another crate.
+///
+/// Returns `Result<Target, E>` when the conversion is fallible.
+fn generate_into_fn(
+    target: &TypeRef,
+    source_type: &TokenStream,
+    target_type: &TokenStream,
+    impl_generics: &TokenStream,
+    where_clause: &TokenStream,
+    error_type: Option<&TokenStream>,
+    body: &ConversionBody,
+) -> TokenStream {
+    let fn_name = Ident::new(
+        &format!("into_{}", to_snake_case(&target.name().to_string())),
+        target.name().span(),
+    );
+    let (lets, value) = (&body.let_bindings, &body.value);
+    let inline = body.inline.then(|| quote! { #[inline] });
+    match error_type {
+        Some(error_type) => {
+            let stmts = try_statements(body, target_type);
+            quote! {
+                #inline
+                pub fn #fn_name #impl_generics (
+                    src: #source_type,
+                ) -> ::core::result::Result<#target_type, #error_type> #where_clause {
+                    #stmts
+                }
             }
         }
+        None => quote! {
+            #inline
+            pub fn #fn_name #impl_generics (src: #source_type) -> #target_type #where_clause {
+                #(#lets)*
+                #value
+            }
+        },
     }
 }
 
+/// The type in type position: the path and type parameters, without bounds
+/// (`Container<T>`, not `Container<T: Clone>`).
+fn type_tokens(type_ref: &TypeRef) -> TokenStream {
+    let TypeRef { path, generics } = type_ref;
+    let ty_generics = generics.as_ref().map(|g| {
+        let (_, ty_generics, _) = g.split_for_impl();
+        quote! { #ty_generics }
+    });
+    quote! { #path #ty_generics }
+}
+
 /// Main entry point for generating output from parsed input.
 pub fn generate_relate_output(input: &RelateInput) -> Result<TokenStream> {
     let mut output = TokenStream::new();
 
+    let default_error = input.default_error.as_deref();
     for relation in &input.relations {
-        output.extend(generate_relation(relation)?);
+        output.extend(generate_relation(relation, default_error)?);
     }
 
     Ok(output)
 }
 
-fn generate_relation(relation: &Relation) -> Result<TokenStream> {
-    generate_existing_relation(&relation.0)
+fn generate_relation(relation: &Relation, default_error: Option<&Type>) -> Result<TokenStream> {
+    match relation {
+        Relation::Existing(existing) => generate_existing_relation(existing, default_error),
+        Relation::Define(define) => {
+            let mut output: TokenStream =
+                define.definitions.iter().map(generate_struct_def).collect();
+            output.extend(generate_existing_relation(&define.relation, default_error)?);
+            Ok(output)
+        }
+    }
+}
+
+/// Emit a struct defined inline in the macro.
+fn generate_struct_def(def: &StructDef) -> TokenStream {
+    let StructDef {
+        attrs,
+        vis,
+        name,
+        generics,
+        fields,
+    } = def;
+    let where_clause = &generics.where_clause;
+    quote! {
+        #(#attrs)*
+        #vis struct #name #generics #where_clause #fields
+    }
+}
+
+/// Get the where clause gating the ref impls behind `T: Clone` for every type
+/// parameter, or `None` if it isn't needed.
+///
+/// A relation whose fields are all plain identity moves needs no bound from
+/// its owned impls, but its ref impls clone every field. Gating only those
+/// keeps `Source<T> ~> Target<T>` usable for any `T` by value.
+fn clone_gated_where_clause(
+    generics: Option<&syn::Generics>,
+    fields: &[FieldMapping],
+) -> Option<TokenStream> {
+    let generics = generics?;
+    let identity_only = fields.iter().all(|f| f.source.transform.is_identity());
+    if generics.type_params().next().is_none() || !identity_only {
+        return None;
+    }
+    let mut where_clause = generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| syn::WhereClause {
+            where_token: Default::default(),
+            predicates:  Default::default(),
+        });
+    for param in generics.type_params() {
+        let ident = &param.ident;
+        where_clause
+            .predicates
+            .push(syn::parse_quote! { #ident: ::core::clone::Clone });
+    }
+    Some(quote! { #where_clause })
 }
 
-fn generate_existing_relation(relation: &ExistingRelation) -> Result<TokenStream> {
-    let source_name = &relation.source.name;
-    let target_name = &relation.target.name;
+fn generate_existing_relation(
+    relation: &ExistingRelation,
+    default_error: Option<&Type>,
+) -> Result<TokenStream> {
+    let source_path = &relation.source.path;
+    let target_path = &relation.target.path;
 
     let source_generics = relation.source.generics.as_ref();
     let target_generics = relation.target.generics.as_ref();
 
-    // For type position, we only need the type parameters (no bounds)
-    // e.g., Container<T> not Container<T: Clone>
-    let source_type = source_generics
-        .map(|g| {
-            let (_, ty_generics, _) = g.split_for_impl();
-            quote! { #source_name #ty_generics }
-        })
-        .unwrap_or_else(|| quote! { #source_name });
+    let source_type = type_tokens(&relation.source);
+    let target_type = type_tokens(&relation.target);
 
-    let target_type = target_generics
-        .map(|g| {
-            let (_, ty_generics, _) = g.split_for_impl();
-            quote! { #target_name #ty_generics }
-        })
-        .unwrap_or_else(|| quote! { #target_name });
+    // A target lifetime the source doesn't have borrows from the source:
+    // the ref impls of `Source ~> View<'a>` take `&'a Source`
+    let borrow = target_generics.and_then(|g| borrowed_lifetime(g, &source_type));
 
     // Get generics for impl (prefer source, fall back to target)
-    // This includes the bounds: impl<T: Clone>
-    let (impl_generics, where_clause) = source_generics
-        .or(target_generics)
+    // This includes the bounds: impl<T: Clone>, plus a borrowed lifetime
+    let borrowing_generics = match (source_generics, &borrow) {
+        (Some(generics), Some(lifetime)) => {
+            let mut generics = generics.clone();
+            generics.params.insert(0, syn::parse_quote! { #lifetime });
+            Some(generics)
+        }
+        _ => None,
+    };
+    let generics = borrowing_generics
+        .as_ref()
+        .or(source_generics)
+        .or(target_generics);
+    // A trailing `where` clause adds to the bounds written in the generics
+    let bounded_generics = relation.bounds.as_ref().map(|bounds| {
+        let mut generics = generics.cloned().unwrap_or_default();
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(bounds.predicates.iter().cloned());
+        generics
+    });
+    let generics = bounded_generics.as_ref().or(generics);
+    let (impl_generics, where_clause) = generics
         .map(|g| {
             let (impl_gen, _, where_cl) = g.split_for_impl();
             (quote! { #impl_gen }, quote! { #where_cl })
@@ -145,104 +533,177 @@ fn generate_existing_relation(relation: &ExistingRelation) -> Result<TokenStream
 
     let Some(body) = &relation.body else {
         return Err(Error::new_spanned(
-            source_name,
-            "Cannot use `A :: B` without fields - proc macros cannot introspect struct fields.\n\
-             Use `A :: B { field1, field2, ... }` to list fields explicitly,\n\
-             or use `#[attrs] struct A :: #[attrs] struct B { fields }` to define both structs.",
+            source_path,
+            "Cannot use `A ~ B` without fields - proc macros cannot introspect struct fields.\n\
+             Use `A ~ B { field1; field2; }` to list fields explicitly,\n\
+             or use `#[attrs] struct A ~ #[attrs] struct B { fields }` to define both structs.",
         ));
     };
 
-    if body.has_spread && body.fields.is_empty() {
+    if body.has_spread {
         return Err(Error::new_spanned(
-            source_name,
-            "Cannot use `..` spread alone with existing structs - proc macros cannot introspect fields.\n\
-             List the fields explicitly: `A -> B { field1, field2 }`",
+            target_path,
+            "Cannot use `..` spread with an existing target struct - proc macros cannot introspect fields.\n\
+             List the fields explicitly: `A ~> B { field1; field2; }`,\n\
+             or define the target in the macro: `A ~> struct B { field1: T, } { field1; .. }`",
         ));
     }
 
-    // Use the core utility for counting field usage
-    let field_usage = count_field_usage(&body.fields);
+    check_duplicate_fields(&body.fields)?;
+    let ref_where_clause = clone_gated_where_clause(generics, &body.fields);
 
-    // Generate field initializers using core utility
-    // relate_structs! macro uses Auto clone mode (default behavior)
-    let forward_fields: Vec<_> = body
-        .fields
-        .iter()
-        .enumerate()
-        .map(|(idx, f)| generate_field_init(f, idx, false, &field_usage, CloneMode::Auto))
-        .collect();
+    // Free functions have no `Self`, so they name the target instead
+    let foreign = body.into_fn;
+    let self_path = if foreign {
+        quote! { #target_path }
+    } else {
+        quote! { Self }
+    };
+    let base = body.base.as_ref();
 
-    let forward_ref_fields: Vec<_> = body
-        .fields
-        .iter()
-        .enumerate()
-        .map(|(idx, f)| generate_field_init(f, idx, true, &field_usage, CloneMode::Auto))
-        .collect();
+    // Auto-detect fallible transforms and upgrade direction if needed
+    let effective_dir = effective_direction(&relation.direction, &body.fields, default_error);
+
+    let mut owned_body = conversion_body(&body.fields, &self_path, false, body.inline, base)?;
+    let mut ref_body = conversion_body(&body.fields, &self_path, true, body.inline, base)?;
+    ref_body.borrow = borrow;
+    owned_body.option = effective_dir == Direction::TryForwardOption;
+    ref_body.option = owned_body.option;
+    let forward = body.impls.owned().then_some(&owned_body);
+    let forward_ref = body.impls.by_ref().then_some(&ref_body);
 
     let mut output = TokenStream::new();
 
-    // Auto-detect fallible transforms and upgrade direction if needed
-    let effective_dir = effective_direction(&relation.direction, &body.fields);
-
-    // Generate forward impls based on effective direction
-    match &effective_dir {
-        Direction::TryForward(custom_error) => {
-            let error_type = custom_error
-                .as_ref()
-                .map(|t| quote! { #t })
-                .unwrap_or_else(|| quote! { ::relate::ConversionError });
-
-            output.extend(generate_try_from_impl_pair(
-                &source_type,
-                &target_type,
-                &impl_generics,
-                &where_clause,
-                &error_type,
-                &forward_fields,
-                &forward_ref_fields,
+    let forward_error = match &effective_dir {
+        Direction::TryForward(custom_error) => Some(error_type_tokens(custom_error.as_deref())),
+        Direction::TryForwardOption => Some(quote! { () }),
+        _ => None,
+    };
+
+    // `#![into_fn]` generates a free function instead
+    if foreign {
+        // Only one function can be generated: by reference with `#![ref_only]`
+        let (source_type, body) = if body.impls == ImplSet::RefOnly {
+            let lifetime = &ref_body.borrow;
+            (quote! { &#lifetime #source_type }, &ref_body)
+        } else {
+            (source_type.clone(), &owned_body)
+        };
+        output.extend(generate_into_fn(
+            &relation.target,
+            &source_type,
+            &target_type,
+            &impl_generics,
+            &where_clause,
+            forward_error.as_ref(),
+            body,
+        ));
+    } else {
+        match &forward_error {
+            Some(error_type) => {
+                output.extend(generate_try_from_impl_pair(
+                    &source_type,
+                    &target_type,
+                    &impl_generics,
+                    &where_clause,
+                    error_type,
+                    forward,
+                    forward_ref,
+                ));
+            }
+            None => {
+                output.extend(generate_from_impl_pair(
+                    &source_type,
+                    &target_type,
+                    &impl_generics,
+                    &where_clause,
+                    ref_where_clause.as_ref(),
+                    forward,
+                    forward_ref,
+                ));
+            }
+        }
+    }
+
+    // Generate backward impls if bidirectional: From<Target> for Source
+    // (or TryFrom when fallible)
+    let bidirectional = matches!(
+        relation.direction,
+        Direction::Bidirectional | Direction::TryBidirectional(_)
+    );
+    if !bidirectional {
+        if let Some(roundtrip) = &body.roundtrip {
+            return Err(Error::new_spanned(
+                roundtrip,
+                "`#![roundtrip_check]` converts to the target and back and requires `~`",
+            ));
+        }
+        if let Some(mapping) = body.fields.iter().find(|f| f.source.reverse.is_some()) {
+            return Err(Error::new_spanned(
+                &mapping.target_field,
+                "`reverse = expr` only applies to bidirectional relations (`~` or `~?`)",
             ));
         }
-        Direction::Forward | Direction::Bidirectional => {
-            output.extend(generate_from_impl_pair(
-                &source_type,
-                &target_type,
-                &impl_generics,
-                &where_clause,
-                &forward_fields,
-                &forward_ref_fields,
+        if let Some((keyword, _)) = &body.reverse {
+            return Err(Error::new_spanned(
+                keyword,
+                format!(
+                    "`#![{keyword}]` sets the clone mode of the backward impls and requires `~` or \
+                     `~?`"
+                ),
             ));
         }
+        return Ok(output);
     }
 
-    // Generate backward impls if bidirectional: From<Target> for Source
-    if relation.direction == Direction::Bidirectional {
-        let reverse_usage = count_reverse_field_usage(&body.fields);
-
-        // For relate_structs!, only Identity transforms can be reversed
-        let backward_fields: Vec<_> = body
-            .fields
-            .iter()
-            .filter_map(|f| {
-                generate_reverse_field_init(f, false, &reverse_usage, ReverseStrategy::IdentityOnly)
-            })
-            .collect();
-
-        let backward_ref_fields: Vec<_> = body
-            .fields
-            .iter()
-            .filter_map(|f| {
-                generate_reverse_field_init(f, true, &reverse_usage, ReverseStrategy::IdentityOnly)
-            })
-            .collect();
+    let reversed = reverse_mappings(&body.fields, body.reverse.as_ref().map(|(_, mode)| *mode))?;
+    let backward = conversion_body(&reversed, &quote! { Self }, false, body.inline, None)?;
+    let backward_ref = conversion_body(&reversed, &quote! { Self }, true, body.inline, None)?;
+    let backward = body.impls.owned().then_some(&backward);
+    let backward_ref = body.impls.by_ref().then_some(&backward_ref);
+
+    let backward_error = backward_error_type(&relation.direction, &reversed, default_error);
+    if let Some(roundtrip) = &body.roundtrip {
+        if foreign || backward_error.is_some() || effective_dir != Direction::Bidirectional {
+            return Err(Error::new_spanned(
+                roundtrip,
+                "`#![roundtrip_check]` requires `From` in both directions; this relation is \
+                 fallible or generates a free function",
+            ));
+        }
+        let by_ref = !body.impls.owned();
+        let where_clause = match &ref_where_clause {
+            Some(ref_where_clause) if by_ref => ref_where_clause,
+            _ => &where_clause,
+        };
+        output.extend(roundtrip_check_fn(
+            &impl_generics,
+            &target_type,
+            &source_type,
+            where_clause,
+            by_ref,
+        ));
+    }
 
-        output.extend(generate_from_impl_pair(
+    match backward_error {
+        Some(error_type) => output.extend(generate_try_from_impl_pair(
             &target_type,
             &source_type,
             &impl_generics,
             &where_clause,
-            &backward_fields,
-            &backward_ref_fields,
-        ));
+            &error_type,
+            backward,
+            backward_ref,
+        )),
+        None => output.extend(generate_from_impl_pair(
+            &target_type,
+            &source_type,
+            &impl_generics,
+            &where_clause,
+            ref_where_clause.as_ref(),
+            backward,
+            backward_ref,
+        )),
     }
 
     Ok(output)
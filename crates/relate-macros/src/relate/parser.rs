@@ -4,29 +4,76 @@
 //! - `A ~ B { fields }` - bidirectional
 //! - `A ~> B { fields }` - forward only
 //! - `A ~>? B { fields }` - fallible forward (TryFrom)
+//! - `A ~? B { fields }` - fallible bidirectional (TryFrom both ways)
+//! - `a::A ~> b::B { fields }` - types in other modules, by path
+//! - `A<T> ~> B<T> where T: Bound { fields }` - extra bounds on every impl
 //!
 //! Field syntax uses semicolon terminators:
 //! - `field;` - identity mapping
 //! - `field: cloned;` - with clone mode
+//! - `field: arc_clone;` - clone with `Arc::clone(&field)`
 //! - `field: default = expr;` - default value
+//! - `field: default_with = path;` - default from a factory function `path()`
+//! - `field: default_if_none = .other;` - fall back to another `Option` field
+//! - `field: required;` - unwrap a required `Option` (fallible)
+//! - `field: into;` - convert a nested type with `Into`
+//! - `field: into_owned;` - turn a `Cow<'a, str>` into a `String`
+//! - `field: vec;` - copy a `[T; N]` array or slice into a `Vec<T>`
+//! - `field: with_fn = path;` - call `path(field)` (`path(&field)` by
+//!   reference)
+//! - `field: try_into = path;` - call a fallible `path(field)?`
+//! - `field: some;` - wrap in `Some` for an `Option` target
+//! - `field: unwrap_or_default;` - unwrap an `Option` source, or `T::default()`
+//! - `field: secs_to_duration;` - `u64` seconds to a `Duration`
+//!   (`duration_to_secs;` the other way); both convert back in `~` relations
+//! - `field: skip_if = .cond, default = expr;` - conditional default
 //! - `field: with = expr;` - transform expression
+//! - `{a, b}: with = .shared;` - one source into several target fields
+//! - `field: as i64;` - numeric cast, `with = _ as i64`
+//! - `field: with = map[_.value];` - map over map values, keeping keys
+//! - `field: with = opt[_.id];` - map over an `Option<Vec<_>>`, keeping `None`
+//! - `field: with = expr, reverse = expr;` - with an inverse for `~` / `~?`
+//! - `field: with = expr?, map_err = closure;` - adapt a fallible field's error
+//!
+//! A leading `#![default_error(Type)]` sets the error type of every fallible
+//! relation that doesn't name its own.
+//!
+//! A relation body can start with `#![owned_only]` / `#![ref_only]` to
+//! generate only one of the owned and `&Source` impls, and `#![into_fn]` for
+//! a free `into_b` function instead of the forward impls (for a target in
+//! another crate). `#![roundtrip_check]` adds a
+//! `Target::__relate_roundtrip(Source) -> bool` helper, `#![inline]` marks
+//! the generated functions `#[inline]`, and `#![reverse_cloned]` /
+//! `#![reverse_copy]` / `#![reverse_move]` set the backward impls' clone mode.
+//!
+//! Leading statements: `error = Type;`, `base = expr;` to fill
+//! the unlisted target fields with `..expr`, and `strip_prefix = "db_";` to
+//! read identity fields from `db_<name>`.
 
-use proc_macro2::TokenStream;
+use quote::quote;
 use syn::{
-    Error, Expr, Ident, Result, Token, braced,
+    Attribute, Error, Expr, FieldsNamed, Generics, Ident, LitStr, Member, Result, Token, Type,
+    Visibility, WhereClause, braced,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
     token,
 };
 
 use super::types::{
-    Direction, ExistingRelation, FieldMapping, FieldSource, RelateInput, Relation, RelationBody,
-    Transform, TypeRef,
+    DefineRelation, Direction, ExistingRelation, FieldMapping, FieldSource, ImplSet, RelateInput,
+    Relation, RelationBody, RelationSide, StructDef, Transform, TypeRef,
+};
+use crate::core::{
+    CloneMode, Pointer, apply_strip_prefix, member_name, parse_collection_body,
+    parse_default_if_none, parse_sequence_map_body, parse_skip_if, parse_tokens_until_terminator,
+    parse_trailing_clone_mode, parse_trailing_map_err, peek_map_values, peek_option_collection,
+    peek_trailing_option,
 };
-use crate::core::{CloneMode, parse_tokens_until_terminator, parse_trailing_clone_mode};
 
 impl Parse for RelateInput {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut relations = Vec::new();
+        let default_error = parse_default_error(input)?;
 
         while !input.is_empty() {
             relations.push(input.parse()?);
@@ -37,45 +84,347 @@ impl Parse for RelateInput {
             }
         }
 
-        Ok(Self { relations })
+        Ok(Self {
+            relations,
+            default_error,
+        })
     }
 }
 
-impl Parse for Relation {
-    fn parse(input: ParseStream) -> Result<Self> { Ok(Self(input.parse()?)) }
+/// Parse a leading `#![default_error(Type)]`, the error type of every
+/// fallible relation in the invocation that doesn't name its own.
+fn parse_default_error(input: ParseStream) -> Result<Option<Box<Type>>> {
+    let mut default_error = None;
+    for attr in Attribute::parse_inner(input)? {
+        if !attr.path().is_ident("default_error") {
+            return Err(Error::new_spanned(
+                attr.path(),
+                "Unknown inner attribute.\n\
+                 Only `#![default_error(Type)]` is supported at the start of `relate_structs!`",
+            ));
+        }
+        if default_error.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "`default_error` is given more than once",
+            ));
+        }
+        default_error = Some(Box::new(attr.parse_args::<Type>()?));
+    }
+    Ok(default_error)
 }
 
-impl Parse for ExistingRelation {
+impl Parse for Relation {
     fn parse(input: ParseStream) -> Result<Self> {
         let source = input.parse()?;
+        let direction_span = input.span();
         let direction = input.parse()?;
         let target = input.parse()?;
+        // `Source<T> ~> Target<T> where T: Bound { .. }`
+        let bounds: Option<WhereClause> = input.parse()?;
 
-        let body = if input.peek(token::Brace) {
+        let body: Option<RelationBody> = if input.peek(token::Brace) {
             Some(input.parse()?)
         } else {
             None
         };
 
-        Ok(Self {
+        let body_error = body.as_ref().and_then(|b| b.error_type.clone());
+        let direction = merge_body_error(direction, body_error, direction_span)?;
+
+        match (source, target) {
+            (RelationSide::Existing(source), RelationSide::Existing(target)) => {
+                Ok(Self::Existing(ExistingRelation {
+                    source,
+                    direction,
+                    target,
+                    bounds,
+                    body,
+                }))
+            }
+            (source, target) => {
+                define_relation(source, direction, target, bounds, body).map(Self::Define)
+            }
+        }
+    }
+}
+
+/// Fold a body-level `error = Type;` into the direction.
+///
+/// `A ~> B { error = E; .. }` is equivalent to `A ~>?[E] B { .. }`, and
+/// `A ~? B { error = E; .. }` to `A ~?[E] B { .. }`.
+fn merge_body_error(
+    direction: Direction,
+    body_error: Option<Box<Type>>,
+    span: proc_macro2::Span,
+) -> Result<Direction> {
+    let Some(error) = body_error else {
+        return Ok(direction);
+    };
+    match direction {
+        Direction::Forward | Direction::TryForward(None) => Ok(Direction::TryForward(Some(error))),
+        Direction::TryBidirectional(None) => Ok(Direction::TryBidirectional(Some(error))),
+        Direction::TryForward(Some(_))
+        | Direction::TryForwardOption
+        | Direction::TryBidirectional(Some(_)) => Err(Error::new_spanned(
+            error,
+            "Conflicting error types: `[E]` after the direction and `error = Type;` both given.\n\
+                 Specify the error type only once",
+        )),
+        Direction::Bidirectional => Err(Error::new(
+            span,
+            "`error = Type;` requires a fallible-capable relation (`~>`, `~>?`, or `~?`).\n\
+             Use `~?` for a fallible bidirectional relation",
+        )),
+    }
+}
+
+/// Build a relation where one or both sides are inline struct definitions.
+///
+/// A defined side without its own field list shares the other side's fields
+/// (`struct A ~ struct B { id: i32 }`). Without a relation body, every field
+/// of the defined target is mapped by name.
+fn define_relation(
+    source: RelationSide,
+    direction: Direction,
+    target: RelationSide,
+    bounds: Option<WhereClause>,
+    body: Option<RelationBody>,
+) -> Result<DefineRelation> {
+    let shared_fields = match (&target, &source) {
+        (
+            RelationSide::Defined(StructDef {
+                fields: Some(f), ..
+            }),
+            _,
+        )
+        | (
+            _,
+            RelationSide::Defined(StructDef {
+                fields: Some(f), ..
+            }),
+        ) => Some(f.clone()),
+        _ => None,
+    };
+
+    let mut definitions = Vec::new();
+    let (source, source_fields) = resolve_side(source, shared_fields.as_ref(), &mut definitions)?;
+    let (target, target_fields) = resolve_side(target, shared_fields.as_ref(), &mut definitions)?;
+    let defined = [(&source, source_fields), (&target, target_fields.clone())];
+
+    // No body: map every field of the defined target by name.
+    // Spread `..`: explicitly listed fields win, the rest map by name.
+    let body = match (body, target_fields) {
+        (None, Some(fields)) => {
+            let empty = RelationBody {
+                has_spread: false,
+                fields:     Vec::new(),
+                error_type: None,
+                impls:      ImplSet::Both,
+                into_fn:    false,
+                roundtrip:  None,
+                inline:     false,
+                base:       None,
+                reverse:    None,
+            };
+            Some(spread_fields(empty, fields))
+        }
+        (Some(body), Some(fields)) if body.has_spread => Some(spread_fields(body, fields)),
+        (body, _) => body,
+    };
+    if let Some(body) = &body {
+        check_defined_fields(body, &defined)?;
+    }
+
+    Ok(DefineRelation {
+        definitions,
+        relation: ExistingRelation {
             source,
             direction,
             target,
+            bounds,
             body,
+        },
+    })
+}
+
+/// Check a relation body against the structs defined in the macro.
+///
+/// Mappings must name fields of a defined target and read fields of a
+/// defined source, and every field of a defined target must be mapped unless
+/// `base = expr;` fills the rest.
+fn check_defined_fields(
+    body: &RelationBody,
+    [(source, source_fields), (target, target_fields)]: &[(&TypeRef, Option<FieldsNamed>); 2],
+) -> Result<()> {
+    let names = |fields: &FieldsNamed| -> Vec<Member> {
+        fields
+            .named
+            .iter()
+            .filter_map(|f| f.ident.clone().map(Member::Named))
+            .collect()
+    };
+
+    if let Some(fields) = target_fields {
+        let declared = names(fields);
+        if let Some(mapping) = body
+            .fields
+            .iter()
+            .find(|m| !declared.contains(&m.target_field))
+        {
+            return Err(Error::new_spanned(
+                &mapping.target_field,
+                format!(
+                    "`struct {}` has no field `{}`.\n\
+                     Mapped fields must be declared in the struct definition",
+                    target.name(),
+                    member_name(&mapping.target_field)
+                ),
+            ));
+        }
+        let mapped = |member: &Member| body.fields.iter().any(|m| m.target_field == *member);
+        if let (Some(unmapped), None) = (declared.iter().find(|f| !mapped(f)), &body.base) {
+            return Err(Error::new_spanned(
+                unmapped,
+                format!(
+                    "Field `{}` of `struct {}` is not mapped.\n\
+                     Map it in the relation body, add `..` to map the remaining fields by \
+                     name, or fill them with `base = expr;`",
+                    member_name(unmapped),
+                    target.name()
+                ),
+            ));
+        }
+    }
+
+    if let Some(fields) = source_fields {
+        let declared = names(fields);
+        for mapping in &body.fields {
+            let read = mapping.source.read_fields(&mapping.target_field);
+            if let Some(missing) = read.iter().find(|f| !declared.contains(f)) {
+                return Err(Error::new_spanned(
+                    missing,
+                    format!(
+                        "`struct {}` has no field `{}` for the `{}` mapping",
+                        source.name(),
+                        member_name(missing),
+                        member_name(&mapping.target_field)
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Add identity mappings for target fields not already listed in the body.
+fn spread_fields(mut body: RelationBody, target_fields: FieldsNamed) -> RelationBody {
+    for ident in target_fields
+        .named
+        .into_iter()
+        .filter_map(|field| field.ident)
+    {
+        let member = Member::Named(ident);
+        if !body.fields.iter().any(|f| f.target_field == member) {
+            body.fields.push(FieldMapping {
+                target_field: member,
+                source:       FieldSource::auto(),
+            });
+        }
+    }
+    body.has_spread = false;
+    body
+}
+
+/// Resolve a relation side to a type reference, collecting its definition.
+///
+/// Returns the side's field list when it is defined inline.
+fn resolve_side(
+    side: RelationSide,
+    shared_fields: Option<&FieldsNamed>,
+    definitions: &mut Vec<StructDef>,
+) -> Result<(TypeRef, Option<FieldsNamed>)> {
+    let mut def = match side {
+        RelationSide::Existing(type_ref) => return Ok((type_ref, None)),
+        RelationSide::Defined(def) => def,
+    };
+
+    if def.fields.is_none() {
+        let Some(shared) = shared_fields else {
+            return Err(Error::new_spanned(
+                &def.name,
+                format!(
+                    "`struct {}` needs a field list.\n\
+                     Define fields on this side, or on the other side to share them:\n\
+                     `struct A ~ struct B {{ id: i32, }}`",
+                    def.name
+                ),
+            ));
+        };
+        def.fields = Some(shared.clone());
+    }
+
+    let type_ref = TypeRef {
+        path:     def.name.clone().into(),
+        generics: (!def.generics.params.is_empty()).then(|| def.generics.clone()),
+    };
+    let fields = def.fields.clone();
+    definitions.push(def);
+    Ok((type_ref, fields))
+}
+
+impl Parse for RelationSide {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // Look past attributes and visibility for the `struct` keyword
+        let fork = input.fork();
+        fork.call(Attribute::parse_outer)?;
+        fork.parse::<Visibility>()?;
+
+        if fork.peek(Token![struct]) {
+            Ok(Self::Defined(input.parse()?))
+        } else {
+            Ok(Self::Existing(input.parse()?))
+        }
+    }
+}
+
+impl Parse for StructDef {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let name = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+
+        let fields = if input.peek(token::Brace) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            generics,
+            fields,
         })
     }
 }
 
 impl Parse for TypeRef {
     fn parse(input: ParseStream) -> Result<Self> {
-        let name: Ident = input.parse()?;
+        // Generics are parsed separately so they can carry bounds
+        let path = input.call(syn::Path::parse_mod_style)?;
 
         let generics = if input.peek(Token![<]) {
             Some(input.parse()?)
         } else {
             None
         };
-        Ok(Self { name, generics })
+        Ok(Self { path, generics })
     }
 }
 
@@ -84,11 +433,18 @@ impl Parse for Direction {
         // Must have ~ for all directions
         if !input.peek(Token![~]) {
             return Err(input.error(
-                "Expected `~>` (forward), `~` (bidirectional), or `~>?` (fallible forward)",
+                "Expected `~>` (forward), `~` (bidirectional), `~>?` (fallible forward), \
+                 or `~?` (fallible bidirectional)",
             ));
         }
         input.parse::<Token![~]>()?;
 
+        // ~? with optional [ErrorType] = fallible bidirectional
+        if input.peek(Token![?]) {
+            input.parse::<Token![?]>()?;
+            return Ok(Self::TryBidirectional(parse_error_type(input)?));
+        }
+
         // ~ alone = bidirectional
         if !input.peek(Token![>]) {
             return Ok(Self::Bidirectional);
@@ -101,16 +457,36 @@ impl Parse for Direction {
         }
         input.parse::<Token![?]>()?;
 
-        // ~>? with optional [ErrorType]
-        let error_type = if input.peek(syn::token::Bracket) {
+        // ~>?[Option]: `?` on `Option`s
+        if input.peek(syn::token::Bracket) {
+            let fork = input.fork();
             let content;
-            syn::bracketed!(content in input);
-            Some(Box::new(content.parse::<syn::Type>()?))
-        } else {
-            None
-        };
+            syn::bracketed!(content in fork);
+            if content
+                .parse::<Ident>()
+                .is_ok_and(|ident| ident == "Option")
+                && content.is_empty()
+            {
+                let content;
+                syn::bracketed!(content in input);
+                content.parse::<Ident>()?;
+                return Ok(Self::TryForwardOption);
+            }
+        }
+
+        // ~>? with optional [ErrorType]
+        Ok(Self::TryForward(parse_error_type(input)?))
+    }
+}
 
-        Ok(Self::TryForward(error_type))
+/// Parse the optional `[ErrorType]` after `~>?` or `~?`.
+fn parse_error_type(input: ParseStream) -> Result<Option<Box<Type>>> {
+    if input.peek(syn::token::Bracket) {
+        let content;
+        syn::bracketed!(content in input);
+        Ok(Some(Box::new(content.parse::<Type>()?)))
+    } else {
+        Ok(None)
     }
 }
 
@@ -121,6 +497,110 @@ impl Parse for RelationBody {
 
         let mut has_spread = false;
         let mut fields = Vec::new();
+        let mut error_type = None;
+        let mut impls = ImplSet::Both;
+        let mut into_fn = false;
+        let mut roundtrip = None;
+        let mut inline = false;
+        let mut base = None;
+        let mut strip_prefix = None;
+        let mut reverse = None;
+
+        // Leading `#![owned_only]` / `#![into_fn]` / `#![reverse_cloned]` and
+        // the other options
+        for attr in Attribute::parse_inner(&content)? {
+            attr.meta.require_path_only()?;
+            let unknown = || {
+                Error::new_spanned(
+                    attr.path(),
+                    "Unknown inner attribute.\n\
+                     Valid relation options: `#![owned_only]`, `#![ref_only]`, `#![into_fn]`,\n\
+                     `#![roundtrip_check]`, `#![inline]`, `#![reverse_cloned]`, `#![reverse_copy]`,\n\
+                     `#![reverse_move]`",
+                )
+            };
+            let ident = attr.path().get_ident().cloned().ok_or_else(unknown)?;
+            match ident.to_string().as_str() {
+                "owned_only" | "ref_only" => {
+                    let set = if ident == "owned_only" {
+                        ImplSet::OwnedOnly
+                    } else {
+                        ImplSet::RefOnly
+                    };
+                    if impls != ImplSet::Both && impls != set {
+                        return Err(Error::new_spanned(
+                            attr,
+                            "`owned_only` and `ref_only` can't be combined",
+                        ));
+                    }
+                    impls = set;
+                }
+                "into_fn" => into_fn = true,
+                "roundtrip_check" => roundtrip = Some(ident),
+                "inline" => inline = true,
+                "reverse_cloned" | "reverse_copy" | "reverse_move" => {
+                    let mode = match ident.to_string().as_str() {
+                        "reverse_cloned" => CloneMode::Cloned,
+                        "reverse_copy" => CloneMode::Copy,
+                        _ => CloneMode::Move,
+                    };
+                    if reverse.as_ref().is_some_and(|(_, prev)| *prev != mode) {
+                        return Err(Error::new_spanned(
+                            attr,
+                            "Only one of `reverse_cloned`, `reverse_copy` and `reverse_move` \
+                             can be used",
+                        ));
+                    }
+                    reverse = Some((ident, mode));
+                }
+                _ => return Err(unknown()),
+            }
+        }
+
+        loop {
+            // Leading `error = Type;` / `base = expr;` / `strip_prefix = "..";`
+            // (a field with one of these names uses `:` instead)
+            if content.peek(Ident) && content.peek2(Token![=]) {
+                let ident: Ident = content.fork().parse()?;
+                if ident == "base" {
+                    content.parse::<Ident>()?;
+                    content.parse::<Token![=]>()?;
+                    base = Some(content.parse::<Expr>()?);
+                    content.parse::<Token![;]>()?;
+                    continue;
+                }
+                if ident == "strip_prefix" {
+                    content.parse::<Ident>()?;
+                    content.parse::<Token![=]>()?;
+                    let prefix = content.parse::<LitStr>()?;
+                    content.parse::<Token![;]>()?;
+                    if strip_prefix.is_some() {
+                        return Err(Error::new_spanned(
+                            prefix,
+                            "`strip_prefix = \"..\";` given twice; keep only one",
+                        ));
+                    }
+                    strip_prefix = Some(prefix);
+                    continue;
+                }
+                if ident != "error" {
+                    return Err(Error::new_spanned(
+                        ident,
+                        "Expected `:` or `;` after field name.\n\
+                         Only `error = Type;`, `base = expr;` and `strip_prefix = \"prefix\";` \
+                         may use `=` at the start of a relation body",
+                    ));
+                }
+                content.parse::<Ident>()?;
+                content.parse::<Token![=]>()?;
+                let ty: Type = content.parse()?;
+                content.parse::<Token![;]>()?;
+                error_type = Some(Box::new(ty));
+                continue;
+            }
+
+            break;
+        }
 
         while !content.is_empty() {
             // Check for spread `..`
@@ -134,8 +614,12 @@ impl Parse for RelationBody {
                 continue;
             }
 
-            // Parse field mapping with new syntax
-            fields.push(parse_field_mapping(&content)?);
+            // Parse field mapping with new syntax; `{a, b}: ...` fills several
+            if content.peek(token::Brace) {
+                fields.extend(parse_split_mapping(&content)?);
+            } else {
+                fields.push(parse_field_mapping(&content)?);
+            }
 
             // Semicolon terminator (required, but be lenient for trailing)
             if content.peek(Token![;]) {
@@ -148,7 +632,33 @@ impl Parse for RelationBody {
             }
         }
 
-        Ok(Self { has_spread, fields })
+        if let (true, Some(base)) = (has_spread, &base) {
+            return Err(Error::new_spanned(
+                base,
+                "`base = expr;` and `..` both fill the unlisted fields; keep only one",
+            ));
+        }
+        if let Some(prefix) = &strip_prefix {
+            if has_spread {
+                return Err(Error::new_spanned(
+                    prefix,
+                    "`strip_prefix` can't be combined with `..`; list the fields instead",
+                ));
+            }
+            apply_strip_prefix(&mut fields, prefix)?;
+        }
+
+        Ok(Self {
+            has_spread,
+            fields,
+            error_type,
+            impls,
+            into_fn,
+            roundtrip,
+            inline,
+            base,
+            reverse,
+        })
     }
 }
 
@@ -156,12 +666,14 @@ impl Parse for RelationBody {
 /// `field;` or `field: modifier;`
 ///
 /// Modifier can be:
-/// - `cloned`, `copy`, `move` (clone mode)
-/// - `default` or `default = expr`
-/// - `with = expr` optionally followed by `, clone_mode`
+/// - `cloned`, `copy`, `move`, `arc_clone` (clone mode)
+/// - `default`, `default = expr` or `default_with = path`
+/// - `default_if_none = .field`, optionally followed by `, clone_mode`
+/// - `with = expr` optionally followed by `, clone_mode`, `, reverse = expr`,
+///   and `, map_err = closure`
 fn parse_field_mapping(input: ParseStream) -> Result<FieldMapping> {
     // Parse field name
-    let field: Ident = input.parse()?;
+    let field = Member::Named(input.parse()?);
 
     // Check for modifier (`:` followed by something)
     if !input.peek(Token![:]) {
@@ -179,8 +691,44 @@ fn parse_field_mapping(input: ParseStream) -> Result<FieldMapping> {
     parse_field_modifier(input, field)
 }
 
+/// Parse a mapping into several target fields: `{a, b}: with = .shared;`
+///
+/// Expands into one mapping per target with the same source, so the usual
+/// multi-use detection clones the value for every target but the last.
+fn parse_split_mapping(input: ParseStream) -> Result<Vec<FieldMapping>> {
+    let content;
+    let braces = braced!(content in input);
+    let targets = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+    let Some(first) = targets.first() else {
+        return Err(Error::new(
+            braces.span.join(),
+            "Expected target fields inside `{}`",
+        ));
+    };
+    input.parse::<Token![:]>()?;
+    let mapping = parse_field_modifier(input, Member::Named(first.clone()))?;
+
+    let shared_key = mapping.source.get_usage_key(&mapping.target_field);
+    let mut mappings = Vec::new();
+    for target in targets {
+        let target_field = Member::Named(target);
+        if mapping.source.get_usage_key(&target_field) != shared_key {
+            return Err(Error::new_spanned(
+                target_field,
+                "`{a, b}: ...` needs a source shared by every target field, \
+                 e.g. `{a, b}: with = .shared;`",
+            ));
+        }
+        mappings.push(FieldMapping {
+            target_field,
+            source: mapping.source.clone(),
+        });
+    }
+    Ok(mappings)
+}
+
 /// Parse the modifier after `field:`
-fn parse_field_modifier(input: ParseStream, field: Ident) -> Result<FieldMapping> {
+fn parse_field_modifier(input: ParseStream, field: Member) -> Result<FieldMapping> {
     // Check for `move` keyword (special handling since it's a Rust keyword)
     if input.peek(Token![move]) {
         input.parse::<Token![move]>()?;
@@ -192,20 +740,47 @@ fn parse_field_modifier(input: ParseStream, field: Ident) -> Result<FieldMapping
         });
     }
 
+    // Numeric cast: `as Type` is `with = _ as Type`, optionally followed by
+    // `, clone_mode` and `, reverse = expr`
+    if input.peek(Token![as]) {
+        input.parse::<Token![as]>()?;
+        let ty: Type = input.parse()?;
+        let cast = Transform::WithExpr(quote! { _ as #ty }, false);
+        let mut source = FieldSource::with_transform(cast);
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
     // Must be an identifier for other modifiers
     if !input.peek(Ident) {
         return Err(Error::new(
             input.span(),
             "Expected modifier after `:`. Valid modifiers:\n\
-             - `cloned`, `copy`, `move` (clone mode)\n\
+             - `cloned`, `copy`, `move`, `arc_clone` (clone mode)\n\
+             - `as Type` (numeric cast)\n\
              - `default` or `default = expr`\n\
+             - `default_with = path`\n\
+             - `default_if_none = .field`\n\
+             - `required`\n\
+             - `into`, `into_owned`, `vec`\n\
+             - `with_fn = path`\n\
+             - `try_into = path`\n\
+             - `getter = method`\n\
+             - `some`\n\
+             - `boxed`, `rc`, `arc`, `unbox`\n\
+             - `unwrap_or_default`\n\
+             - `secs_to_duration`, `duration_to_secs`\n\
+             - `skip_if = .cond, default = expr`\n\
              - `with = expr`",
         ));
     }
 
     let modifier: Ident = input.parse()?;
 
-    // Clone modes: `cloned`, `copy`
+    // Clone modes: `cloned`, `copy`, `arc_clone`
     if modifier == "cloned" {
         let mut source = FieldSource::auto();
         source.clone_mode = Some(CloneMode::Cloned);
@@ -222,6 +797,147 @@ fn parse_field_modifier(input: ParseStream, field: Ident) -> Result<FieldMapping
             source,
         });
     }
+    if modifier == "arc_clone" {
+        let mut source = FieldSource::auto();
+        source.clone_mode = Some(CloneMode::ArcClone);
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Required: `Option` source field must be `Some`
+    if modifier == "required" {
+        return Ok(FieldMapping {
+            target_field: field,
+            source:       FieldSource::with_transform(Transform::Required),
+        });
+    }
+
+    // Some: wrap in `Option`, optionally followed by `, clone_mode`
+    // and `, reverse = expr`
+    if modifier == "some" {
+        let mut source = FieldSource::with_transform(Transform::WrapSome);
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Smart pointers: wrap in `Box` / `Rc` / `Arc`, or move out of a `Box`,
+    // optionally followed by `, clone_mode` and `, reverse = expr`
+    let pointer = Pointer::from_keyword(&modifier.to_string());
+    if pointer.is_some() || modifier == "unbox" {
+        let transform = pointer.map_or(Transform::Unbox, Transform::Wrap);
+        let mut source = FieldSource::with_transform(transform);
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Function call: `with_fn = path`, optionally followed by `, clone_mode`
+    // and `, reverse = expr`
+    if modifier == "with_fn" {
+        input.parse::<Token![=]>()?;
+        let mut source = FieldSource::with_transform(Transform::FnCall(input.parse()?));
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Getter: `getter = method` calls `src.method()`, optionally followed by
+    // `, cloned` and `, reverse = expr`
+    if modifier == "getter" {
+        input.parse::<Token![=]>()?;
+        let mut source = FieldSource::with_transform(Transform::Getter(input.parse()?));
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Fallible function call: `try_into = path`, optionally followed by
+    // `, clone_mode` and `, map_err = closure`
+    if modifier == "try_into" {
+        input.parse::<Token![=]>()?;
+        let mut source = FieldSource::with_transform(Transform::TryFnCall(input.parse()?));
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Into: convert a nested type, optionally followed by `, clone_mode`
+    // and `, reverse = expr`
+    if modifier == "into" {
+        let mut source = FieldSource::with_transform(Transform::Into);
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Into owned: `Cow` source to its owned form, optionally followed by
+    // `, clone_mode` and `, reverse = expr`
+    if modifier == "into_owned" {
+        let mut source = FieldSource::with_transform(Transform::IntoOwned);
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Vec: array or slice source to a `Vec`, optionally followed by
+    // `, clone_mode` and `, reverse = expr`
+    if modifier == "vec" {
+        let mut source = FieldSource::with_transform(Transform::ToVec);
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Unwrap or default: `Option<T>` source to `T`
+    if modifier == "unwrap_or_default" {
+        let mut source = FieldSource::unwrap_or_default();
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Durations: `u64` seconds to `Duration` and back, with a built-in reverse
+    if modifier == "secs_to_duration" || modifier == "duration_to_secs" {
+        let mut source = if modifier == "secs_to_duration" {
+            FieldSource::secs_to_duration()
+        } else {
+            FieldSource::duration_to_secs()
+        };
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
+    // Skip if: `skip_if = .cond` with optional `, default` / `, default = expr`
+    if modifier == "skip_if" {
+        return Ok(FieldMapping {
+            target_field: field,
+            source:       FieldSource::with_transform(parse_skip_if(input, true)?),
+        });
+    }
 
     // Default: `default` or `default = expr`
     if modifier == "default" {
@@ -239,42 +955,37 @@ fn parse_field_modifier(input: ParseStream, field: Ident) -> Result<FieldMapping
         });
     }
 
+    // Default factory: `default_with = path`
+    if modifier == "default_with" {
+        input.parse::<Token![=]>()?;
+        let path: syn::Path = input.parse()?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source:       FieldSource::default_fn(path),
+        });
+    }
+
+    // Fallback field: `default_if_none = .field`, optionally followed by
+    // `, clone_mode`
+    if modifier == "default_if_none" {
+        let mut source = FieldSource::with_transform(parse_default_if_none(input)?);
+        parse_trailing_options(input, &mut source)?;
+        return Ok(FieldMapping {
+            target_field: field,
+            source,
+        });
+    }
+
     // With expression: `with = expr` optionally followed by `, clone_mode`
+    // and `, reverse = expr`
     if modifier == "with" {
         if !input.peek(Token![=]) {
-            return Err(Error::new(input.span(), "Expected `=` after `with`"));
+            return Err(Error::new_spanned(&modifier, "Expected `=` after `with`"));
         }
         input.parse::<Token![=]>()?;
 
-        // Check for collection map syntax: `with = [_.field]`
-        if input.peek(token::Bracket) {
-            let content;
-            syn::bracketed!(content in input);
-            let inner: TokenStream = content.parse()?;
-
-            // If it starts with `.`, it's shorthand: [.id] -> [_.id]
-            let inner_str = inner.to_string();
-            let tokens = if inner_str.starts_with('.') {
-                let underscore = Ident::new("_", proc_macro2::Span::call_site());
-                quote::quote! { #underscore #inner }
-            } else {
-                inner
-            };
-
-            let clone_mode = parse_trailing_clone_mode(input, true)?;
-            let mut source = FieldSource::with_transform(Transform::CollectionMap(tokens));
-            source.clone_mode = clone_mode;
-            return Ok(FieldMapping {
-                target_field: field,
-                source,
-            });
-        }
-
-        // Regular expression
-        let (tokens, fallible) = parse_tokens_until_terminator(input, true)?;
-        let clone_mode = parse_trailing_clone_mode(input, true)?;
-        let mut source = FieldSource::with_expr(tokens, fallible);
-        source.clone_mode = clone_mode;
+        let mut source = FieldSource::with_transform(parse_with_transform(input)?);
+        parse_trailing_options(input, &mut source)?;
         return Ok(FieldMapping {
             target_field: field,
             source,
@@ -285,18 +996,103 @@ fn parse_field_modifier(input: ParseStream, field: Ident) -> Result<FieldMapping
         &modifier,
         format!(
             "Unknown modifier `{}`. Valid modifiers:\n\
-             - `cloned`, `copy`, `move` (clone mode)\n\
+             - `cloned`, `copy`, `move`, `arc_clone` (clone mode)\n\
              - `default` or `default = expr`\n\
+             - `default_with = path`\n\
+             - `default_if_none = .field`\n\
+             - `required`\n\
+             - `into`, `into_owned`, `vec`\n\
+             - `try_into = path`\n\
+             - `getter = method`\n\
+             - `some`\n\
+             - `boxed`, `rc`, `arc`, `unbox`\n\
+             - `unwrap_or_default`\n\
+             - `secs_to_duration`, `duration_to_secs`\n\
+             - `skip_if = .cond, default = expr`\n\
              - `with = expr`",
             modifier
         ),
     ))
 }
 
-/// Parse an expression until semicolon (for `default = expr;`)
+/// Parse the right-hand side of `with =` or `reverse =`: a collection map
+/// `[_.field]`, a map-value map `map[_.field]`, an optional collection map
+/// `opt[_.field]`, or an expression.
+fn parse_with_transform(input: ParseStream) -> Result<Transform> {
+    // Check for collection map syntax: `with = [_.field]`
+    if input.peek(token::Bracket) {
+        let (tokens, index) = parse_sequence_map_body(input)?;
+        return Ok(Transform::CollectionMap(tokens, index));
+    }
+
+    // Check for map value syntax: `with = map[_.field]`
+    if peek_map_values(input) {
+        input.parse::<Ident>()?; // consume "map"
+        return Ok(Transform::MapValues(parse_collection_body(input)?));
+    }
+
+    // Check for optional collection syntax: `with = opt[_.field]`
+    if peek_option_collection(input) {
+        input.parse::<Ident>()?; // consume "opt"
+        let (tokens, index) = parse_sequence_map_body(input)?;
+        return Ok(Transform::OptionCollectionMap(tokens, index));
+    }
+
+    // Regular expression
+    let (tokens, fallible) = parse_tokens_until_terminator(input, true)?;
+    Ok(Transform::WithExpr(tokens, fallible))
+}
+
+/// Parse trailing `, clone_mode`, `, reverse = expr`, and `, map_err = closure`
+/// options, in any order.
+fn parse_trailing_options(input: ParseStream, source: &mut FieldSource) -> Result<()> {
+    while input.peek(Token![,]) {
+        if peek_trailing_option(input, "map_err") {
+            if source.map_err.is_some() {
+                return Err(Error::new(option_span(input), "Duplicate `map_err` option"));
+            }
+            source.map_err = parse_trailing_map_err(input, &source.transform)?;
+        } else if peek_trailing_option(input, "reverse") {
+            input.parse::<Token![,]>()?;
+            let keyword: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            if source.reverse.is_some() {
+                return Err(Error::new_spanned(keyword, "Duplicate `reverse` option"));
+            }
+            source.reverse = Some(Box::new(parse_with_transform(input)?));
+        } else {
+            if source.clone_mode.is_some() {
+                return Err(Error::new(
+                    option_span(input),
+                    "Duplicate clone mode option",
+                ));
+            }
+            source.clone_mode = parse_trailing_clone_mode(input, true)?;
+        }
+    }
+    Ok(())
+}
+
+/// The span of the trailing option after the `,` at the start of `input`.
+fn option_span(input: ParseStream) -> proc_macro2::Span {
+    let fork = input.fork();
+    let _ = fork.parse::<Token![,]>();
+    fork.span()
+}
+
+/// Parse an expression until the field's `;` (for `default = expr;`).
+///
+/// Tokens are collected up to the top-level `;` first, so struct literals,
+/// blocks, and closures (whose braces and inner `;` are nested groups) can't
+/// run into the next field.
 fn parse_expr_until_semicolon(input: ParseStream) -> Result<Expr> {
-    // We need to parse tokens until `;` and then parse as Expr
-    // This is tricky because Expr parsing is greedy
-    // For now, just parse a single Expr and hope it works
-    input.parse()
+    let span = input.span();
+    let mut tokens = proc_macro2::TokenStream::new();
+    while !input.is_empty() && !input.peek(Token![;]) {
+        tokens.extend(std::iter::once(input.parse::<proc_macro2::TokenTree>()?));
+    }
+    if tokens.is_empty() {
+        return Err(Error::new(span, "Expected an expression after `default =`"));
+    }
+    syn::parse2(tokens)
 }
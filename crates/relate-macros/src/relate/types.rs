@@ -2,21 +2,56 @@
 //!
 //! Re-exports core types and adds macro-specific input types.
 
-use syn::{Generics, Ident, Type};
+use syn::{Attribute, Expr, FieldsNamed, Generics, Ident, Path, Type, Visibility, WhereClause};
 
 // Re-export core types
-pub use crate::core::{FieldMapping, FieldSource, Transform};
+pub use crate::core::{CloneMode, FieldMapping, FieldSource, ImplSet, Transform};
 
 /// The complete parsed input to the `relate_structs!` macro.
 #[derive(Debug)]
 pub struct RelateInput {
     /// The relation definitions
-    pub relations: Vec<Relation>,
+    pub relations:     Vec<Relation>,
+    /// Error type of fallible relations that don't name one
+    /// (`#![default_error(Type)]`)
+    pub default_error: Option<Box<Type>>,
 }
 
 /// A single relation between two types.
 #[derive(Debug)]
-pub struct Relation(pub ExistingRelation);
+pub enum Relation {
+    /// `A ~> B { fields }` - relate existing structs
+    Existing(ExistingRelation),
+    /// `#[attrs] struct A ~ #[attrs] struct B { fields }` - define and relate
+    Define(DefineRelation),
+}
+
+/// Relation where one or both structs are defined inline.
+#[derive(Debug)]
+pub struct DefineRelation {
+    /// Struct items to emit alongside the conversion impls
+    pub definitions: Vec<StructDef>,
+    /// The relation between the (now defined) structs
+    pub relation:    ExistingRelation,
+}
+
+/// A struct defined inside the macro: `#[attrs] pub struct Name<T> { fields }`.
+#[derive(Debug)]
+pub struct StructDef {
+    pub attrs:    Vec<Attribute>,
+    pub vis:      Visibility,
+    pub name:     Ident,
+    pub generics: Generics,
+    /// Field definitions (None = share the other side's fields)
+    pub fields:   Option<FieldsNamed>,
+}
+
+/// One side of a relation: an existing type or an inline struct definition.
+#[derive(Debug)]
+pub enum RelationSide {
+    Existing(TypeRef),
+    Defined(StructDef),
+}
 
 /// Relation between two existing structs.
 #[derive(Debug)]
@@ -27,6 +62,9 @@ pub struct ExistingRelation {
     pub direction: Direction,
     /// Target type
     pub target:    TypeRef,
+    /// Extra bounds from a `where` clause after the target, added to the
+    /// generics' own bounds
+    pub bounds:    Option<WhereClause>,
     /// Field mappings (using core `FieldMapping` type)
     pub body:      Option<RelationBody>,
 }
@@ -34,17 +72,51 @@ pub struct ExistingRelation {
 /// A type reference with optional generics.
 #[derive(Debug)]
 pub struct TypeRef {
-    pub name:     Ident,
+    /// The type's path without generics (`User`, `db::User`)
+    pub path:     Path,
+    /// Generics written after the final segment, with any bounds
     pub generics: Option<Generics>,
 }
 
+impl TypeRef {
+    /// The type's own name: the final path segment.
+    pub fn name(&self) -> &Ident {
+        &self
+            .path
+            .segments
+            .last()
+            .expect("type path has a segment")
+            .ident
+    }
+}
+
 /// The body of a relation with field mappings.
 #[derive(Debug)]
 pub struct RelationBody {
-    /// Whether spread `..` is present (auto-map remaining fields)
+    /// Whether spread `..` is present (auto-map remaining fields).
+    /// Resolved at parse time when the target is defined in the macro.
     pub has_spread: bool,
     /// Field mappings using the unified `FieldMapping` type
     pub fields:     Vec<FieldMapping>,
+    /// Leading `error = Type;` statement (same as `~>?[Type]`)
+    pub error_type: Option<Box<Type>>,
+    /// Leading `#![owned_only]` / `#![ref_only]`: which of the owned and
+    /// `&Source` impls to generate
+    pub impls:      ImplSet,
+    /// Leading `#![into_fn]`: generate a free `into_<target>` function instead
+    /// of the forward impls
+    pub into_fn:    bool,
+    /// Leading `#![roundtrip_check]`: generate
+    /// `Target::__relate_roundtrip(Source) -> bool`
+    pub roundtrip:  Option<Ident>,
+    /// Leading `#![inline]`: mark the generated functions `#[inline]`
+    pub inline:     bool,
+    /// Leading `base = expr;` statement: fill unlisted target fields with
+    /// `..expr` in the forward impls
+    pub base:       Option<Expr>,
+    /// Leading `#![reverse_cloned]` / `#![reverse_copy]` / `#![reverse_move]`:
+    /// the clone mode of every field in the backward impls
+    pub reverse:    Option<(Ident, CloneMode)>,
 }
 
 /// Direction of the From/TryFrom implementation generation.
@@ -57,4 +129,10 @@ pub enum Direction {
     /// `~>?` Generate `TryFrom<Source> for Target` with default error type
     /// `~>?[E]` Generate `TryFrom<Source> for Target` with custom error type E
     TryForward(Option<Box<Type>>),
+    /// `~>?[Option]` Generate `TryFrom<Source> for Target` with error `()`,
+    /// where `?` short-circuits on `Option`s and `None` becomes `Err(())`
+    TryForwardOption,
+    /// `~?` Generate `TryFrom` in both directions with default error type
+    /// `~?[E]` Generate `TryFrom` in both directions with custom error type E
+    TryBidirectional(Option<Box<Type>>),
 }
@@ -0,0 +1,45 @@
+//! Borrow-only `with` expressions expansion test - shows that calls and
+//! operators read their fields without cloning them, in both impls.
+use relate::Relate;
+struct Source {
+    name: String,
+    width: u32,
+    height: u32,
+}
+#[relate(Source)]
+struct Target {
+    #[relate(.name.len())]
+    name_len: usize,
+    name: String,
+    #[relate(.width*.height)]
+    area: u32,
+    #[relate(_ as u64)]
+    width: u64,
+}
+impl ::core::convert::From<Source> for Target {
+    fn from(src: Source) -> Self {
+        let __with_name_len = src.name.len();
+        let __with_area = src.width * src.height;
+        let __with_width = src.width as u64;
+        Self {
+            name_len: __with_name_len,
+            name: src.name,
+            area: __with_area,
+            width: __with_width,
+        }
+    }
+}
+impl ::core::convert::From<&Source> for Target {
+    fn from(src: &Source) -> Self {
+        let __with_name_len = src.name.len();
+        let __with_area = src.width * src.height;
+        let __with_width = src.width as u64;
+        Self {
+            name_len: __with_name_len,
+            name: src.name.clone(),
+            area: __with_area,
+            width: __with_width,
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,24 @@
+//! Borrow-only `with` expressions expansion test - shows that calls and
+//! operators read their fields without cloning them, in both impls.
+
+use relate::Relate;
+
+struct Source {
+    name: String,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Relate)]
+#[relate(Source)]
+struct Target {
+    #[relate(.name.len())]
+    name_len: usize,
+    name: String,
+    #[relate(.width * .height)]
+    area: u32,
+    #[relate(_ as u64)]
+    width: u64,
+}
+
+fn main() {}
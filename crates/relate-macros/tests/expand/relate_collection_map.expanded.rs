@@ -15,7 +15,7 @@ struct Target {
 impl ::core::convert::From<Source> for Target {
     fn from(src: Source) -> Self {
         Self {
-            items: src.items.iter().map(|__item| __item.id).collect(),
+            items: src.items.into_iter().map(|__item| __item.id).collect(),
         }
     }
 }
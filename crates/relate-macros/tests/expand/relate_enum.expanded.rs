@@ -0,0 +1,47 @@
+//! Relate derive on an enum expansion test - shows the generated `match` arms.
+use relate::Relate;
+enum DbStatus {
+    Active,
+    Suspended(String),
+    Deleted { at: u64, reason: String },
+}
+#[relate(DbStatus)]
+enum Status {
+    Active,
+    #[relate(Suspended)]
+    Paused(String),
+    Deleted { at: u64, #[relate(_.to_uppercase())] reason: String },
+}
+impl ::core::convert::From<DbStatus> for Status {
+    fn from(src: DbStatus) -> Self {
+        match src {
+            DbStatus::Active => Self::Active,
+            DbStatus::Suspended { 0: __field_0, .. } => Self::Paused(__field_0),
+            DbStatus::Deleted { reason: __field_reason, at: __field_at, .. } => {
+                let __with_reason = __field_reason.to_uppercase();
+                Self::Deleted {
+                    at: __field_at,
+                    reason: __with_reason,
+                }
+            }
+        }
+    }
+}
+impl ::core::convert::From<&DbStatus> for Status {
+    fn from(src: &DbStatus) -> Self {
+        match src {
+            DbStatus::Active => Self::Active,
+            DbStatus::Suspended { 0: __field_0, .. } => {
+                Self::Paused((*__field_0).clone())
+            }
+            DbStatus::Deleted { reason: __field_reason, at: __field_at, .. } => {
+                let __with_reason = (*__field_reason).to_uppercase();
+                Self::Deleted {
+                    at: (*__field_at).clone(),
+                    reason: __with_reason,
+                }
+            }
+        }
+    }
+}
+fn main() {}
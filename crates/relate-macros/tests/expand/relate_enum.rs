@@ -0,0 +1,24 @@
+//! Relate derive on an enum expansion test - shows the generated `match` arms.
+
+use relate::Relate;
+
+enum DbStatus {
+    Active,
+    Suspended(String),
+    Deleted { at: u64, reason: String },
+}
+
+#[derive(Relate)]
+#[relate(DbStatus)]
+enum Status {
+    Active,
+    #[relate(Suspended)]
+    Paused(String),
+    Deleted {
+        at:     u64,
+        #[relate(_.to_uppercase())]
+        reason: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,66 @@
+//! `inline` expansion test - shows `#[inline]` on the generated `from`,
+//! `try_from` and `into_` function.
+use relate::{Relate, relate_structs};
+struct Pixel {
+    r: u8,
+    g: u8,
+}
+#[relate(Pixel, inline)]
+struct Rgb {
+    r: u8,
+    g: u8,
+}
+impl ::core::convert::From<Pixel> for Rgb {
+    #[inline]
+    fn from(src: Pixel) -> Self {
+        Self { r: src.r, g: src.g }
+    }
+}
+impl ::core::convert::From<&Pixel> for Rgb {
+    #[inline]
+    fn from(src: &Pixel) -> Self {
+        Self {
+            r: src.r.clone(),
+            g: src.g.clone(),
+        }
+    }
+}
+struct RawPort {
+    port: String,
+}
+#[relate(RawPort, inline)]
+struct Port {
+    #[relate(_.parse()?)]
+    port: u16,
+}
+impl ::core::convert::TryFrom<RawPort> for Port {
+    type Error = ::relate::ConversionError;
+    #[inline]
+    fn try_from(src: RawPort) -> ::core::result::Result<Self, Self::Error> {
+        let __with_port = (src.port.parse())?;
+        ::core::result::Result::Ok(Self { port: __with_port })
+    }
+}
+impl ::core::convert::TryFrom<&RawPort> for Port {
+    type Error = ::relate::ConversionError;
+    #[inline]
+    fn try_from(src: &RawPort) -> ::core::result::Result<Self, Self::Error> {
+        let __with_port = (src.port.parse())?;
+        ::core::result::Result::Ok(Self { port: __with_port })
+    }
+}
+mod other_crate {
+    pub struct Meters {
+        pub value: f64,
+    }
+}
+struct Distance {
+    value: f64,
+}
+#[inline]
+pub fn into_meters(src: Distance) -> other_crate::Meters {
+    other_crate::Meters {
+        value: src.value,
+    }
+}
+fn main() {}
@@ -0,0 +1,47 @@
+//! `inline` expansion test - shows `#[inline]` on the generated `from`,
+//! `try_from` and `into_` function.
+
+use relate::{Relate, relate_structs};
+
+struct Pixel {
+    r: u8,
+    g: u8,
+}
+
+#[derive(Relate)]
+#[relate(Pixel, inline)]
+struct Rgb {
+    r: u8,
+    g: u8,
+}
+
+struct RawPort {
+    port: String,
+}
+
+#[derive(Relate)]
+#[relate(RawPort, inline)]
+struct Port {
+    #[relate(_.parse()?)]
+    port: u16,
+}
+
+mod other_crate {
+    pub struct Meters {
+        pub value: f64,
+    }
+}
+
+struct Distance {
+    value: f64,
+}
+
+relate_structs! {
+    Distance ~> other_crate::Meters {
+        #![into_fn]
+        #![inline]
+        value;
+    }
+}
+
+fn main() {}
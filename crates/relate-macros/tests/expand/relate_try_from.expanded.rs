@@ -13,7 +13,7 @@ struct Target {
 impl ::core::convert::TryFrom<Source> for Target {
     type Error = ::relate::ConversionError;
     fn try_from(src: Source) -> ::core::result::Result<Self, Self::Error> {
-        let __with_port = src.port.parse()?;
+        let __with_port = (src.port.parse())?;
         ::core::result::Result::Ok(Self {
             port: __with_port,
             host: src.host,
@@ -23,7 +23,7 @@ impl ::core::convert::TryFrom<Source> for Target {
 impl ::core::convert::TryFrom<&Source> for Target {
     type Error = ::relate::ConversionError;
     fn try_from(src: &Source) -> ::core::result::Result<Self, Self::Error> {
-        let __with_port = src.port.parse()?;
+        let __with_port = (src.port.parse())?;
         ::core::result::Result::Ok(Self {
             port: __with_port,
             host: src.host.clone(),
@@ -32,7 +32,7 @@ impl ::core::convert::From<Source> for Target {
 }
 impl ::core::convert::From<&Source> for Target {
     fn from(src: &Source) -> Self {
-        let __with_id = src.id.unwrap_or(0);
+        let __with_id = src.id.clone().unwrap_or(0);
         let __with_value = if src.encrypted { None } else { Some(src.value.clone()) };
         Self {
             id: __with_id,
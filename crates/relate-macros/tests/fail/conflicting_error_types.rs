@@ -0,0 +1,28 @@
+//! Test for an error type given both in the direction and in the body.
+
+use relate::relate_structs;
+
+#[derive(Debug)]
+struct ErrorA;
+
+#[derive(Debug)]
+struct ErrorB;
+
+#[derive(Debug, Clone)]
+struct Source {
+    value: String,
+}
+
+#[derive(Debug, Clone)]
+struct Target {
+    value: String,
+}
+
+relate_structs! {
+    Source ~>?[ErrorA] Target {
+        error = ErrorB;
+        value;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+//! Test for `copy` on a field whose type isn't `Copy`.
+
+use relate::relate_structs;
+
+struct Source {
+    name: String,
+}
+
+struct Target {
+    name: String,
+}
+
+// Error: `String` is not `Copy`
+relate_structs! {
+    Source ~> Target {
+        name: copy;
+    }
+}
+
+fn main() {}
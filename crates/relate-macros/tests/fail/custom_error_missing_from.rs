@@ -0,0 +1,28 @@
+use relate::relate_structs;
+
+#[derive(Debug)]
+enum ConfigError {
+    Missing,
+}
+
+struct Source {
+    name: String,
+    port: String,
+}
+
+struct Target {
+    name: String,
+    port: u16,
+}
+
+// Error: `ConfigError` has no `From<ParseIntError>`, reported at `port`
+relate_structs! {
+    Source ~>?[ConfigError] Target {
+        name;
+        port: with = _.parse::<u16>()?;
+    }
+}
+
+fn main() {
+    let _ = ConfigError::Missing;
+}
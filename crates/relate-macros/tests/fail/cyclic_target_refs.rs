@@ -0,0 +1,24 @@
+//! Test for cyclic `@field` references.
+
+use relate::relate_structs;
+
+#[derive(Debug, Clone)]
+struct Source {
+    a: i32,
+}
+
+#[derive(Debug, Clone)]
+struct Target {
+    a: i32,
+    b: i32,
+}
+
+// `a` and `b` each need the other first
+relate_structs! {
+    Source ~> Target {
+        a: with = @b + 1;
+        b: with = @a + 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+//! Test for a field given both a `default` and a `with` expression.
+
+use relate::relate_structs;
+
+struct Source {
+    id: i32,
+}
+
+struct Target {
+    id: i32,
+}
+
+// Error: `id` can't be both defaulted and computed
+relate_structs! {
+    Source ~> Target {
+        id: default;
+        id: with = _ + 1;
+    }
+}
+
+fn main() {}
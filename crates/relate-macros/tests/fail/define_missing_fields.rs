@@ -0,0 +1,16 @@
+//! Test for an inline struct definition without any field list.
+
+use relate::relate_structs;
+
+#[derive(Debug, Clone)]
+struct Target {
+    id: i32,
+}
+
+// A defined side needs its own fields or a defined other side to share from
+relate_structs! {
+    #[derive(Debug, Clone)]
+    struct Source ~> Target { id; }
+}
+
+fn main() {}
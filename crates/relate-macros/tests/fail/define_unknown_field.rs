@@ -0,0 +1,20 @@
+//! Test for a mapping naming a field the defined target doesn't declare.
+
+use relate::relate_structs;
+
+relate_structs! {
+    #[derive(Debug, Clone)]
+    struct Signup {
+        name: String,
+    }
+    ~>
+    #[derive(Debug, Clone)]
+    struct Account {
+        name: String,
+    } {
+        name;
+        nickname: with = .name.clone();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+//! Test for a mapping reading a field the defined source doesn't declare.
+
+use relate::relate_structs;
+
+relate_structs! {
+    #[derive(Debug, Clone)]
+    struct Signup {
+        name: String,
+    }
+    ~>
+    #[derive(Debug, Clone)]
+    struct Account {
+        name:  String,
+        email: String,
+    } {
+        name;
+        email: with = .contact.to_lowercase();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! Test for a defined target field missing from the relation body.
+
+use relate::relate_structs;
+
+// Both structs are defined, so the unmapped `email` is reported on its declaration
+relate_structs! {
+    #[derive(Debug, Clone)]
+    struct Signup {
+        name:  String,
+        email: String,
+    }
+    ~>
+    #[derive(Debug, Clone)]
+    struct Account {
+        name:  String,
+        email: String,
+    } {
+        name;
+    }
+}
+
+fn main() {}
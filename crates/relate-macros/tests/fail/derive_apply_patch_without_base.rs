@@ -0,0 +1,16 @@
+//! Test for `apply_patch` without a `base = expr` to fall back to.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct UserPatch {
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(UserPatch, apply_patch)]
+struct User {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+//! Test for `collect_errors` combined with a custom error type.
+
+use relate::Relate;
+
+#[derive(Debug)]
+struct MyError;
+
+#[derive(Debug, Clone)]
+struct Form {
+    age: String,
+}
+
+// Collected errors are always `ConversionError::Multiple`
+#[derive(Debug, Relate)]
+#[relate(Form, collect_errors, error = MyError)]
+struct Person {
+    #[relate(_.parse()?)]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+//! Test for `[...]` collection syntax on a map-typed field.
+
+use std::collections::HashMap;
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Source {
+    prices: HashMap<String, u32>,
+}
+
+// `[...]` iterates `(key, value)` entries; `map[...]` should be used instead
+#[derive(Debug, Clone, Relate)]
+#[relate(Source)]
+struct Target {
+    #[relate([_.into()])]
+    prices: HashMap<String, u64>,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+//! Test for two different struct-level clone modes on one derive.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Source {
+    value: String,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(Source, cloned, move)]
+struct Target {
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! Test for an error type given both with `try_from = Type` and `error = Type`.
+
+use relate::Relate;
+
+#[derive(Debug)]
+struct ErrorA;
+
+#[derive(Debug)]
+struct ErrorB;
+
+#[derive(Debug, Clone)]
+struct Source {
+    value: String,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(Source, try_from = ErrorA, error = ErrorB)]
+struct Target {
+    value: String,
+}
+
+fn main() {}
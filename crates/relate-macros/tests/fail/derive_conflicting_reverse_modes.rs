@@ -0,0 +1,16 @@
+//! Test for two different reverse clone modes on one derive.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Source {
+    value: String,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(Source, both, reverse_cloned, reverse_move)]
+struct Target {
+    value: String,
+}
+
+fn main() {}
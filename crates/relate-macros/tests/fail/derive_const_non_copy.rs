@@ -0,0 +1,18 @@
+//! Test for `const` on a field that isn't read in `copy` mode.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct RawUser {
+    id:   u64,
+    name: String,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(RawUser, const)]
+struct User {
+    id:   u64,
+    name: String,
+}
+
+fn main() {}
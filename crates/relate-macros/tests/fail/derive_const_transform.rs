@@ -0,0 +1,17 @@
+//! Test for `const` on a field with a transform.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, Copy)]
+struct RawCounter {
+    count: u32,
+}
+
+#[derive(Debug, Clone, Copy, Relate)]
+#[relate(RawCounter, copy, const)]
+struct Counter {
+    #[relate(into)]
+    count: u64,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Test for `context` on a conversion that can't fail.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Source {
+    name: String,
+}
+
+// Nothing to label: the conversion is a plain `From`
+#[derive(Debug, Relate)]
+#[relate(Source, context = "converting Source")]
+struct Target {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use relate::Relate;
+
+#[derive(Debug)]
+enum ConfigError {
+    Missing,
+}
+
+struct RawConfig {
+    port: String,
+}
+
+// Error: `ConfigError` has no `From<ParseIntError>`, reported at `port`
+#[derive(Relate)]
+#[relate(RawConfig, error = ConfigError)]
+struct Config {
+    #[relate(_.parse::<u16>()?)]
+    port: u16,
+}
+
+fn main() {
+    let _ = ConfigError::Missing;
+}
@@ -0,0 +1,17 @@
+//! Test that `debug_expand` reports the generated code as a rustc warning.
+
+#![deny(deprecated)]
+
+use relate::Relate;
+
+struct Point {
+    x: i32,
+}
+
+#[derive(Relate)]
+#[relate(Point, debug_expand)]
+struct PointDto {
+    x: i32,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+//! Test for `delimiter` on a source that isn't `&str`.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct RawRow {
+    line: String,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(RawRow, delimiter = ",")]
+struct Row {
+    line: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+//! Test for a discriminant that doesn't fit the integer type.
+
+use relate::Relate;
+
+// `300 as u8` would silently become 44
+#[derive(Debug, Clone, Copy, PartialEq, Relate)]
+#[relate(u8, both)]
+#[repr(u16)]
+enum Code {
+    A = 1,
+    B = 300,
+}
+
+fn main() {}
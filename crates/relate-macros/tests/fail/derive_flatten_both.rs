@@ -0,0 +1,22 @@
+//! Test for `flatten` combined with `both`.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Meta {
+    created_at: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Document {
+    meta: Meta,
+}
+
+// `Meta` can't be rebuilt from the flattened field
+#[derive(Debug, Clone, Relate)]
+#[relate(Document, both, flatten(meta: created_at))]
+struct DocumentRow {
+    created_at: u64,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+//! Test for a `strip_prefix` that doesn't form valid field names.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct DbUser {
+    id: i64,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(DbUser, strip_prefix = "db-")]
+struct User {
+    id: i64,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Test for `method = name` combined with `both`.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct User {
+    id: u32,
+}
+
+// The reverse direction can't be an inherent method on the derived type
+#[derive(Debug, Clone, Relate)]
+#[relate(User, method = from_user, both)]
+struct UserDto {
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+//! Test for `no_clone` with a source field read twice.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Source {
+    name: String,
+}
+
+// `display` reads `name` too, so the owned impl would clone it
+#[derive(Debug, Relate)]
+#[relate(Source, no_clone)]
+struct Target {
+    #[relate(.name)]
+    display: String,
+    name:    String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Test for `ref_only` combined with `move`.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Source {
+    name: String,
+}
+
+// `move` only affects the owned impl, which isn't generated
+#[derive(Debug, Relate)]
+#[relate(Source, move, ref_only)]
+struct Target {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Test for `roundtrip_check` without `both`.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    id: u32,
+}
+
+// There is no conversion back to `User` to complete the roundtrip
+#[derive(Debug, Clone, Relate)]
+#[relate(User, roundtrip_check)]
+struct UserDto {
+    id: u32,
+}
+
+fn main() {}
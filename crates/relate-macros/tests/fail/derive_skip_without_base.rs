@@ -0,0 +1,20 @@
+//! Test for `skip` without a `base = expr` to fill the field.
+
+use relate::Relate;
+
+struct Handle(u32);
+
+struct Source {
+    name: String,
+}
+
+// `Handle` has no `Default`, and nothing else fills the skipped field
+#[derive(Relate)]
+#[relate(Source)]
+struct Session {
+    name:   String,
+    #[relate(skip)]
+    handle: Handle,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+//! Test for `try_into_self` on an infallible conversion.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct RawUser {
+    id: u64,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(RawUser, try_into_self)]
+struct User {
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+//! Test for `from = Source` naming a type that isn't a source of the derive.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct DbUser {
+    id: i64,
+}
+
+#[derive(Debug, Clone)]
+struct ApiUser {
+    id: i64,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(DbUser)]
+struct UserDto {
+    // `ApiUser` has no #[relate(ApiUser)] on the struct
+    #[relate(from = ApiUser, default)]
+    id: i64,
+}
+
+fn main() {}
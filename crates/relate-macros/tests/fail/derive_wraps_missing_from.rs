@@ -0,0 +1,27 @@
+//! Test for `wraps(..)` listing an error type the custom error can't be built from.
+
+use std::num::{ParseFloatError, ParseIntError};
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct RawReading {
+    count: String,
+}
+
+#[derive(Debug)]
+struct ReadingError;
+
+impl From<ParseIntError> for ReadingError {
+    fn from(_: ParseIntError) -> Self { Self }
+}
+
+// `ReadingError` has no `From<ParseFloatError>`
+#[derive(Debug, Clone, Relate)]
+#[relate(RawReading, error = ReadingError, wraps(ParseIntError, ParseFloatError))]
+struct Reading {
+    #[relate(_.parse()?)]
+    count: u32,
+}
+
+fn main() {}
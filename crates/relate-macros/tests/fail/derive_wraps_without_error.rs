@@ -0,0 +1,17 @@
+//! Test for `wraps(..)` without a custom error type.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct RawReading {
+    count: String,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(RawReading, wraps(std::num::ParseIntError))]
+struct Reading {
+    #[relate(_.parse()?)]
+    count: u32,
+}
+
+fn main() {}
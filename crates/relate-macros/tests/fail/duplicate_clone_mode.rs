@@ -0,0 +1,19 @@
+//! Test that a repeated clone mode points at the second mode.
+
+use relate::relate_structs;
+
+struct Source {
+    name: String,
+}
+
+struct Target {
+    name: String,
+}
+
+relate_structs! {
+    Source ~> Target {
+        name: with = _.trim().to_string(), cloned, copy;
+    }
+}
+
+fn main() {}
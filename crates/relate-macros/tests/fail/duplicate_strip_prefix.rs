@@ -0,0 +1,26 @@
+//! Should fail: a second `strip_prefix` would silently replace the first.
+
+use relate::relate_structs;
+
+#[derive(Debug, Clone)]
+struct Row {
+    db_id:   u64,
+    db_name: String,
+}
+
+#[derive(Debug, Clone)]
+struct User {
+    id:   u64,
+    name: String,
+}
+
+relate_structs! {
+    Row ~> User {
+        strip_prefix = "db_";
+        strip_prefix = "row_";
+        id;
+        name;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! Test for `map_err` on a field whose transform can't fail.
+
+use relate::relate_structs;
+
+#[derive(Debug, Clone)]
+struct Source {
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+struct Target {
+    name: String,
+}
+
+// There is no `?` for the error mapping to apply to
+relate_structs! {
+    Source ~>? Target {
+        name: with = _.to_uppercase(), map_err = |e| e;
+    }
+}
+
+fn main() {}
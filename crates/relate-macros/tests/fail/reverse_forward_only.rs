@@ -0,0 +1,22 @@
+//! Test for `reverse = expr` on a forward-only relation.
+
+use relate::relate_structs;
+
+#[derive(Debug, Clone)]
+struct Source {
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+struct Target {
+    name: String,
+}
+
+// There is no backward impl to use the reverse expression
+relate_structs! {
+    Source ~> Target {
+        name: with = _.to_uppercase(), reverse = _.to_lowercase();
+    }
+}
+
+fn main() {}
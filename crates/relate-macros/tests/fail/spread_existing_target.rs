@@ -0,0 +1,25 @@
+//! Test for `..` spread when the target struct is not defined in the macro.
+
+use relate::relate_structs;
+
+#[derive(Debug, Clone)]
+struct Source {
+    id:   i32,
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+struct Target {
+    id:   String,
+    name: String,
+}
+
+// The macro can't see Target's fields, so `..` can't fill them in
+relate_structs! {
+    Source ~> Target {
+        id: with = _.to_string();
+        ..
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//! Test for an `@field` reference to a field the target doesn't have.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Source {
+    first: String,
+}
+
+#[derive(Debug, Clone, Relate)]
+#[relate(Source)]
+struct Target {
+    first: String,
+    #[relate(with = @frist.len())]
+    len:   usize,
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+//! Tests for `allow_missing`, which defaults fields without an attribute.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Upload {
+    name: String,
+    size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Upload, allow_missing)]
+struct StoredFile {
+    #[relate]
+    name:     String,
+    #[relate(.size / 1024)]
+    size_kb:  u64,
+    checksum: Option<String>,
+    tags:     Vec<String>,
+    #[relate(default = 1)]
+    version:  u32,
+}
+
+#[test]
+fn test_allow_missing_defaults_unlisted_fields() {
+    let upload = Upload {
+        name: "report.pdf".to_string(),
+        size: 4096,
+    };
+    let expected = StoredFile {
+        name:     "report.pdf".to_string(),
+        size_kb:  4,
+        checksum: None,
+        tags:     Vec::new(),
+        version:  1,
+    };
+
+    assert_eq!(StoredFile::from(&upload), expected);
+    assert_eq!(StoredFile::from(upload), expected);
+}
@@ -0,0 +1,73 @@
+//! Tests for `also_arc`, which adds `Target::from_arc` for shared sources.
+
+use std::sync::Arc;
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct DbInner {
+    id:   u32,
+    name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbInner, also_arc)]
+struct Inner {
+    id:   u32,
+    name: String,
+}
+
+fn db_inner() -> DbInner {
+    DbInner {
+        id:   1,
+        name: "shared".to_string(),
+    }
+}
+
+#[test]
+fn test_from_arc_unique() {
+    let inner: Arc<Inner> = Inner::from_arc(Arc::new(db_inner()));
+    assert_eq!(inner.name, "shared");
+}
+
+#[test]
+fn test_from_arc_shared() {
+    let source = Arc::new(db_inner());
+    let inner = Inner::from_arc(Arc::clone(&source));
+
+    assert_eq!(
+        *inner,
+        Inner {
+            id:   1,
+            name: "shared".to_string(),
+        }
+    );
+    // The other handle is untouched
+    assert_eq!(source.name, "shared");
+}
+
+#[derive(Debug, Clone)]
+struct RawLimit {
+    max: String,
+}
+
+#[derive(Debug, Relate)]
+#[relate(RawLimit, also_arc, ref_only)]
+struct Limit {
+    #[relate(_.parse()?)]
+    max: u32,
+}
+
+#[test]
+fn test_from_arc_fallible() {
+    let limit = Limit::from_arc(Arc::new(RawLimit {
+        max: "10".to_string(),
+    }))
+    .unwrap();
+    assert_eq!(limit.max, 10);
+
+    let bad = Arc::new(RawLimit {
+        max: "ten".to_string(),
+    });
+    assert!(Limit::from_arc(bad).is_err());
+}
@@ -0,0 +1,82 @@
+//! Tests for `also_option`, which adds `Target::from_option` for optional
+//! sources.
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone)]
+struct DbAddress {
+    city: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbAddress, also_option)]
+struct Address {
+    city: String,
+}
+
+#[derive(Debug, Clone)]
+struct DbCustomer {
+    name:    String,
+    address: Option<DbAddress>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbCustomer)]
+struct Customer {
+    name:    String,
+    #[relate(with = Address::from_option(.address.clone()))]
+    address: Option<Address>,
+}
+
+#[test]
+fn test_from_option() {
+    let address = Address::from_option(Some(DbAddress {
+        city: "Oslo".to_string(),
+    }));
+    assert_eq!(
+        address,
+        Some(Address {
+            city: "Oslo".to_string(),
+        })
+    );
+    assert_eq!(Address::from_option(None), None);
+}
+
+#[test]
+fn test_from_option_nested() {
+    let customer: Customer = DbCustomer {
+        name:    "ada".to_string(),
+        address: None,
+    }
+    .into();
+    assert_eq!(customer.address, None);
+}
+
+#[derive(Debug, Clone)]
+struct RawLimit {
+    max: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(RawLimit, ref_only, also_option)]
+struct Limit {
+    #[relate(_.parse()?, map_err = ConversionError::other)]
+    max: u32,
+}
+
+#[test]
+fn test_from_option_fallible_by_ref() {
+    let raw = RawLimit {
+        max: "10".to_string(),
+    };
+    assert_eq!(
+        Limit::from_option(Some(&raw)).unwrap(),
+        Some(Limit { max: 10 })
+    );
+    assert_eq!(Limit::from_option(None).unwrap(), None);
+
+    let bad = RawLimit {
+        max: "ten".to_string(),
+    };
+    assert!(Limit::from_option(Some(&bad)).is_err());
+}
@@ -0,0 +1,102 @@
+//! Tests for `apply_patch`: filling unset patch fields from a base value.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, Default)]
+struct UserPatch {
+    name:   Option<String>,
+    email:  Option<String>,
+    age:    Option<u32>,
+    active: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(UserPatch, apply_patch, base = User::current())]
+struct User {
+    name:   String,
+    email:  String,
+    age:    u32,
+    active: bool,
+}
+
+impl User {
+    fn current() -> Self {
+        Self {
+            name:   "alice".to_string(),
+            email:  "alice@example.com".to_string(),
+            age:    30,
+            active: true,
+        }
+    }
+}
+
+#[test]
+fn test_apply_patch_keeps_unset_fields() {
+    let patch = UserPatch {
+        email: Some("alice@example.org".to_string()),
+        active: Some(false),
+        ..UserPatch::default()
+    };
+    let user = User::from(&patch);
+    assert_eq!(
+        user,
+        User {
+            email: "alice@example.org".to_string(),
+            active: false,
+            ..User::current()
+        }
+    );
+    assert_eq!(User::from(patch), user);
+}
+
+#[test]
+fn test_apply_patch_empty_patch_is_base() {
+    assert_eq!(User::from(UserPatch::default()), User::current());
+}
+
+/// Test renamed fields, fields with their own transform, and `skip` fields
+mod mixed_fields {
+    use super::*;
+
+    #[derive(Debug, Clone, Default)]
+    struct SettingsPatch {
+        theme:     Option<String>,
+        font_size: Option<u8>,
+        label:     Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default, Relate)]
+    #[relate(
+        SettingsPatch,
+        apply_patch,
+        base = Settings::default(),
+        rename_fields(label = title)
+    )]
+    struct Settings {
+        theme:     String,
+        #[relate(.font_size.map_or(12, u32::from))]
+        font_size: u32,
+        title:     String,
+        #[relate(skip)]
+        revision:  u64,
+    }
+
+    #[test]
+    fn test_apply_patch_mixed_fields() {
+        let patch = SettingsPatch {
+            theme: Some("dark".to_string()),
+            label: Some("Editor".to_string()),
+            ..SettingsPatch::default()
+        };
+        let settings = Settings::from(&patch);
+        assert_eq!(
+            settings,
+            Settings {
+                theme: "dark".to_string(),
+                font_size: 12,
+                title: "Editor".to_string(),
+                ..Settings::default()
+            }
+        );
+    }
+}
@@ -106,3 +106,52 @@ fn test_barcodes_bidirectional() {
     let back: Barcodes = db.into();
     assert_eq!(barcodes, back);
 }
+
+// Test `roundtrip_check` generating `__relate_roundtrip`
+mod roundtrip_check {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Account {
+        id:    u32,
+        email: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Account, both, roundtrip_check)]
+    struct AccountRow {
+        id:    u32,
+        email: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Profile {
+        name: String,
+    }
+
+    // Trimming loses whitespace, which isn't restored on the way back
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Profile, both, ref_only, roundtrip_check)]
+    struct ProfileCard {
+        #[relate(_.trim().to_string())]
+        name: String,
+    }
+
+    #[test]
+    fn test_symmetric_roundtrip() {
+        assert!(AccountRow::__relate_roundtrip(Account {
+            id:    1,
+            email: "a@b.c".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_asymmetric_roundtrip() {
+        assert!(ProfileCard::__relate_roundtrip(Profile {
+            name: "ada".to_string(),
+        }));
+        assert!(!ProfileCard::__relate_roundtrip(Profile {
+            name: " ada ".to_string(),
+        }));
+    }
+}
@@ -76,6 +76,51 @@ mod struct_level_move {
     }
 }
 
+mod struct_level_move_multi_use {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Source {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    // Method calls borrow `name`; `tags` moves on its last use only
+    #[derive(Debug, PartialEq, Relate)]
+    #[relate(Source, move)]
+    struct Target {
+        name:      String,
+        #[relate(.name.len())]
+        len:       usize,
+        #[relate(.name.to_uppercase())]
+        upper:     String,
+        tags:      Vec<String>,
+        #[relate(.tags)]
+        tags_copy: Vec<String>,
+    }
+
+    #[test]
+    fn test_move_multi_use() {
+        let source = Source {
+            name: "ada".to_string(),
+            tags: vec!["x".to_string()],
+        };
+
+        let target: Target = source.into();
+
+        assert_eq!(
+            target,
+            Target {
+                name:      "ada".to_string(),
+                len:       3,
+                upper:     "ADA".to_string(),
+                tags:      vec!["x".to_string()],
+                tags_copy: vec!["x".to_string()],
+            }
+        );
+    }
+}
+
 // =============================================================================
 // Field-Level Clone Mode Overrides
 // =============================================================================
@@ -307,6 +352,92 @@ mod bidirectional_clone_modes {
     }
 }
 
+// =============================================================================
+// Reverse Clone Modes: `reverse_cloned`, `reverse_copy`, `reverse_move`
+// =============================================================================
+
+mod reverse_clone_modes {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq)]
+    struct Counted(u32);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Self(self.0)
+        }
+    }
+
+    // Copy, but cloning it explicitly panics
+    #[derive(Debug, PartialEq, Copy)]
+    struct Flag(bool);
+
+    #[allow(clippy::non_canonical_clone_impl)]
+    impl Clone for Flag {
+        fn clone(&self) -> Self { panic!("`Flag` is copied, not cloned") }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Row {
+        count: Counted,
+    }
+
+    #[derive(Debug, PartialEq, Relate)]
+    #[relate(Row, both, move, reverse_cloned)]
+    struct Model {
+        count: Counted,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct FlagRow {
+        flag: Flag,
+    }
+
+    #[derive(Debug, PartialEq, Relate)]
+    #[relate(FlagRow, both, reverse_copy)]
+    struct FlagModel {
+        flag: Flag,
+    }
+
+    #[derive(Debug, PartialEq, Relate)]
+    #[relate(Row, both, reverse_move)]
+    struct MovedModel {
+        count: Counted,
+    }
+
+    #[test]
+    fn test_reverse_cloned() {
+        let model: Model = Row { count: Counted(1) }.into();
+        let before = CLONES.load(Ordering::SeqCst);
+        let row: Row = model.into();
+        assert_eq!(row, Row { count: Counted(1) });
+        assert!(CLONES.load(Ordering::SeqCst) > before);
+    }
+
+    #[test]
+    fn test_reverse_copy() {
+        let model = FlagModel { flag: Flag(true) };
+        let row = FlagRow::from(&model);
+        assert_eq!(row, FlagRow { flag: Flag(true) });
+        assert_eq!(FlagModel::from(row), model);
+    }
+
+    #[test]
+    fn test_reverse_move() {
+        let row = Row::from(MovedModel { count: Counted(2) });
+        assert_eq!(row, Row { count: Counted(2) });
+        assert_eq!(
+            Row::from(&MovedModel { count: Counted(3) }).count,
+            Counted(3)
+        );
+    }
+}
+
 // =============================================================================
 // Clone Mode Combined with All Options
 // =============================================================================
@@ -391,6 +522,141 @@ mod move_with_copy_types {
     }
 }
 
+// =============================================================================
+// No Implicit Clones
+// =============================================================================
+
+mod no_clone {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    // `name` is read twice, but the `with` call produces a fresh value and
+    // the explicit `cloned` is allowed
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Source, no_clone)]
+    struct Target {
+        #[relate(with = .name.to_uppercase())]
+        upper: String,
+        name:  String,
+        #[relate(cloned)]
+        tags:  Vec<String>,
+    }
+
+    #[test]
+    fn test_no_clone() {
+        let source = Source {
+            name: "ada".to_string(),
+            tags: vec!["x".to_string()],
+        };
+
+        let from_ref: Target = (&source).into();
+        let owned: Target = source.into();
+
+        assert_eq!(owned.upper, "ADA");
+        assert_eq!(owned.name, "ada");
+        assert_eq!(owned, from_ref);
+    }
+}
+
+// =============================================================================
+// `cloned` With Expressions Reading Several Fields
+// =============================================================================
+
+mod cloned_multi_field_with {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct FullName {
+        first: String,
+        last:  String,
+    }
+
+    impl FullName {
+        fn new(first: String, last: String) -> Self { Self { first, last } }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Person {
+        first: String,
+        last:  String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Person)]
+    struct Contact {
+        #[relate(with = FullName::new(.first, .last), cloned)]
+        name:    FullName,
+        #[relate(with = vec![.first, .last], cloned)]
+        aliases: Vec<String>,
+        #[relate(with = format!("{} {}", .first, .last), cloned)]
+        label:   String,
+    }
+
+    #[test]
+    fn test_cloned_reads_each_field() {
+        let person = Person {
+            first: "Grace".to_string(),
+            last:  "Hopper".to_string(),
+        };
+        let expected = Contact {
+            name:    FullName::new("Grace".to_string(), "Hopper".to_string()),
+            aliases: vec!["Grace".to_string(), "Hopper".to_string()],
+            label:   "Grace Hopper".to_string(),
+        };
+
+        assert_eq!(Contact::from(&person), expected);
+        assert_eq!(Contact::from(person), expected);
+    }
+}
+
+// =============================================================================
+// Arc::clone
+// =============================================================================
+
+mod arc_clone {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        config: Arc<String>,
+        id:     u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Source)]
+    struct Target {
+        #[relate(arc_clone)]
+        config: Arc<String>,
+        #[relate(.config, arc_clone)]
+        shared: Arc<String>,
+        id:     u32,
+    }
+
+    #[test]
+    fn test_arc_clone() {
+        let source = Source {
+            config: Arc::new("prod".to_string()),
+            id:     7,
+        };
+
+        let from_ref: Target = (&source).into();
+        assert!(Arc::ptr_eq(&from_ref.config, &source.config));
+        assert_eq!(Arc::strong_count(&source.config), 3);
+
+        let config = Arc::clone(&source.config);
+        let owned: Target = source.into();
+        assert!(Arc::ptr_eq(&owned.shared, &config));
+        assert_eq!(owned, from_ref);
+    }
+}
+
 // =============================================================================
 // Edge Case: Empty Struct
 // =============================================================================
@@ -0,0 +1,59 @@
+//! Tests for closure transforms: `#[relate(|x| expr)]`.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Input {
+    name:   String,
+    email:  String,
+    scores: Vec<u32>,
+    count:  u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Input)]
+struct Output {
+    // Untyped: takes the field by value (cloned in the ref impl)
+    #[relate(|x| x.trim().to_string())]
+    name:   String,
+    // Takes a reference in both impls
+    #[relate(|x: &str| x.to_lowercase())]
+    email:  String,
+    #[relate(|&x| x * 2, copy)]
+    count:  u32,
+    #[relate(move |scores: Vec<u32>| scores.into_iter().max().unwrap_or(0))]
+    scores: u32,
+}
+
+fn input() -> Input {
+    Input {
+        name:   "  ada ".to_string(),
+        email:  "ADA@EXAMPLE.COM".to_string(),
+        scores: vec![3, 9, 4],
+        count:  5,
+    }
+}
+
+#[test]
+fn test_closures() {
+    let output: Output = input().into();
+    assert_eq!(
+        output,
+        Output {
+            name:   "ada".to_string(),
+            email:  "ada@example.com".to_string(),
+            count:  10,
+            scores: 9,
+        }
+    );
+}
+
+#[test]
+fn test_closures_from_ref() {
+    let source = input();
+    let output: Output = (&source).into();
+    assert_eq!(output.name, "ada");
+    assert_eq!(output.scores, 9);
+    // Original still usable
+    assert_eq!(source.scores.len(), 3);
+}
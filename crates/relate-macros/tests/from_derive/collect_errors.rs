@@ -0,0 +1,81 @@
+//! Tests for `#[relate(Source, collect_errors)]` reporting every failed field.
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone)]
+struct Form {
+    age:    String,
+    height: String,
+    name:   String,
+    email:  Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Form, collect_errors)]
+struct Person {
+    #[relate(_.parse()?)]
+    age:    u8,
+    #[relate(_.parse()?)]
+    height: f32,
+    name:   String,
+    #[relate(required)]
+    email:  String,
+}
+
+fn form(age: &str, height: &str, email: Option<&str>) -> Form {
+    Form {
+        age:    age.to_string(),
+        height: height.to_string(),
+        name:   "Ada".to_string(),
+        email:  email.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_all_fields_valid() {
+    let result: Result<Person, ConversionError> = form("36", "1.7", Some("a@b.c")).try_into();
+    assert_eq!(
+        result.unwrap(),
+        Person {
+            age:    36,
+            height: 1.7,
+            name:   "Ada".to_string(),
+            email:  "a@b.c".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_single_error_is_collected() {
+    let result: Result<Person, ConversionError> = form("old", "1.7", Some("a@b.c")).try_into();
+    let Err(ConversionError::Multiple(errors)) = result else {
+        panic!("expected collected errors");
+    };
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], ConversionError::ParseInt(_)));
+}
+
+#[test]
+fn test_all_errors_are_collected() {
+    let source = form("old", "tall", None);
+    let result: Result<Person, ConversionError> = (&source).try_into();
+    let Err(ConversionError::Multiple(errors)) = result else {
+        panic!("expected collected errors");
+    };
+
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(errors[0], ConversionError::ParseInt(_)));
+    assert!(matches!(errors[1], ConversionError::ParseFloat(_)));
+    assert!(matches!(errors[2], ConversionError::MissingField("email")));
+    // Original still usable
+    assert_eq!(source.name, "Ada");
+}
+
+#[test]
+fn test_multiple_display() {
+    let result: Result<Person, ConversionError> = form("36", "1.7", None).try_into();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "1 fields failed to convert: missing required field: email"
+    );
+}
@@ -0,0 +1,121 @@
+//! Tests for collection maps that change the container type.
+//!
+//! The generated `.collect()` infers the container from the target field, so
+//! any target implementing `FromIterator` of the mapped element works.
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Item {
+    id:    u32,
+    #[allow(dead_code)]
+    label: String,
+}
+
+#[derive(Debug, Clone)]
+struct Order {
+    items: Vec<Item>,
+}
+
+fn order() -> Order {
+    Order {
+        items: vec![
+            Item {
+                id:    3,
+                label: "c".to_string(),
+            },
+            Item {
+                id:    1,
+                label: "a".to_string(),
+            },
+            Item {
+                id:    3,
+                label: "c again".to_string(),
+            },
+        ],
+    }
+}
+
+// =============================================================================
+// Vec -> VecDeque
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Order)]
+struct QueuedOrder {
+    #[relate([_.id])]
+    items: VecDeque<u32>,
+}
+
+#[test]
+fn test_vec_to_vec_deque() {
+    let queued: QueuedOrder = order().into();
+    assert_eq!(queued.items, VecDeque::from([3, 1, 3]));
+}
+
+// =============================================================================
+// Vec -> BTreeSet
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Order)]
+struct SortedOrder {
+    #[relate([_.id])]
+    items: BTreeSet<u32>,
+}
+
+#[test]
+fn test_vec_to_btree_set() {
+    let sorted: SortedOrder = (&order()).into();
+    assert_eq!(sorted.items.into_iter().collect::<Vec<_>>(), vec![1, 3]);
+}
+
+// =============================================================================
+// Vec -> HashSet
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Order)]
+struct UniqueOrder {
+    #[relate([_.id])]
+    items: HashSet<u32>,
+}
+
+#[test]
+fn test_vec_to_hash_set() {
+    let unique: UniqueOrder = order().into();
+    assert_eq!(unique.items, HashSet::from([1, 3]));
+}
+
+#[test]
+fn test_empty_container_change() {
+    let unique: UniqueOrder = Order { items: vec![] }.into();
+    assert!(unique.items.is_empty());
+}
+
+// =============================================================================
+// Container change with a fallible element transform
+// =============================================================================
+
+#[derive(Debug, Clone)]
+struct RawTags {
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(RawTags)]
+struct Tags {
+    #[relate([_.parse()?])]
+    tags: BTreeSet<u8>,
+}
+
+#[test]
+fn test_fallible_container_change() {
+    let raw = RawTags {
+        tags: vec!["2".to_string(), "1".to_string(), "2".to_string()],
+    };
+    let tags: Tags = raw.try_into().expect("should parse");
+    assert_eq!(tags.tags, BTreeSet::from([1, 2]));
+}
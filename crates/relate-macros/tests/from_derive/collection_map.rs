@@ -110,3 +110,187 @@ fn test_collection_map_method_chain() {
 
     assert_eq!(result.items, vec![1, 3, 5]);
 }
+
+// Bare `[_]` converts each item with Into, without `cloned`
+#[derive(Debug, Clone)]
+struct DbTag {
+    label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbTag)]
+struct Tag {
+    label: String,
+}
+
+#[derive(Debug, Clone)]
+struct DbPost {
+    tags: Vec<DbTag>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbPost)]
+struct Post {
+    #[relate([_])]
+    tags: Vec<Tag>,
+}
+
+#[test]
+fn test_collection_map_into_items() {
+    let post = DbPost {
+        tags: vec![DbTag {
+            label: "rust".to_string(),
+        }],
+    };
+
+    let from_ref: Post = (&post).into();
+    let owned: Post = post.into();
+
+    assert_eq!(
+        from_ref.tags,
+        vec![Tag {
+            label: "rust".to_string(),
+        }]
+    );
+    assert_eq!(owned, from_ref);
+}
+
+// `[idx, _ => expr]` binds each element's position
+#[derive(Debug, Clone)]
+struct Playlist {
+    songs: Vec<Item>,
+    plays: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Track {
+    order: usize,
+    name:  String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Playlist)]
+struct NumberedPlaylist {
+    #[relate([idx, _ => Track { order: idx + 1, name: _.name.clone() }])]
+    songs: Vec<Track>,
+    #[relate([i, _ => _.parse::<u32>().map(|n| (i, n))?])]
+    plays: Vec<(usize, u32)>,
+}
+
+#[test]
+fn test_collection_map_enumerated() {
+    let playlist = Playlist {
+        songs: vec![
+            Item {
+                name: "intro".to_string(),
+            },
+            Item {
+                name: "outro".to_string(),
+            },
+        ],
+        plays: vec!["3".to_string(), "10".to_string()],
+    };
+
+    let numbered: NumberedPlaylist = (&playlist).try_into().unwrap();
+    assert_eq!(
+        numbered.songs,
+        vec![
+            Track {
+                order: 1,
+                name:  "intro".to_string(),
+            },
+            Track {
+                order: 2,
+                name:  "outro".to_string(),
+            },
+        ]
+    );
+    assert_eq!(numbered.plays, vec![(0, 3), (1, 10)]);
+
+    let playlist = Playlist {
+        plays: vec!["x".to_string()],
+        ..playlist
+    };
+    assert!(NumberedPlaylist::try_from(playlist).is_err());
+}
+
+// `[_.key, _.value]` collects key-value pairs into a map
+#[derive(Debug, Clone)]
+struct Inventory {
+    items: Vec<Item>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Inventory)]
+struct InventoryIndex {
+    #[relate([_.name.clone(), _.name.len()])]
+    items: std::collections::HashMap<String, usize>,
+}
+
+#[test]
+fn test_collection_map_key_value_pairs() {
+    let inventory = Inventory {
+        items: vec![
+            Item {
+                name: "bolt".to_string(),
+            },
+            Item {
+                name: "washer".to_string(),
+            },
+        ],
+    };
+
+    let index: InventoryIndex = (&inventory).into();
+    assert_eq!(index.items["bolt"], 4);
+    assert_eq!(index.items["washer"], 6);
+    assert_eq!(InventoryIndex::from(inventory), index);
+}
+
+// Test `opt[..]` mapping over a nullable collection column
+mod optional_collection {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct OrderRow {
+        line_ids: Option<Vec<u32>>,
+        notes:    Option<Vec<String>>,
+    }
+
+    #[derive(Debug, PartialEq, Relate)]
+    #[relate(OrderRow)]
+    struct Order {
+        #[relate(opt[_.to_string()])]
+        line_ids: Option<Vec<String>>,
+        #[relate(opt[_])]
+        notes:    Option<Vec<String>>,
+    }
+
+    #[test]
+    fn test_option_collection_some() {
+        let row = OrderRow {
+            line_ids: Some(vec![1, 2]),
+            notes:    Some(vec!["fragile".to_string()]),
+        };
+        let expected = Order {
+            line_ids: Some(vec!["1".to_string(), "2".to_string()]),
+            notes:    Some(vec!["fragile".to_string()]),
+        };
+        assert_eq!(Order::from(&row), expected);
+        assert_eq!(Order::from(row), expected);
+    }
+
+    #[test]
+    fn test_option_collection_none() {
+        let row = OrderRow {
+            line_ids: None,
+            notes:    None,
+        };
+        assert_eq!(
+            Order::from(row),
+            Order {
+                line_ids: None,
+                notes:    None,
+            }
+        );
+    }
+}
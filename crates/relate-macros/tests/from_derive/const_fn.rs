@@ -0,0 +1,74 @@
+//! Tests for `const` conversions between `Copy` structs.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RawPoint {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Relate)]
+#[relate(RawPoint, copy, const, both)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+const ORIGIN: Point = Point::const_from(RawPoint { x: 0, y: 0 });
+
+#[test]
+fn test_const_from_in_const_context() {
+    assert_eq!(ORIGIN, Point { x: 0, y: 0 });
+}
+
+#[test]
+fn test_const_keeps_from_impls() {
+    let raw = RawPoint { x: 3, y: 4 };
+    assert_eq!(Point::from(raw), Point::const_from(raw));
+    assert_eq!(
+        RawPoint::from(Point { x: 1, y: 2 }),
+        RawPoint { x: 1, y: 2 }
+    );
+}
+
+// Renamed fields and tuple structs copy the same way
+#[derive(Debug, Clone, Copy, PartialEq, Relate)]
+#[relate(RawPoint, copy, const)]
+struct Offset(#[relate(.x)] i32, #[relate(.y)] i32);
+
+const STEP: Offset = Offset::const_from(RawPoint { x: 1, y: -1 });
+
+#[test]
+fn test_const_tuple_struct() {
+    assert_eq!(STEP, Offset(1, -1));
+}
+
+// A field-level `copy` qualifies without the struct-level mode
+#[derive(Debug, Clone, Copy, PartialEq, Relate)]
+#[relate(RawPoint, const)]
+struct Column {
+    #[relate(.x, copy)]
+    index: i32,
+}
+
+#[test]
+fn test_const_field_copy() {
+    const FIRST: Column = Column::const_from(RawPoint { x: 5, y: 0 });
+    assert_eq!(FIRST.index, 5);
+}
+
+// With `method`, the generated method itself is `const`
+#[derive(Debug, Clone, Copy, PartialEq, Relate)]
+#[relate(RawPoint, copy, const, method = to_size)]
+struct Size {
+    x: i32,
+    y: i32,
+}
+
+const UNIT: Size = Size::to_size(&RawPoint { x: 1, y: 1 });
+
+#[test]
+fn test_const_method() {
+    assert_eq!(UNIT, Size { x: 1, y: 1 });
+}
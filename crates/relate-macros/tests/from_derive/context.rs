@@ -0,0 +1,78 @@
+//! Tests for `context = "..."` labelling `TryFrom` errors.
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone)]
+struct RawConfig {
+    port:    String,
+    retries: String,
+}
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawConfig, try_from, context = "parsing Config")]
+struct Config {
+    #[relate(_.parse()?)]
+    port:    u16,
+    #[relate(_.parse()?)]
+    retries: u8,
+}
+
+fn raw(port: &str) -> RawConfig {
+    RawConfig {
+        port:    port.to_string(),
+        retries: "3".to_string(),
+    }
+}
+
+#[test]
+fn test_context_ok() {
+    let config = Config::try_from(raw("8080")).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            port:    8080,
+            retries: 3,
+        }
+    );
+}
+
+#[test]
+fn test_context_wraps_error() {
+    let err = Config::try_from(&raw("http")).unwrap_err();
+
+    let ConversionError::Context { context, source } = &err else {
+        panic!("expected a context error, got {err:?}");
+    };
+    assert_eq!(*context, "parsing Config");
+    assert!(matches!(**source, ConversionError::ParseInt(_)));
+    assert!(
+        err.to_string()
+            .starts_with("parsing Config: failed to parse integer")
+    );
+}
+
+#[derive(Debug, Relate)]
+#[relate(RawConfig, collect_errors, context = "parsing Limits")]
+struct Limits {
+    #[relate(_.parse()?)]
+    port:    u16,
+    #[relate(_.parse()?)]
+    retries: u8,
+}
+
+#[test]
+fn test_context_with_collect_errors() {
+    let limits = Limits::try_from(raw("80")).unwrap();
+    assert_eq!((limits.port, limits.retries), (80, 3));
+
+    let source = RawConfig {
+        port:    "x".to_string(),
+        retries: "y".to_string(),
+    };
+    let err = Limits::try_from(source).unwrap_err();
+
+    let ConversionError::Context { source, .. } = err else {
+        panic!("expected a context error");
+    };
+    assert!(matches!(*source, ConversionError::Multiple(ref errors) if errors.len() == 2));
+}
@@ -17,7 +17,7 @@ struct Target {
     optional:    Option<String>,
     #[relate(default = "default".to_string())]
     with_value:  String,
-    #[relate(skip)]
+    #[relate(default)]
     skipped:     i32,
     #[relate(default = false)]
     should_sync: bool,
@@ -84,6 +84,107 @@ mod duplicate_defaults {
     }
 }
 
+mod duplicate_default_fns {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn make_id() -> usize { COUNTER.fetch_add(1, Ordering::SeqCst) + 100 }
+
+    #[derive(Debug, Clone)]
+    struct SimpleSource {
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(SimpleSource)]
+    struct WithDefaultFns {
+        name:      String,
+        #[relate(default_with = make_id)]
+        id:        usize,
+        #[relate(default_with = make_id)]
+        parent_id: usize,
+    }
+
+    #[test]
+    fn test_default_with_called_once() {
+        let source = SimpleSource {
+            name: "test".to_string(),
+        };
+
+        COUNTER.store(0, Ordering::SeqCst);
+        let target: WithDefaultFns = source.clone().into();
+        assert_eq!(target.id, 100);
+        assert_eq!(target.parent_id, 100);
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+
+        COUNTER.store(0, Ordering::SeqCst);
+        let target: WithDefaultFns = (&source).into();
+        assert_eq!(target.name, "test");
+        assert_eq!(target.id, target.parent_id);
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+    }
+}
+
+mod default_if_none {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Record {
+        primary_id: Option<i64>,
+        legacy_id:  Option<i64>,
+        email:      Option<String>,
+        old_email:  Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Record)]
+    struct Account {
+        #[relate(.primary_id, default_if_none = .legacy_id)]
+        id:        Option<i64>,
+        #[relate(default_if_none = .old_email)]
+        email:     Option<String>,
+        // Also read by `email`, so both reads clone in the owned impl
+        old_email: Option<String>,
+    }
+
+    fn record(primary_id: Option<i64>, email: Option<&str>) -> Record {
+        Record {
+            primary_id,
+            legacy_id: Some(7),
+            email: email.map(str::to_string),
+            old_email: Some("old@example.com".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_primary_present() {
+        let account: Account = record(Some(1), Some("new@example.com")).into();
+        assert_eq!(
+            account,
+            Account {
+                id:        Some(1),
+                email:     Some("new@example.com".to_string()),
+                old_email: Some("old@example.com".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_falls_back_when_none() {
+        let account: Account = record(None, None).into();
+        assert_eq!(account.id, Some(7));
+        assert_eq!(account.email.as_deref(), Some("old@example.com"));
+
+        let source = record(None, None);
+        let account: Account = (&source).into();
+        assert_eq!(account.id, Some(7));
+        assert_eq!(account.email, source.old_email);
+    }
+}
+
 #[test]
 fn test_default_values() {
     let source = Source {
@@ -115,3 +216,114 @@ fn test_from_ref_with_defaults() {
     // Source still usable
     assert_eq!(source.name, "ref_test");
 }
+
+// `skip` leaves fields without `Default` to `..base`
+mod skip_with_base {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Handle(u32);
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Source, base = Session::detached())]
+    struct Session {
+        name:   String,
+        #[relate(skip)]
+        handle: Handle,
+        #[relate(skip)]
+        peers:  Vec<Handle>,
+    }
+
+    impl Session {
+        fn detached() -> Self {
+            Self {
+                name:   String::new(),
+                handle: Handle(7),
+                peers:  vec![Handle(1)],
+            }
+        }
+    }
+
+    #[test]
+    fn test_skip_uses_base() {
+        let source = Source {
+            name: "main".to_string(),
+        };
+        let expected = Session {
+            name:   "main".to_string(),
+            handle: Handle(7),
+            peers:  vec![Handle(1)],
+        };
+
+        assert_eq!(Session::from(&source), expected);
+        assert_eq!(Session::from(source), expected);
+    }
+}
+
+// Struct literals and blocks as default expressions
+#[derive(Debug, Clone, PartialEq)]
+struct Limits {
+    min: u32,
+    max: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Source)]
+struct Configured {
+    name:   String,
+    #[relate(default = Limits { min: 1, max: 10 })]
+    limits: Limits,
+    #[relate(default = { let base = 5; base * 2 })]
+    size:   u32,
+}
+
+#[test]
+fn test_struct_literal_and_block_defaults() {
+    let source = Source {
+        name: "complex".to_string(),
+    };
+
+    let target: Configured = (&source).into();
+
+    assert_eq!(target.limits, Limits { min: 1, max: 10 });
+    assert_eq!(target.size, 10);
+}
+
+// Test every field defaulting, so the conversion never reads `src`
+mod all_defaults {
+    #![deny(warnings)]
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Relate)]
+    #[relate(Source)]
+    struct Fresh {
+        #[relate(default)]
+        count: u32,
+        #[relate(default = "new".to_string())]
+        state: String,
+    }
+
+    #[derive(Debug, PartialEq, Relate)]
+    #[relate(Source, error = std::num::ParseIntError)]
+    struct Parsed {
+        #[relate(default = "7".parse()?)]
+        level: u8,
+    }
+
+    #[test]
+    fn test_all_defaults() {
+        let source = Source {
+            name: "ignored".to_string(),
+        };
+        assert_eq!(
+            Fresh::from(&source),
+            Fresh {
+                count: 0,
+                state: "new".to_string(),
+            }
+        );
+        assert_eq!(Parsed::try_from(&source).unwrap(), Parsed { level: 7 });
+        assert_eq!(Parsed::try_from(source).unwrap(), Parsed { level: 7 });
+    }
+}
@@ -0,0 +1,83 @@
+//! Tests for structs parsed from a delimited `&str` row.
+
+use std::marker::PhantomData;
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(&str, delimiter = ",")]
+struct Employee {
+    id:     u32,
+    name:   String,
+    salary: f64,
+    active: bool,
+}
+
+#[test]
+fn test_parse_row() {
+    let employee = Employee::try_from("7, Ada Lovelace, 1200.5, true").unwrap();
+    assert_eq!(
+        employee,
+        Employee {
+            id:     7,
+            name:   "Ada Lovelace".to_string(),
+            salary: 1200.5,
+            active: true,
+        }
+    );
+}
+
+#[test]
+fn test_missing_column() {
+    let err = Employee::try_from("7,Ada").unwrap_err();
+    assert!(matches!(err, ConversionError::MissingField("salary")));
+}
+
+#[test]
+fn test_invalid_column() {
+    let err = Employee::try_from("seven,Ada,1200,true").unwrap_err();
+    assert!(matches!(
+        err,
+        ConversionError::Context { context: "id", .. }
+    ));
+}
+
+// Defaulted fields take no column
+#[derive(Debug, PartialEq, Relate)]
+#[relate(&str, delimiter = "|")]
+struct Reading<Unit> {
+    sensor: String,
+    #[relate(default)]
+    note:   Option<String>,
+    value:  i64,
+    unit:   PhantomData<Unit>,
+}
+
+struct Celsius;
+
+#[test]
+fn test_defaulted_fields_take_no_column() {
+    let reading: Reading<Celsius> = "boiler|-4".try_into().unwrap();
+    assert_eq!(reading.sensor, "boiler");
+    assert_eq!(reading.note, None);
+    assert_eq!(reading.value, -4);
+}
+
+// Tuple structs read columns in order as well
+#[derive(Debug, PartialEq, Relate)]
+#[relate(&str, delimiter = ";", context = "parsing a point")]
+struct Point(i32, i32);
+
+#[test]
+fn test_tuple_struct_row() {
+    assert_eq!(Point::try_from("3;-2").unwrap(), Point(3, -2));
+
+    let err = Point::try_from("3").unwrap_err();
+    assert!(matches!(
+        err,
+        ConversionError::Context {
+            context: "parsing a point",
+            ..
+        }
+    ));
+}
@@ -0,0 +1,77 @@
+//! Tests for converting a fieldless enum to and from its integer discriminant
+//! with `#[relate(i32)]`, and casting fields with `#[relate(as i32)]`.
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone, Copy, PartialEq, Relate)]
+#[relate(i32, both)]
+enum Status {
+    Active = 1,
+    Suspended = 5,
+    Deleted = -1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Relate)]
+#[relate(u8)]
+enum Level {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Account {
+    id:     u64,
+    status: Status,
+}
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(Account)]
+struct AccountRow {
+    id:     u64,
+    #[relate(as i32)]
+    status: i32,
+}
+
+#[test]
+fn test_from_discriminant() {
+    assert_eq!(Status::try_from(5).unwrap(), Status::Suspended);
+    assert_eq!(Status::try_from(&-1).unwrap(), Status::Deleted);
+    assert_eq!(Level::try_from(1u8).unwrap(), Level::High);
+}
+
+#[test]
+fn test_unknown_discriminant() {
+    let err = Status::try_from(2).unwrap_err();
+    assert!(matches!(
+        err,
+        ConversionError::UnknownDiscriminant {
+            enum_name: "Status",
+            value:     2,
+        }
+    ));
+    assert_eq!(err.to_string(), "no variant of Status has discriminant 2");
+    assert!(Level::try_from(&7u8).is_err());
+}
+
+#[test]
+fn test_to_discriminant() {
+    assert_eq!(i32::from(Status::Active), 1);
+    assert_eq!(i32::from(&Status::Deleted), -1);
+}
+
+#[test]
+fn test_field_cast() {
+    let account = Account {
+        id:     7,
+        status: Status::Suspended,
+    };
+    let row: AccountRow = (&account).into();
+    assert_eq!(
+        row,
+        AccountRow {
+            id:     7,
+            status: 5,
+        }
+    );
+    assert_eq!(Status::try_from(row.status).unwrap(), account.status);
+}
@@ -0,0 +1,50 @@
+//! Tests for `secs_to_duration` and `duration_to_secs`.
+
+use std::time::Duration;
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct RawSession {
+    user:       String,
+    ttl:        u64,
+    idle_limit: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(RawSession, both)]
+struct Session {
+    user:       String,
+    #[relate(secs_to_duration)]
+    ttl:        Duration,
+    #[relate(duration_to_secs)]
+    idle_limit: u64,
+}
+
+#[test]
+fn test_secs_to_duration() {
+    let raw = RawSession {
+        user:       "ada".to_string(),
+        ttl:        3_600,
+        idle_limit: Duration::from_secs(300),
+    };
+    let session: Session = (&raw).into();
+    assert_eq!(session.ttl, Duration::from_secs(3_600));
+    assert_eq!(session.idle_limit, 300);
+
+    assert_eq!(Session::from(raw), session);
+}
+
+#[test]
+fn test_durations_reverse() {
+    let session = Session {
+        user:       "bo".to_string(),
+        ttl:        Duration::from_secs(60),
+        idle_limit: 10,
+    };
+    let raw: RawSession = (&session).into();
+    assert_eq!(raw.ttl, 60);
+    assert_eq!(raw.idle_limit, Duration::from_secs(10));
+
+    assert_eq!(RawSession::from(session), raw);
+}
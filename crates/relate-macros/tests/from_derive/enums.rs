@@ -0,0 +1,292 @@
+//! Tests for deriving Relate on enums.
+
+use relate::Relate;
+
+// =============================================================================
+// Unit, Tuple, and Struct Variants
+// =============================================================================
+
+mod variant_kinds {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum DbShape {
+        Empty,
+        Circle(f64),
+        Rect { width: f64, height: f64 },
+        Polygon { sides: u32, label: String },
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(DbShape)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rect {
+            width:  f64,
+            height: f64,
+        },
+        Polygon {
+            sides: u32,
+            #[relate(_.to_uppercase())]
+            label: String,
+        },
+    }
+
+    #[test]
+    fn test_unit_variant() {
+        let shape: Shape = DbShape::Empty.into();
+        assert_eq!(shape, Shape::Empty);
+    }
+
+    #[test]
+    fn test_tuple_variant() {
+        let shape: Shape = DbShape::Circle(1.5).into();
+        assert_eq!(shape, Shape::Circle(1.5));
+    }
+
+    #[test]
+    fn test_struct_variant() {
+        let shape: Shape = DbShape::Rect {
+            width:  2.0,
+            height: 3.0,
+        }
+        .into();
+        assert_eq!(
+            shape,
+            Shape::Rect {
+                width:  2.0,
+                height: 3.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_struct_variant_transform_from_ref() {
+        let db = DbShape::Polygon {
+            sides: 6,
+            label: "hex".to_string(),
+        };
+        let shape: Shape = (&db).into();
+
+        assert_eq!(
+            shape,
+            Shape::Polygon {
+                sides: 6,
+                label: "HEX".to_string(),
+            }
+        );
+        // Source still usable
+        assert!(matches!(db, DbShape::Polygon { sides: 6, .. }));
+    }
+}
+
+// =============================================================================
+// Variant Renames and Nested Conversions
+// =============================================================================
+
+mod renames {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct DbUser {
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(DbUser)]
+    struct User {
+        name: String,
+    }
+
+    #[derive(Debug, Clone)]
+    enum DbEvent {
+        SignedUp { user: DbUser },
+        LoggedOut,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(DbEvent)]
+    enum Event {
+        #[relate(SignedUp)]
+        Registered {
+            #[relate(into)]
+            user: User,
+        },
+        #[relate(LoggedOut)]
+        SignedOut,
+    }
+
+    #[test]
+    fn test_renamed_variants() {
+        let event: Event = DbEvent::SignedUp {
+            user: DbUser {
+                name: "ada".to_string(),
+            },
+        }
+        .into();
+        assert_eq!(
+            event,
+            Event::Registered {
+                user: User {
+                    name: "ada".to_string(),
+                },
+            }
+        );
+
+        let event: Event = (&DbEvent::LoggedOut).into();
+        assert_eq!(event, Event::SignedOut);
+    }
+}
+
+// =============================================================================
+// Bidirectional Enums
+// =============================================================================
+
+mod bidirectional {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum DbStatus {
+        Active,
+        Suspended(String),
+        Deleted { at: u64 },
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(DbStatus, both)]
+    enum Status {
+        Active,
+        #[relate(Suspended)]
+        Paused(String),
+        Deleted {
+            at: u64,
+        },
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for db in [
+            DbStatus::Active,
+            DbStatus::Suspended("abuse".to_string()),
+            DbStatus::Deleted { at: 42 },
+        ] {
+            let status: Status = (&db).into();
+            let back: DbStatus = status.into();
+            assert_eq!(back, db);
+        }
+    }
+
+    #[test]
+    fn test_reverse_from_ref() {
+        let status = Status::Paused("review".to_string());
+        let db: DbStatus = (&status).into();
+        assert_eq!(db, DbStatus::Suspended("review".to_string()));
+    }
+}
+
+// =============================================================================
+// Fallible Variant Fields
+// =============================================================================
+
+mod fallible {
+    use relate::ConversionError;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    enum RawSetting {
+        Port { value: String },
+        Disabled,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawSetting)]
+    enum Setting {
+        Port {
+            #[relate(_.parse()?)]
+            value: u16,
+        },
+        Disabled,
+    }
+
+    #[test]
+    fn test_fallible_variant_success() {
+        let setting: Setting = RawSetting::Port {
+            value: "8080".to_string(),
+        }
+        .try_into()
+        .expect("should parse");
+        assert_eq!(setting, Setting::Port { value: 8080 });
+    }
+
+    #[test]
+    fn test_fallible_variant_failure() {
+        let raw = RawSetting::Port {
+            value: "http".to_string(),
+        };
+        let result: Result<Setting, ConversionError> = (&raw).try_into();
+        assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+    }
+
+    #[test]
+    fn test_infallible_variant_in_try_from() {
+        let setting: Result<Setting, ConversionError> = RawSetting::Disabled.try_into();
+        assert_eq!(setting.unwrap(), Setting::Disabled);
+    }
+}
+
+// =============================================================================
+// Tuple Variant Field Attributes
+// =============================================================================
+
+mod tuple_variant_attributes {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    enum RawMeasure {
+        Count(u32, String),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawMeasure)]
+    enum Measure {
+        Count(#[relate(into, copy)] u64, #[relate(_.len())] usize),
+    }
+
+    #[test]
+    fn test_tuple_variant_attributes() {
+        let measure: Measure = (&RawMeasure::Count(3, "abcd".to_string())).into();
+        assert_eq!(measure, Measure::Count(3, 4));
+    }
+}
+
+// =============================================================================
+// Generic Source Enum
+// =============================================================================
+
+mod generic_source {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    enum Wrapped<T> {
+        Value(T),
+        Missing,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Wrapped<String>)]
+    enum Text {
+        Value(String),
+        Missing,
+    }
+
+    #[test]
+    fn test_generic_source_enum() {
+        let text: Text = Wrapped::Value("hi".to_string()).into();
+        assert_eq!(text, Text::Value("hi".to_string()));
+
+        let text: Text = (&Wrapped::<String>::Missing).into();
+        assert_eq!(text, Text::Missing);
+    }
+}
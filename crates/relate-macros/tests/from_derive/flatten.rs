@@ -0,0 +1,82 @@
+//! Tests for `#[relate(Source, flatten(outer: a, b))]` nested source fields.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Meta {
+    created_at: u64,
+    updated_at: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Audit {
+    author: String,
+}
+
+#[derive(Debug, Clone)]
+struct Document {
+    meta:  Meta,
+    audit: Audit,
+    title: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Document, flatten(meta: created_at, updated_at), flatten(audit: author))]
+struct DocumentRow {
+    title:      String,
+    created_at: u64,
+    updated_at: u64,
+    author:     String,
+}
+
+fn document() -> Document {
+    Document {
+        meta:  Meta {
+            created_at: 10,
+            updated_at: 20,
+        },
+        audit: Audit {
+            author: "ada".to_string(),
+        },
+        title: "Notes".to_string(),
+    }
+}
+
+#[test]
+fn test_flatten() {
+    let row: DocumentRow = document().into();
+    assert_eq!(
+        row,
+        DocumentRow {
+            title:      "Notes".to_string(),
+            created_at: 10,
+            updated_at: 20,
+            author:     "ada".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_flatten_from_ref() {
+    let source = document();
+    let row: DocumentRow = (&source).into();
+    assert_eq!(row.author, "ada");
+    assert_eq!(source.audit.author, "ada");
+}
+
+// A field with its own mapping keeps it
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Document, flatten(meta: created_at, updated_at))]
+struct DocumentSummary {
+    title:      String,
+    created_at: u64,
+    #[relate(default)]
+    updated_at: u64,
+}
+
+#[test]
+fn test_flatten_explicit_mapping_wins() {
+    let summary: DocumentSummary = document().into();
+    assert_eq!(summary.created_at, 10);
+    assert_eq!(summary.updated_at, 0);
+}
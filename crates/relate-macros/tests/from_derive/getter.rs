@@ -0,0 +1,55 @@
+//! Tests for `#[relate(getter = method)]` reading a value through a method.
+
+use relate::Relate;
+
+mod model {
+    /// Private fields, exposed only through getters.
+    #[derive(Debug, Clone)]
+    pub struct Account {
+        first: String,
+        last:  String,
+        cents: i64,
+    }
+
+    impl Account {
+        pub fn new(first: &str, last: &str, cents: i64) -> Self {
+            Self {
+                first: first.to_string(),
+                last: last.to_string(),
+                cents,
+            }
+        }
+
+        pub fn full_name(&self) -> String { format!("{} {}", self.first, self.last) }
+
+        pub const fn balance(&self) -> i64 { self.cents / 100 }
+
+        pub fn last(&self) -> &String { &self.last }
+    }
+}
+
+use model::Account;
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(Account)]
+struct AccountView {
+    #[relate(getter = full_name)]
+    name:    String,
+    #[relate(getter = balance)]
+    balance: i64,
+    #[relate(getter = last, cloned)]
+    surname: String,
+}
+
+#[test]
+fn test_getter_owned_and_ref() {
+    let account = Account::new("Ada", "Lovelace", 12_345);
+    let expected = AccountView {
+        name:    "Ada Lovelace".to_string(),
+        balance: 123,
+        surname: "Lovelace".to_string(),
+    };
+
+    assert_eq!(AccountView::from(&account), expected);
+    assert_eq!(AccountView::from(account), expected);
+}
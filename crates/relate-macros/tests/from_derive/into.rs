@@ -0,0 +1,112 @@
+//! Tests for `#[relate(into)]` nested conversions.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Inner {
+    value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Inner)]
+struct InnerDto {
+    value: String,
+}
+
+#[derive(Debug, Clone)]
+struct Outer {
+    id:    u64,
+    inner: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Outer)]
+struct OuterDto {
+    id:    u64,
+    #[relate(into)]
+    inner: InnerDto,
+}
+
+fn outer() -> Outer {
+    Outer {
+        id:    7,
+        inner: Inner {
+            value: "nested".to_string(),
+        },
+    }
+}
+
+#[test]
+fn test_into_nested() {
+    let dto: OuterDto = outer().into();
+
+    assert_eq!(dto.id, 7);
+    assert_eq!(
+        dto.inner,
+        InnerDto {
+            value: "nested".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_into_nested_from_ref() {
+    let source = outer();
+    // Uses `From<&Inner> for InnerDto`, no clone of `Inner` required
+    let dto: OuterDto = (&source).into();
+
+    assert_eq!(dto.inner.value, "nested");
+    assert_eq!(source.inner.value, "nested");
+}
+
+// Copy mode converts by value even in the ref impl
+#[derive(Debug, Clone)]
+struct Counter {
+    hits: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Counter)]
+struct WideCounter {
+    #[relate(into, copy)]
+    hits: u64,
+}
+
+#[test]
+fn test_into_copy_from_ref() {
+    let counter = Counter { hits: 3 };
+    let wide: WideCounter = (&counter).into();
+    assert_eq!(wide.hits, 3);
+}
+
+// Bidirectional converts back with `Into` as well
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(InnerDto, both)]
+struct InnerView {
+    value: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Wrapper {
+    inner: InnerDto,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Wrapper, both)]
+struct WrapperView {
+    #[relate(into)]
+    inner: InnerView,
+}
+
+#[test]
+fn test_into_bidirectional() {
+    let wrapper = Wrapper {
+        inner: InnerDto {
+            value: "round".to_string(),
+        },
+    };
+    let view: WrapperView = (&wrapper).into();
+    let back: Wrapper = view.into();
+
+    assert_eq!(back, wrapper);
+}
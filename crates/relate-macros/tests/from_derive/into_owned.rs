@@ -0,0 +1,55 @@
+//! Tests for `#[relate(into_owned)]` on `Cow` source fields.
+
+use std::borrow::Cow;
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Parsed<'a> {
+    name:  Cow<'a, str>,
+    bytes: Cow<'a, [u8]>,
+    count: u32,
+}
+
+// `'_` in the source type keeps the generated impls generic over its lifetime
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Parsed<'_>)]
+struct Record {
+    #[relate(into_owned)]
+    name:  String,
+    #[relate(into_owned)]
+    bytes: Vec<u8>,
+    count: u32,
+}
+
+fn parsed(input: &str) -> Parsed<'_> {
+    Parsed {
+        name:  Cow::Borrowed(input),
+        bytes: Cow::Owned(input.as_bytes().to_vec()),
+        count: 2,
+    }
+}
+
+#[test]
+fn test_into_owned() {
+    let input = String::from("zero-copy");
+    let record: Record = parsed(&input).into();
+
+    assert_eq!(
+        record,
+        Record {
+            name:  "zero-copy".to_string(),
+            bytes: b"zero-copy".to_vec(),
+            count: 2,
+        }
+    );
+}
+
+#[test]
+fn test_into_owned_from_ref() {
+    let source = parsed("borrowed");
+    let record: Record = (&source).into();
+
+    assert_eq!(record.name, source.name);
+    assert_eq!(record.bytes, &*source.bytes);
+}
@@ -0,0 +1,67 @@
+//! Tests for `#[relate(serde_json::Value)]` JSON object sources.
+
+use relate::{ConversionError, Relate};
+use serde_json::json;
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(serde_json::Value)]
+struct Profile {
+    id:       u64,
+    name:     String,
+    #[relate(.display_name)]
+    nickname: Option<String>,
+    #[relate(default)]
+    tags:     Vec<String>,
+}
+
+#[test]
+fn test_from_json() {
+    let value = json!({ "id": 1, "name": "Ada", "display_name": "ada" });
+    let profile = Profile::try_from(value).unwrap();
+    assert_eq!(
+        profile,
+        Profile {
+            id:       1,
+            name:     "Ada".to_string(),
+            nickname: Some("ada".to_string()),
+            tags:     Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn test_from_json_ref() {
+    let value = json!({ "id": 2, "name": "Grace" });
+    let profile = Profile::try_from(&value).unwrap();
+    assert_eq!(profile.name, "Grace");
+    assert_eq!(profile.nickname, None);
+}
+
+#[test]
+fn test_missing_key() {
+    let err = Profile::try_from(json!({ "id": 3 })).unwrap_err();
+    assert!(matches!(err, ConversionError::MissingField("name")));
+}
+
+#[test]
+fn test_mistyped_key() {
+    let err = Profile::try_from(json!({ "id": "three", "name": "Ada" })).unwrap_err();
+    assert!(matches!(
+        err,
+        ConversionError::Context { context: "id", .. }
+    ));
+    assert!(err.to_string().starts_with("id: invalid JSON value"));
+}
+
+// Struct-level renames pick the key too
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(serde_json::Value, rename_fields(userName = name))]
+struct Author {
+    name: String,
+}
+
+#[test]
+fn test_from_json_rename_fields() {
+    let author = Author::try_from(json!({ "userName": "Ada" })).unwrap();
+    assert_eq!(author.name, "Ada");
+}
@@ -0,0 +1,86 @@
+//! Tests for `HashMap` and `BTreeMap` fields in identity, rename, and
+//! cloned collection mappings.
+
+use std::collections::{BTreeMap, HashMap};
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Source {
+    tags:   HashMap<String, u32>,
+    scores: BTreeMap<String, u32>,
+}
+
+mod identity_and_rename {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Source)]
+    struct Target {
+        tags:    HashMap<String, u32>,
+        #[relate(.scores)]
+        ranking: BTreeMap<String, u32>,
+    }
+
+    #[test]
+    fn test_identity_and_rename() {
+        let source = source();
+        let from_ref: Target = (&source).into();
+        let owned: Target = source.clone().into();
+
+        assert_eq!(owned.tags, source.tags);
+        assert_eq!(owned.ranking, source.scores);
+        assert_eq!(from_ref, owned);
+    }
+}
+
+mod cloned_mode {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Source, cloned)]
+    struct Target {
+        tags:   HashMap<String, u32>,
+        scores: BTreeMap<String, u32>,
+    }
+
+    #[test]
+    fn test_cloned_maps() {
+        let source = source();
+        let target: Target = (&source).into();
+        assert_eq!(target.tags, source.tags);
+        assert_eq!(target.scores, source.scores);
+    }
+}
+
+// `[_], cloned` clones keys and converts values with `Into`
+mod collection_cloned {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Source)]
+    struct Target {
+        #[relate([_], cloned)]
+        tags:   HashMap<String, u64>,
+        #[relate([_], cloned)]
+        scores: BTreeMap<String, u64>,
+    }
+
+    #[test]
+    fn test_values_converted() {
+        let source = source();
+        let from_ref: Target = (&source).into();
+        let owned: Target = source.into();
+
+        assert_eq!(owned.tags["a"], 1_u64);
+        assert_eq!(owned.scores.get("b"), Some(&2_u64));
+        assert_eq!(from_ref, owned);
+    }
+}
+
+fn source() -> Source {
+    Source {
+        tags:   HashMap::from([("a".to_string(), 1)]),
+        scores: BTreeMap::from([("b".to_string(), 2), ("c".to_string(), 3)]),
+    }
+}
@@ -0,0 +1,149 @@
+//! Tests for map value mapping (`map[...]`) in Relate derive.
+
+use std::collections::{BTreeMap, HashMap};
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct DbVariant {
+    sku: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Variant {
+    sku: String,
+}
+
+impl From<DbVariant> for Variant {
+    fn from(db: DbVariant) -> Self { Self { sku: db.sku } }
+}
+
+#[derive(Debug, Clone)]
+struct DbProduct {
+    id:       u64,
+    variants: HashMap<String, DbVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbProduct)]
+struct Product {
+    id:       u64,
+    #[relate(map[_.clone().into()])]
+    variants: HashMap<String, Variant>,
+}
+
+fn db_product() -> DbProduct {
+    let mut variants = HashMap::new();
+    variants.insert(
+        "small".to_string(),
+        DbVariant {
+            sku: "S-1".to_string(),
+        },
+    );
+    variants.insert(
+        "large".to_string(),
+        DbVariant {
+            sku: "L-1".to_string(),
+        },
+    );
+    DbProduct { id: 1, variants }
+}
+
+#[test]
+fn test_map_values() {
+    let product: Product = db_product().into();
+
+    assert_eq!(product.id, 1);
+    assert_eq!(product.variants.len(), 2);
+    assert_eq!(
+        product.variants["small"],
+        Variant {
+            sku: "S-1".to_string(),
+        }
+    );
+    assert_eq!(
+        product.variants["large"],
+        Variant {
+            sku: "L-1".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_map_values_from_ref() {
+    let db = db_product();
+    let product: Product = (&db).into();
+
+    assert_eq!(product.variants["small"].sku, "S-1");
+    // Original still usable
+    assert_eq!(db.variants["small"].sku, "S-1");
+}
+
+#[test]
+fn test_empty_map() {
+    let db = DbProduct {
+        id:       2,
+        variants: HashMap::new(),
+    };
+    let product: Product = db.into();
+
+    assert!(product.variants.is_empty());
+}
+
+// Field access shorthand with a BTreeMap
+#[derive(Debug, Clone)]
+struct Inventory {
+    counts: BTreeMap<String, DbVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Inventory)]
+struct InventorySkus {
+    #[relate(map[.sku.clone()])]
+    counts: BTreeMap<String, String>,
+}
+
+#[test]
+fn test_map_values_shorthand() {
+    let mut counts = BTreeMap::new();
+    counts.insert(
+        "a".to_string(),
+        DbVariant {
+            sku: "A-1".to_string(),
+        },
+    );
+    let skus: InventorySkus = Inventory { counts }.into();
+
+    assert_eq!(skus.counts["a"], "A-1");
+}
+
+// Cloned mode clones values and converts with Into
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbProduct)]
+struct ClonedProduct {
+    id:       u64,
+    #[relate(map[_], cloned)]
+    variants: HashMap<String, Variant>,
+}
+
+#[test]
+fn test_map_values_cloned() {
+    let product: ClonedProduct = (&db_product()).into();
+
+    assert_eq!(product.variants["large"].sku, "L-1");
+}
+
+// `[...]` building `(key, value)` entries is still allowed on map fields
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbProduct)]
+struct UppercaseKeys {
+    #[relate([(_.0.to_uppercase(), _.1.clone().into())])]
+    variants: HashMap<String, Variant>,
+}
+
+#[test]
+fn test_collection_map_entries() {
+    let product: UppercaseKeys = db_product().into();
+
+    assert_eq!(product.variants["SMALL"].sku, "S-1");
+}
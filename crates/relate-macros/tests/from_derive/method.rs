@@ -0,0 +1,70 @@
+//! Tests for `#[relate(Source, method = name)]` inherent conversion methods.
+
+use std::ops::Range;
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Range<u32>, method = from_range)]
+struct Span {
+    start: u32,
+    #[relate(.end - .start)]
+    len:   u32,
+}
+
+#[test]
+fn test_method() {
+    let span = Span::from_range(&(3..10));
+    assert_eq!(span, Span { start: 3, len: 7 });
+}
+
+#[derive(Debug, Clone)]
+struct RawPort {
+    port: String,
+    host: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(RawPort, method = parse_raw)]
+struct Port {
+    #[relate(_.parse()?)]
+    port: u16,
+    host: String,
+}
+
+#[test]
+fn test_fallible_method() {
+    let raw = RawPort {
+        port: "8080".to_string(),
+        host: "localhost".to_string(),
+    };
+    let port = Port::parse_raw(&raw).expect("should parse");
+    assert_eq!(port.port, 8080);
+    assert_eq!(port.host, "localhost");
+
+    let raw = RawPort {
+        port: "http".to_string(),
+        ..raw
+    };
+    let result: Result<Port, ConversionError> = Port::parse_raw(&raw);
+    assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(RawPort, method = collect_raw, collect_errors)]
+struct CheckedPort {
+    #[relate(_.parse()?)]
+    port: u16,
+    #[relate(_.parse()?)]
+    host: u8,
+}
+
+#[test]
+fn test_collect_errors_method() {
+    let raw = RawPort {
+        port: "x".to_string(),
+        host: "y".to_string(),
+    };
+    let err = CheckedPort::collect_raw(&raw).unwrap_err();
+    assert!(matches!(err, ConversionError::Multiple(errors) if errors.len() == 2));
+}
@@ -1,13 +1,56 @@
+mod allow_missing;
+mod also_arc;
+mod also_option;
+mod apply_patch;
 mod basic;
 mod bidirectional;
 mod clone_modes;
+mod closures;
+mod collect_errors;
 mod collection_cloned;
+mod collection_container_change;
 mod collection_map;
+mod const_fn;
+mod context;
 mod defaults;
+mod delimited;
+mod discriminant;
+mod durations;
+mod enums;
+mod flatten;
 mod from_expr;
+mod getter;
+mod into;
+mod into_owned;
+mod json_value;
 mod large_struct;
+mod map_collections;
+mod map_values;
+mod method;
+mod multiple_sources;
+mod optionalize;
+mod owned_only;
+mod phantom;
+mod pointers;
+mod raw_identifiers;
+mod ref_only;
 mod rename;
+mod rename_fields;
+mod required;
 mod single_field;
+mod skip_if;
 mod source_access;
+mod strip_prefix;
+mod target_refs;
+mod to_vec;
 mod transform;
 mod try_from;
+mod try_into_self;
+mod tuple_source;
+mod tuple_structs;
+mod unit_source;
+mod unwrap_or_default;
+mod validate;
+mod where_bounds;
+mod with_fn;
+mod wrap_some;
@@ -0,0 +1,143 @@
+//! Tests for stacked `#[relate(A)]` / `#[relate(B)]` source attributes.
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone)]
+struct DbUser {
+    id:    i64,
+    name:  String,
+    email: String,
+}
+
+#[derive(Debug, Clone)]
+struct ApiUser {
+    user_id: String,
+    name:    String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbUser)]
+#[relate(ApiUser, error = ConversionError)]
+struct UserDto {
+    #[relate(from = ApiUser, .user_id.parse()?)]
+    id:    i64,
+    // Unscoped attributes apply to every source unless a scoped one overrides them
+    #[relate(_.to_uppercase())]
+    #[relate(from = ApiUser, _.clone())]
+    name:  String,
+    #[relate(from = ApiUser, default)]
+    email: String,
+}
+
+#[test]
+fn test_first_source() {
+    let user = DbUser {
+        id:    7,
+        name:  "Ada".to_string(),
+        email: "ada@example.com".to_string(),
+    };
+    let dto: UserDto = (&user).into();
+    assert_eq!(
+        dto,
+        UserDto {
+            id:    7,
+            name:  "ADA".to_string(),
+            email: "ada@example.com".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_second_source() {
+    let user = ApiUser {
+        user_id: "7".to_string(),
+        name:    "Ada".to_string(),
+    };
+    let dto: UserDto = user.try_into().expect("should parse");
+    assert_eq!(dto.id, 7);
+    assert_eq!(dto.name, "Ada");
+    assert_eq!(dto.email, "");
+}
+
+#[test]
+fn test_second_source_failure() {
+    let user = ApiUser {
+        user_id: "seven".to_string(),
+        name:    "Ada".to_string(),
+    };
+    let result: Result<UserDto, ConversionError> = (&user).try_into();
+    assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+}
+
+// Fields without an attribute for a source map by name
+#[derive(Debug, Clone)]
+struct Celsius {
+    value: f64,
+}
+
+#[derive(Debug, Clone)]
+struct Fahrenheit {
+    value: f64,
+}
+
+#[derive(Debug, Clone)]
+struct Kelvin {
+    kelvin: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Celsius, both)]
+#[relate(Fahrenheit)]
+#[relate(Kelvin)]
+struct Reading {
+    #[relate(from = Fahrenheit, with = (.value - 32.0) / 1.8)]
+    #[relate(from = Kelvin, with = .kelvin - 273.15)]
+    value: f64,
+}
+
+#[test]
+fn test_scoped_override() {
+    let from_celsius: Reading = Celsius { value: 100.0 }.into();
+    let from_fahrenheit: Reading = Fahrenheit { value: 212.0 }.into();
+    let from_kelvin: Reading = (&Kelvin { kelvin: 373.15 }).into();
+
+    assert_eq!(from_celsius, Reading { value: 100.0 });
+    assert_eq!(from_fahrenheit, Reading { value: 100.0 });
+    assert!((from_kelvin.value - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_per_source_options() {
+    let celsius: Celsius = Reading { value: 21.5 }.into();
+    assert_eq!(celsius.value, 21.5);
+}
+
+// Enum variant renames can be scoped too
+#[derive(Debug, Clone)]
+enum DbState {
+    On,
+    Off,
+}
+
+#[derive(Debug, Clone)]
+enum WireState {
+    Enabled,
+    Off,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbState)]
+#[relate(WireState)]
+enum State {
+    #[relate(from = WireState, Enabled)]
+    On,
+    Off,
+}
+
+#[test]
+fn test_scoped_variant_rename() {
+    assert_eq!(State::from(DbState::On), State::On);
+    assert_eq!(State::from(WireState::Enabled), State::On);
+    assert_eq!(State::from(&WireState::Off), State::Off);
+    assert_eq!(State::from(DbState::Off), State::Off);
+}
@@ -0,0 +1,62 @@
+//! Tests for `#[relate(Base, optionalize)]` / `#[relate(Patch, deoptionalize)]`
+//! patch structs.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(UserPatch, deoptionalize)]
+struct User {
+    name:  String,
+    age:   u32,
+    #[relate(.nickname.clone().unwrap_or_else(|| String::from("anon")))]
+    alias: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(User, optionalize)]
+struct UserPatch {
+    name:     Option<String>,
+    age:      Option<u32>,
+    #[relate(with = Some(.alias.clone()))]
+    nickname: Option<String>,
+}
+
+#[test]
+fn test_optionalize() {
+    let user = User {
+        name:  "Ada".to_string(),
+        age:   36,
+        alias: "ada".to_string(),
+    };
+    let patch: UserPatch = (&user).into();
+    assert_eq!(
+        patch,
+        UserPatch {
+            name:     Some("Ada".to_string()),
+            age:      Some(36),
+            nickname: Some("ada".to_string()),
+        }
+    );
+    let patch: UserPatch = user.into();
+    assert_eq!(patch.age, Some(36));
+}
+
+#[test]
+fn test_deoptionalize() {
+    let patch = UserPatch {
+        name:     Some("Grace".to_string()),
+        age:      None,
+        nickname: None,
+    };
+    let user: User = (&patch).into();
+    assert_eq!(
+        user,
+        User {
+            name:  "Grace".to_string(),
+            age:   0,
+            alias: "anon".to_string(),
+        }
+    );
+    let user: User = patch.into();
+    assert_eq!(user.name, "Grace");
+}
@@ -0,0 +1,63 @@
+//! Tests for `owned_only`, which skips the `From<&Source>` impls.
+
+use relate::Relate;
+
+// Not `Clone`: a ref impl couldn't be generated
+#[derive(Debug, PartialEq)]
+struct Handle(u32);
+
+#[derive(Debug)]
+struct Connection {
+    id:     u32,
+    handle: Handle,
+}
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(Connection, owned_only, both)]
+struct Session {
+    id:     u32,
+    handle: Handle,
+}
+
+#[test]
+fn test_owned_only() {
+    let session: Session = Connection {
+        id:     1,
+        handle: Handle(9),
+    }
+    .into();
+    assert_eq!(
+        session,
+        Session {
+            id:     1,
+            handle: Handle(9),
+        }
+    );
+
+    let back: Connection = session.into();
+    assert_eq!(back.handle, Handle(9));
+}
+
+#[derive(Debug)]
+struct RawPort {
+    port:   String,
+    handle: Handle,
+}
+
+#[derive(Debug, Relate)]
+#[relate(RawPort, owned_only)]
+struct Port {
+    #[relate(_.parse()?)]
+    port:   u16,
+    handle: Handle,
+}
+
+#[test]
+fn test_owned_only_try_from() {
+    let port = Port::try_from(RawPort {
+        port:   "80".to_string(),
+        handle: Handle(2),
+    })
+    .unwrap();
+    assert_eq!((port.port, port.handle), (80, Handle(2)));
+}
@@ -0,0 +1,51 @@
+//! Tests for `PhantomData` marker fields filled without an attribute.
+
+use std::marker::PhantomData;
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct RawId {
+    value: u64,
+}
+
+// `T` needs no `Default`: the marker is built as `PhantomData` directly
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawId, both)]
+struct Id<T> {
+    value:  u64,
+    marker: PhantomData<T>,
+}
+
+struct User;
+
+#[test]
+fn test_phantom_marker_filled() {
+    let raw = RawId { value: 7 };
+    let id: Id<User> = (&raw).into();
+    assert_eq!(id.value, 7);
+
+    let id: Id<User> = raw.into();
+    assert_eq!(id.marker, PhantomData);
+}
+
+#[test]
+fn test_phantom_marker_reverse() {
+    let id = Id::<User> {
+        value:  9,
+        marker: PhantomData,
+    };
+    assert_eq!(RawId::from(&id), RawId { value: 9 });
+    assert_eq!(RawId::from(id), RawId { value: 9 });
+}
+
+// Markers in tuple structs are filled the same way
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawId)]
+struct Tagged<T>(#[relate(.value)] u64, PhantomData<fn() -> T>);
+
+#[test]
+fn test_phantom_marker_tuple_struct() {
+    let tagged: Tagged<User> = RawId { value: 3 }.into();
+    assert_eq!(tagged.0, 3);
+}
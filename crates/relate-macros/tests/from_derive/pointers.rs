@@ -0,0 +1,83 @@
+//! Tests for `boxed`, `rc`, `arc`, and `unbox` smart pointer modifiers.
+
+use std::{rc::Rc, sync::Arc};
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Config {
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+struct Plain {
+    config: Config,
+    label:  String,
+    tags:   Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Plain)]
+struct Shared {
+    #[relate(boxed)]
+    config: Box<Config>,
+    #[relate(rc)]
+    label:  Rc<String>,
+    #[relate(arc)]
+    tags:   Arc<Vec<String>>,
+}
+
+fn plain() -> Plain {
+    Plain {
+        config: Config {
+            name: "main".to_string(),
+        },
+        label:  "x".to_string(),
+        tags:   vec!["a".to_string()],
+    }
+}
+
+#[test]
+fn test_wrap_pointers() {
+    let shared: Shared = plain().into();
+
+    assert_eq!(shared.config.name, "main");
+    assert_eq!(*shared.label, "x");
+    assert_eq!(*shared.tags, vec!["a".to_string()]);
+}
+
+#[test]
+fn test_wrap_pointers_from_ref() {
+    let source = plain();
+    let shared: Shared = (&source).into();
+
+    assert_eq!(*shared.config, source.config);
+    assert_eq!(Rc::strong_count(&shared.label), 1);
+}
+
+#[derive(Debug, Clone)]
+struct Boxed {
+    config: Box<Config>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Boxed)]
+struct Unboxed {
+    #[relate(unbox)]
+    config: Config,
+}
+
+#[test]
+fn test_unbox() {
+    let boxed = Boxed {
+        config: Box::new(Config {
+            name: "main".to_string(),
+        }),
+    };
+
+    let from_ref: Unboxed = (&boxed).into();
+    let owned: Unboxed = boxed.into();
+
+    assert_eq!(owned.config.name, "main");
+    assert_eq!(owned, from_ref);
+}
@@ -0,0 +1,71 @@
+//! Tests for raw identifier field names (`r#type`, `r#struct`).
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct ApiItem {
+    r#type:   String,
+    r#struct: u32,
+    kind:     String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(ApiItem)]
+struct Item {
+    // Identity
+    r#type:   String,
+    // `with` using `_`, reading `r#struct` twice
+    #[relate(with = _ + 1)]
+    r#struct: u32,
+    #[relate(with = .r#struct * 2)]
+    doubled:  u32,
+    // Rename from a raw identifier, read again after `r#type` moved
+    #[relate(.r#type)]
+    label:    String,
+    // Rename to a raw identifier
+    #[relate(.kind)]
+    r#enum:   String,
+}
+
+#[test]
+fn test_raw_identifiers() {
+    let api = ApiItem {
+        r#type:   "widget".to_string(),
+        r#struct: 20,
+        kind:     "gear".to_string(),
+    };
+
+    let from_ref: Item = (&api).into();
+    let owned: Item = api.into();
+
+    assert_eq!(owned.r#type, "widget");
+    assert_eq!(owned.r#struct, 21);
+    assert_eq!(owned.doubled, 40);
+    assert_eq!(owned.label, "widget");
+    assert_eq!(owned.r#enum, "gear");
+    assert_eq!(owned, from_ref);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    r#type: String,
+    value:  String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Token, both)]
+struct TokenRow {
+    r#type: String,
+    value:  String,
+}
+
+#[test]
+fn test_raw_identifiers_both() {
+    let token = Token {
+        r#type: "ident".to_string(),
+        value:  "x".to_string(),
+    };
+    let row: TokenRow = token.clone().into();
+    let back: Token = row.into();
+    assert_eq!(back, token);
+}
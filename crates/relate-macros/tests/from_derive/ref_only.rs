@@ -0,0 +1,102 @@
+//! Tests for `ref_only`, which skips the owning `From<Source>` impls.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Account {
+    id:      u32,
+    balance: i64,
+    owner:   String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Account, ref_only)]
+struct AccountView {
+    id:      u32,
+    balance: i64,
+    owner:   String,
+}
+
+#[test]
+fn test_ref_only() {
+    let account = Account {
+        id:      4,
+        balance: -20,
+        owner:   "ada".to_string(),
+    };
+
+    let view = AccountView::from(&account);
+    assert_eq!(
+        view,
+        AccountView {
+            id:      4,
+            balance: -20,
+            owner:   "ada".to_string(),
+        }
+    );
+    // The source is never consumed
+    assert_eq!(account.owner, "ada");
+}
+
+// `copy` still applies: the ref impl copies instead of cloning
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Account, ref_only, copy, both)]
+struct Balance {
+    id:      u32,
+    balance: i64,
+    #[relate(cloned)]
+    owner:   String,
+}
+
+#[test]
+fn test_ref_only_bidirectional() {
+    let account = Account {
+        id:      1,
+        balance: 5,
+        owner:   "bo".to_string(),
+    };
+
+    let balance = Balance::from(&account);
+    let back = Account::from(&balance);
+    assert_eq!(back.balance, 5);
+    assert_eq!(back.owner, "bo");
+}
+
+// A lifetime of the derived type borrows from the source: `From<&'a Account>`
+#[derive(Debug, PartialEq, Relate)]
+#[relate(Account, ref_only)]
+struct AccountRef<'a> {
+    id:    u32,
+    #[relate(_.as_str())]
+    owner: &'a str,
+}
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(Account, ref_only, try_from)]
+struct CheckedAccountRef<'a> {
+    #[relate(_.to_string().parse()?)]
+    id:    u16,
+    #[relate(with = &_)]
+    owner: &'a String,
+}
+
+#[test]
+fn test_ref_only_borrowing_target() {
+    let account = Account {
+        id:      9,
+        balance: 0,
+        owner:   "grace".to_string(),
+    };
+
+    let view = AccountRef::from(&account);
+    assert_eq!(
+        view,
+        AccountRef {
+            id:    9,
+            owner: "grace",
+        }
+    );
+
+    let checked = CheckedAccountRef::try_from(&account).unwrap();
+    assert_eq!(checked.owner, "grace");
+}
@@ -0,0 +1,78 @@
+//! Tests for `#[relate(Source, rename_fields(old = new, ..))]` bulk renames.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct LegacyAccount {
+    id:        u64,
+    user_name: String,
+    mail:      String,
+    active:    bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(LegacyAccount, both, rename_fields(user_name = name, mail = email))]
+struct Account {
+    id:     u64,
+    name:   String,
+    email:  String,
+    active: bool,
+}
+
+fn legacy() -> LegacyAccount {
+    LegacyAccount {
+        id:        7,
+        user_name: "ada".to_string(),
+        mail:      "ada@example.com".to_string(),
+        active:    true,
+    }
+}
+
+#[test]
+fn test_rename_fields() {
+    let account: Account = legacy().into();
+    assert_eq!(
+        account,
+        Account {
+            id:     7,
+            name:   "ada".to_string(),
+            email:  "ada@example.com".to_string(),
+            active: true,
+        }
+    );
+}
+
+#[test]
+fn test_rename_fields_from_ref() {
+    let source = legacy();
+    let account: Account = (&source).into();
+    assert_eq!(account.name, "ada");
+    assert_eq!(source.user_name, "ada");
+}
+
+#[test]
+fn test_rename_fields_reverse() {
+    let back: LegacyAccount = Account::from(legacy()).into();
+    assert_eq!(back, legacy());
+}
+
+// Fields with their own mapping ignore the rename
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(LegacyAccount, rename_fields(user_name = name, mail = email))]
+struct AccountCard {
+    #[relate(.user_name.to_uppercase())]
+    name:  String,
+    email: String,
+}
+
+#[test]
+fn test_rename_fields_explicit_mapping_wins() {
+    let card: AccountCard = legacy().into();
+    assert_eq!(
+        card,
+        AccountCard {
+            name:  "ADA".to_string(),
+            email: "ada@example.com".to_string(),
+        }
+    );
+}
@@ -0,0 +1,102 @@
+//! Tests for `#[relate(required)]` on `Option` source fields.
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone)]
+struct ApiUser {
+    id:    Option<i32>,
+    email: Option<String>,
+    name:  String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(ApiUser)]
+struct User {
+    #[relate(required)]
+    id:    i64,
+    #[relate(required)]
+    email: String,
+    name:  String,
+}
+
+#[test]
+fn test_required_present() {
+    let api = ApiUser {
+        id:    Some(7),
+        email: Some("a@b.c".to_string()),
+        name:  "Ann".to_string(),
+    };
+
+    let user: User = api.try_into().expect("all required fields present");
+
+    assert_eq!(user.id, 7);
+    assert_eq!(user.email, "a@b.c");
+    assert_eq!(user.name, "Ann");
+}
+
+#[test]
+fn test_required_missing_names_field() {
+    let api = ApiUser {
+        id:    Some(7),
+        email: None,
+        name:  "Ann".to_string(),
+    };
+
+    let result: Result<User, ConversionError> = api.try_into();
+
+    assert!(matches!(
+        result,
+        Err(ConversionError::MissingField("email"))
+    ));
+}
+
+#[test]
+fn test_required_from_ref() {
+    let api = ApiUser {
+        id:    None,
+        email: Some("a@b.c".to_string()),
+        name:  "Ann".to_string(),
+    };
+
+    let result: Result<User, ConversionError> = (&api).try_into();
+
+    assert!(matches!(result, Err(ConversionError::MissingField("id"))));
+    // Source still usable
+    assert_eq!(api.email.as_deref(), Some("a@b.c"));
+}
+
+// Custom error types receive the missing-field error through `From`
+mod custom_error {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum MyError {
+        Missing(String),
+    }
+
+    impl From<ConversionError> for MyError {
+        fn from(e: ConversionError) -> Self { MyError::Missing(e.to_string()) }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        port: Option<u16>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Source, error = MyError)]
+    struct Target {
+        #[relate(required)]
+        port: u32,
+    }
+
+    #[test]
+    fn test_required_custom_error() {
+        let result: Result<Target, MyError> = Source { port: None }.try_into();
+
+        assert_eq!(
+            result,
+            Err(MyError::Missing("missing required field: port".to_string()))
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Tests for `#[relate(skip_if = ...)]` conditional defaults.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Account {
+    balance:    i64,
+    nickname:   String,
+    is_deleted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Account)]
+struct AccountDto {
+    #[relate(skip_if = .is_deleted, default = -1)]
+    balance:  i64,
+    #[relate(skip_if = .is_deleted)]
+    nickname: String,
+}
+
+fn account(is_deleted: bool) -> Account {
+    Account {
+        balance: 250,
+        nickname: "sam".to_string(),
+        is_deleted,
+    }
+}
+
+#[test]
+fn test_condition_false_reads_source() {
+    let dto: AccountDto = account(false).into();
+    assert_eq!(
+        dto,
+        AccountDto {
+            balance:  250,
+            nickname: "sam".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_condition_true_uses_fallback() {
+    let dto: AccountDto = account(true).into();
+    assert_eq!(
+        dto,
+        AccountDto {
+            balance:  -1,
+            nickname: String::new(),
+        }
+    );
+}
+
+#[test]
+fn test_from_ref() {
+    let source = account(true);
+    let dto: AccountDto = (&source).into();
+    assert_eq!(dto.balance, -1);
+    // Original still usable
+    assert_eq!(source.nickname, "sam");
+}
+
+#[derive(Debug, Clone)]
+struct Label {
+    text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Label)]
+struct LabelDto {
+    // `_` refers to the same-named source field
+    #[relate(skip_if = _.is_empty(), default = "(none)".to_string())]
+    text: String,
+}
+
+#[test]
+fn test_condition_on_same_field() {
+    let empty: LabelDto = Label {
+        text: String::new(),
+    }
+    .into();
+    let filled: LabelDto = Label {
+        text: "tag".to_string(),
+    }
+    .into();
+
+    assert_eq!(empty.text, "(none)");
+    assert_eq!(filled.text, "tag");
+}
@@ -371,3 +371,48 @@ mod mixed_syntax {
         assert_eq!(target.nested_value, 42);
     }
 }
+
+// =============================================================================
+// Whole Source: `$src`
+// =============================================================================
+
+mod whole_source {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Order {
+        id:    u32,
+        items: Vec<u32>,
+    }
+
+    impl Order {
+        fn total(&self) -> u32 { self.items.iter().sum() }
+    }
+
+    fn describe(order: &Order) -> String { format!("#{}: {} items", order.id, order.items.len()) }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Order)]
+    struct OrderView {
+        items:   Vec<u32>,
+        #[relate(with = describe($src))]
+        summary: String,
+        #[relate(with = $src.total())]
+        total:   u32,
+    }
+
+    #[test]
+    fn test_whole_source() {
+        let order = Order {
+            id:    7,
+            items: vec![2, 3],
+        };
+
+        let from_ref: OrderView = (&order).into();
+        let owned: OrderView = order.into();
+
+        assert_eq!(owned.summary, "#7: 2 items");
+        assert_eq!(owned.total, 5);
+        assert_eq!(owned, from_ref);
+    }
+}
@@ -0,0 +1,76 @@
+//! Tests for `#[relate(Source, strip_prefix = "...")]` prefixed source fields.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct DbUser {
+    db_id:    i64,
+    db_name:  String,
+    db_email: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbUser, both, strip_prefix = "db_")]
+struct User {
+    id:    i64,
+    name:  String,
+    email: Option<String>,
+}
+
+fn db_user() -> DbUser {
+    DbUser {
+        db_id:    1,
+        db_name:  "Ada".to_string(),
+        db_email: None,
+    }
+}
+
+#[test]
+fn test_strip_prefix() {
+    let user: User = db_user().into();
+    assert_eq!(
+        user,
+        User {
+            id:    1,
+            name:  "Ada".to_string(),
+            email: None,
+        }
+    );
+}
+
+#[test]
+fn test_strip_prefix_from_ref() {
+    let source = db_user();
+    let user: User = (&source).into();
+    assert_eq!(user.name, "Ada");
+}
+
+#[test]
+fn test_strip_prefix_reverse() {
+    let db: DbUser = User::from(db_user()).into();
+    assert_eq!(db, db_user());
+}
+
+// Fields with their own mapping ignore the prefix
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(DbUser, strip_prefix = "db_")]
+struct UserSummary {
+    id:      i64,
+    #[relate(_.to_uppercase())]
+    db_name: String,
+    #[relate(default)]
+    email:   Option<String>,
+}
+
+#[test]
+fn test_strip_prefix_explicit_mapping_wins() {
+    let summary: UserSummary = db_user().into();
+    assert_eq!(
+        summary,
+        UserSummary {
+            id:      1,
+            db_name: "ADA".to_string(),
+            email:   None,
+        }
+    );
+}
@@ -0,0 +1,79 @@
+//! Tests for `@field` references to other target fields in `with` expressions.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Person {
+    first: String,
+    last:  String,
+    age:   u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Person)]
+struct Badge {
+    #[relate(with = format!("[{}]", @full))]
+    label: String,
+    #[relate(with = format!("{} {}", @first, @last))]
+    full:  String,
+    first: String,
+    #[relate(_.to_uppercase())]
+    last:  String,
+    age:   u8,
+    #[relate(with = @age >= 18)]
+    adult: bool,
+}
+
+fn person() -> Person {
+    Person {
+        first: "Ada".to_string(),
+        last:  "Lovelace".to_string(),
+        age:   36,
+    }
+}
+
+#[test]
+fn test_target_refs() {
+    let badge: Badge = person().into();
+    assert_eq!(
+        badge,
+        Badge {
+            label: "[Ada LOVELACE]".to_string(),
+            full:  "Ada LOVELACE".to_string(),
+            first: "Ada".to_string(),
+            last:  "LOVELACE".to_string(),
+            age:   36,
+            adult: true,
+        }
+    );
+}
+
+#[test]
+fn test_target_refs_from_ref() {
+    let source = person();
+    let badge: Badge = (&source).into();
+    assert_eq!(badge.label, "[Ada LOVELACE]");
+    assert_eq!(source.first, "Ada");
+}
+
+// `@` after an identifier is still a pattern binding
+#[derive(Debug, Clone)]
+struct Score {
+    points: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Score)]
+struct Grade {
+    #[relate(with = match .points { p @ 90.. => p, low => low / 2 })]
+    points: u32,
+}
+
+#[test]
+fn test_pattern_binding_untouched() {
+    let grade: Grade = Score { points: 95 }.into();
+    assert_eq!(grade.points, 95);
+
+    let grade: Grade = Score { points: 40 }.into();
+    assert_eq!(grade.points, 20);
+}
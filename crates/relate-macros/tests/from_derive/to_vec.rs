@@ -0,0 +1,46 @@
+//! Tests for `#[relate(vec)]` copying array and slice fields into `Vec`
+//! targets.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Packet {
+    header:  [u8; 4],
+    payload: &'static [u16],
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Packet)]
+struct PacketDto {
+    #[relate(vec)]
+    header:  Vec<u8>,
+    #[relate(vec)]
+    payload: Vec<u16>,
+}
+
+fn packet() -> Packet {
+    Packet {
+        header:  [0xCA, 0xFE, 0xBA, 0xBE],
+        payload: &[1, 2, 3],
+    }
+}
+
+#[test]
+fn test_array_and_slice_to_vec() {
+    let dto: PacketDto = packet().into();
+    assert_eq!(
+        dto,
+        PacketDto {
+            header:  vec![0xCA, 0xFE, 0xBA, 0xBE],
+            payload: vec![1, 2, 3],
+        }
+    );
+}
+
+#[test]
+fn test_array_to_vec_from_ref() {
+    let source = packet();
+    let dto: PacketDto = (&source).into();
+    assert_eq!(dto.header, source.header.to_vec());
+    assert_eq!(dto.payload, vec![1, 2, 3]);
+}
@@ -151,3 +151,172 @@ mod nested_field_access {
         assert_eq!(flat.value, 42);
     }
 }
+
+// Test tuple fields built from several source fields: `with = (.x, .y)`
+mod tuple_fields {
+    use relate::Relate;
+
+    #[derive(Debug, Clone)]
+    struct Contact {
+        first: String,
+        last:  String,
+        lat:   f64,
+        lon:   f64,
+        alt:   f64,
+    }
+
+    // The tuple binding is evaluated before `first` is moved, so it clones it
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Contact)]
+    struct Card {
+        #[relate(with = (.first, .last))]
+        name:     (String, String),
+        #[relate(with = (.lat, .lon, .alt))]
+        position: (f64, f64, f64),
+        first:    String,
+    }
+
+    #[test]
+    fn test_tuple_fields() {
+        let contact = Contact {
+            first: "Ada".to_string(),
+            last:  "Lovelace".to_string(),
+            lat:   51.5,
+            lon:   -0.1,
+            alt:   11.0,
+        };
+
+        let from_ref: Card = (&contact).into();
+        let owned: Card = contact.into();
+
+        assert_eq!(owned.name, ("Ada".to_string(), "Lovelace".to_string()));
+        assert_eq!(owned.position, (51.5, -0.1, 11.0));
+        assert_eq!(owned.first, "Ada");
+        assert_eq!(owned, from_ref);
+    }
+}
+
+// Test qualified paths and turbofish `_` (an inferred type, not the field)
+mod qualified_paths {
+    use relate::Relate;
+
+    #[derive(Debug, Clone)]
+    struct Reading {
+        raw:     i32,
+        samples: Vec<u8>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Reading)]
+    struct Normalized {
+        #[relate(with = <i64 as From<i32>>::from(.raw))]
+        raw:     i64,
+        #[relate(_.iter().map(|&s| u16::from(s)).collect::<Vec<_>>())]
+        samples: Vec<u16>,
+    }
+
+    #[test]
+    fn test_qualified_paths() {
+        let reading = Reading {
+            raw:     -5,
+            samples: vec![1, 2],
+        };
+        let expected = Normalized {
+            raw:     -5,
+            samples: vec![1, 2],
+        };
+
+        assert_eq!(Normalized::from(&reading), expected);
+        assert_eq!(Normalized::from(reading), expected);
+    }
+}
+
+// Test `Result` source fields: `.ok()`, `.map_err(..)`, `.unwrap_or_else(..)`
+mod result_fields {
+    use relate::Relate;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct LookupError(String);
+
+    impl From<String> for LookupError {
+        fn from(message: String) -> Self { Self(message) }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Lookup {
+        score:  Result<u32, String>,
+        owner:  Result<String, String>,
+        region: Result<String, String>,
+        extra:  Result<u32, String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Lookup)]
+    struct Resolved {
+        #[relate(_.ok())]
+        score:  Option<u32>,
+        #[relate(_.map_err(Into::into))]
+        owner:  Result<String, LookupError>,
+        #[relate(_.unwrap_or_else(|e| "unknown (".to_string() + &e + ")"))]
+        region: String,
+        #[relate(.extra.ok().map_or(0, |x| x * 2))]
+        bonus:  u32,
+    }
+
+    #[test]
+    fn test_result_fields_owned_and_ref() {
+        let lookup = Lookup {
+            score:  Ok(7),
+            owner:  Err("no owner".to_string()),
+            region: Err("offline".to_string()),
+            extra:  Ok(4),
+        };
+        let expected = Resolved {
+            score:  Some(7),
+            owner:  Err(LookupError("no owner".to_string())),
+            region: "unknown (offline)".to_string(),
+            bonus:  8,
+        };
+
+        assert_eq!(Resolved::from(&lookup), expected);
+        assert_eq!(Resolved::from(lookup), expected);
+    }
+}
+
+// Test constant `with` expressions that don't read the source
+mod constant_with {
+    use relate::Relate;
+
+    #[derive(Debug, Clone)]
+    struct Draft {
+        title: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Draft)]
+    struct Published {
+        title:   String,
+        #[relate(with = String::from("v1"))]
+        version: String,
+        #[relate(with = Vec::new())]
+        tags:    Vec<String>,
+        #[relate(with = 3 * 7)]
+        stars:   u32,
+    }
+
+    #[test]
+    fn test_constant_with_owned_and_ref() {
+        let draft = Draft {
+            title: "Notes".to_string(),
+        };
+        let expected = Published {
+            title:   "Notes".to_string(),
+            version: "v1".to_string(),
+            tags:    Vec::new(),
+            stars:   21,
+        };
+
+        assert_eq!(Published::from(&draft), expected);
+        assert_eq!(Published::from(draft), expected);
+    }
+}
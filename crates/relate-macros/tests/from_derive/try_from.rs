@@ -491,3 +491,375 @@ mod closure_fallible {
         assert_eq!(target.data, 777);
     }
 }
+
+// =============================================================================
+// Fallible Collection Map
+// =============================================================================
+
+mod fallible_collection {
+    use std::collections::HashMap;
+
+    use relate::ConversionError;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct RawPorts {
+        ports:   Vec<String>,
+        weights: HashMap<String, String>,
+    }
+
+    // A trailing `?` in `[...]` collects into `Result` and triggers TryFrom
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawPorts)]
+    struct Ports {
+        #[relate([_.parse()?])]
+        ports:   Vec<u16>,
+        #[relate(map[_.parse()?])]
+        weights: HashMap<String, u8>,
+    }
+
+    fn raw(ports: &[&str]) -> RawPorts {
+        RawPorts {
+            ports:   ports.iter().map(ToString::to_string).collect(),
+            weights: HashMap::from([("a".to_string(), "3".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_fallible_collection_success() {
+        let ports: Ports = raw(&["80", "443"]).try_into().expect("should parse");
+
+        assert_eq!(ports.ports, vec![80, 443]);
+        assert_eq!(ports.weights["a"], 3);
+    }
+
+    #[test]
+    fn test_fallible_collection_failure() {
+        let result: Result<Ports, ConversionError> = (&raw(&["80", "http"])).try_into();
+        assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+    }
+
+    #[test]
+    fn test_fallible_map_values_failure() {
+        let mut source = raw(&["80"]);
+        source.weights.insert("b".to_string(), "heavy".to_string());
+
+        let result: Result<Ports, ConversionError> = source.try_into();
+        assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+    }
+
+    #[test]
+    fn test_fallible_collection_empty() {
+        let ports: Ports = raw(&[]).try_into().expect("should parse");
+        assert!(ports.ports.is_empty());
+    }
+}
+
+// =============================================================================
+// Per-Field Error Mapping
+// =============================================================================
+
+mod per_field_map_err {
+    use std::num::ParseIntError;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct RawConfig {
+        port:    String,
+        retries: String,
+        tags:    Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum ConfigError {
+        Port(String),
+        Retries(String),
+        Tag(String),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawConfig, error = ConfigError)]
+    struct Config {
+        #[relate(_.parse()?, map_err = |e: ParseIntError| ConfigError::Port(e.to_string()))]
+        port:    u16,
+        #[relate(_.parse()?, map_err = |e: ParseIntError| ConfigError::Retries(e.to_string()))]
+        retries: u8,
+        #[relate([_.parse()?], map_err = |e: ParseIntError| ConfigError::Tag(e.to_string()))]
+        tags:    Vec<u32>,
+    }
+
+    fn raw(port: &str, retries: &str, tag: &str) -> RawConfig {
+        RawConfig {
+            port:    port.to_string(),
+            retries: retries.to_string(),
+            tags:    vec![tag.to_string()],
+        }
+    }
+
+    #[test]
+    fn test_map_err_success() {
+        let config: Config = raw("8080", "3", "7").try_into().unwrap();
+
+        assert_eq!(config.port, 8080);
+        assert_eq!(config.retries, 3);
+        assert_eq!(config.tags, vec![7]);
+    }
+
+    #[test]
+    fn test_map_err_distinguishes_fields() {
+        let result: Result<Config, ConfigError> = raw("http", "3", "7").try_into();
+        assert!(matches!(result, Err(ConfigError::Port(_))));
+
+        let result: Result<Config, ConfigError> = (&raw("80", "many", "7")).try_into();
+        assert!(matches!(result, Err(ConfigError::Retries(_))));
+
+        let result: Result<Config, ConfigError> = raw("80", "3", "x").try_into();
+        assert!(matches!(result, Err(ConfigError::Tag(_))));
+    }
+}
+
+// =============================================================================
+// Wrapped Field Errors: `wraps(ParseIntError, ..)`
+// =============================================================================
+
+mod wrapped_errors {
+    use std::num::{ParseFloatError, ParseIntError};
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct RawReading {
+        count: String,
+        ratio: String,
+        tags:  Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum ReadingError {
+        Int(ParseIntError),
+        Float(ParseFloatError),
+    }
+
+    impl From<ParseIntError> for ReadingError {
+        fn from(e: ParseIntError) -> Self { Self::Int(e) }
+    }
+
+    impl From<ParseFloatError> for ReadingError {
+        fn from(e: ParseFloatError) -> Self { Self::Float(e) }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawReading, error = ReadingError, wraps(ParseIntError, ParseFloatError))]
+    struct Reading {
+        #[relate(_.parse()?)]
+        count: u32,
+        #[relate(_.parse()?)]
+        ratio: f64,
+        #[relate([_.parse()?])]
+        tags:  Vec<u8>,
+    }
+
+    fn raw(count: &str, ratio: &str) -> RawReading {
+        RawReading {
+            count: count.to_string(),
+            ratio: ratio.to_string(),
+            tags:  vec!["1".to_string(), "2".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_wraps_success() {
+        let reading: Reading = raw("3", "0.5").try_into().unwrap();
+        assert_eq!(
+            reading,
+            Reading {
+                count: 3,
+                ratio: 0.5,
+                tags:  vec![1, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn test_wraps_converts_each_error() {
+        let result: Result<Reading, ReadingError> = raw("x", "0.5").try_into();
+        assert!(matches!(result, Err(ReadingError::Int(_))));
+
+        let result: Result<Reading, ReadingError> = (&raw("3", "half")).try_into();
+        assert!(matches!(result, Err(ReadingError::Float(_))));
+    }
+}
+
+// =============================================================================
+// `?` Before the End of the Expression
+// =============================================================================
+
+mod mid_expression_question_mark {
+    use relate::ConversionError;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct RawConfig {
+        port: String,
+        host: String,
+    }
+
+    // The `?` sits in the middle, so the normalized value is still a `String`
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawConfig)]
+    struct Config {
+        #[relate(_.parse::<u16>()?.to_string())]
+        port: String,
+        host: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawConfig, collect_errors)]
+    struct CollectedConfig {
+        #[relate(_.trim().parse::<u16>()?.to_string())]
+        port: String,
+        host: String,
+    }
+
+    fn raw(port: &str) -> RawConfig {
+        RawConfig {
+            port: port.to_string(),
+            host: "localhost".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_mid_question_mark_success() {
+        let config: Config = raw("08080").try_into().unwrap();
+        assert_eq!(config.port, "8080");
+
+        let config: Config = (&raw("443")).try_into().unwrap();
+        assert_eq!(config.port, "443");
+    }
+
+    #[test]
+    fn test_mid_question_mark_failure() {
+        let result: Result<Config, ConversionError> = raw("http").try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_mid_question_mark_collected() {
+        let config: CollectedConfig = raw(" 80 ").try_into().unwrap();
+        assert_eq!(config.port, "80");
+
+        let result: Result<CollectedConfig, ConversionError> = raw("http").try_into();
+        let Err(ConversionError::Multiple(errors)) = result else {
+            panic!("expected collected errors");
+        };
+        assert_eq!(errors.len(), 1);
+    }
+}
+
+// =============================================================================
+// Block Expressions: `with = { let ..; expr? }`
+// =============================================================================
+
+mod block_expression {
+    use relate::ConversionError;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct RawLimits {
+        min: String,
+        max: String,
+    }
+
+    // The `?` inside the block marks the field fallible
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawLimits)]
+    struct Limits {
+        #[relate(with = { let trimmed = .min.trim(); trimmed.parse::<u32>()? })]
+        min: u32,
+        #[relate(with = {
+            let max: u32 = _.trim().parse()?;
+            max.max(1)
+        })]
+        max: u32,
+    }
+
+    fn raw(min: &str, max: &str) -> RawLimits {
+        RawLimits {
+            min: min.to_string(),
+            max: max.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_block_success() {
+        let limits: Limits = raw(" 2 ", "0").try_into().unwrap();
+        assert_eq!(limits, Limits { min: 2, max: 1 });
+
+        let limits: Limits = (&raw("3", " 9")).try_into().unwrap();
+        assert_eq!(limits, Limits { min: 3, max: 9 });
+    }
+
+    #[test]
+    fn test_block_failure() {
+        let result: Result<Limits, ConversionError> = raw("2", "many").try_into();
+        assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+    }
+}
+
+// =============================================================================
+// Fallible Function: `try_into = path`
+// =============================================================================
+
+mod try_into_fn {
+    use std::num::ParseIntError;
+
+    use relate::ConversionError;
+
+    use super::*;
+
+    fn parse_port(port: impl AsRef<str>) -> Result<u16, ParseIntError> { port.as_ref().parse() }
+
+    #[derive(Debug, Clone)]
+    struct RawConfig {
+        port: String,
+        host: String,
+    }
+
+    // No explicit `try_from`: `try_into` alone upgrades to TryFrom
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawConfig)]
+    struct Config {
+        #[relate(try_into = parse_port)]
+        port: u16,
+        host: String,
+    }
+
+    fn raw(port: &str) -> RawConfig {
+        RawConfig {
+            port: port.to_string(),
+            host: "localhost".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_try_into_success() {
+        let config: Config = raw("8080").try_into().unwrap();
+        assert_eq!(config.port, 8080);
+
+        // The ref impl passes `&src.port`
+        let source = raw("443");
+        let config: Config = (&source).try_into().unwrap();
+        assert_eq!(config.port, 443);
+        assert_eq!(source.port, "443");
+    }
+
+    #[test]
+    fn test_try_into_failure() {
+        let result: Result<Config, ConversionError> = raw("http").try_into();
+        assert!(result.is_err());
+    }
+}
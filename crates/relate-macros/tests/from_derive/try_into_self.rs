@@ -0,0 +1,103 @@
+//! Tests for `try_into_self`, a `try_into_<target>` method on the source.
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone)]
+struct RawPort {
+    number: String,
+}
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawPort, try_from, try_into_self)]
+struct Port {
+    #[relate(_.parse()?)]
+    number: u16,
+}
+
+#[test]
+fn test_try_into_named_target() {
+    let raw = RawPort {
+        number: "8080".to_string(),
+    };
+    assert_eq!(raw.try_into_port().unwrap(), Port { number: 8080 });
+
+    let raw = RawPort {
+        number: "eighty".to_string(),
+    };
+    assert!(matches!(
+        raw.try_into_port(),
+        Err(ConversionError::ParseInt(_))
+    ));
+}
+
+// A generic target is named by the method, so no annotation is needed
+#[derive(Debug, Clone)]
+struct RawMeasurement<T> {
+    value: T,
+    unit:  String,
+}
+
+#[derive(Debug, PartialEq)]
+enum UnitError {
+    Empty,
+}
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawMeasurement<T>, error = UnitError, try_into_self)]
+struct Measurement<T: Clone> {
+    value: T,
+    #[relate(with = Some(.unit.clone()).filter(|u| !u.is_empty()).ok_or(UnitError::Empty)?)]
+    unit:  String,
+}
+
+#[test]
+fn test_try_into_generic_target() {
+    let raw = RawMeasurement {
+        value: 2.5f32,
+        unit:  "kg".to_string(),
+    };
+    let measurement = raw.clone().try_into_measurement().unwrap();
+    assert_eq!(measurement.value, 2.5);
+
+    let raw = RawMeasurement {
+        value: 1u8,
+        unit:  String::new(),
+    };
+    assert_eq!(raw.try_into_measurement(), Err(UnitError::Empty));
+}
+
+// With `ref_only` the method borrows the source
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawPort, try_from, ref_only, try_into_self)]
+struct PortNumber {
+    #[relate(.number.parse()?)]
+    value: u16,
+}
+
+#[test]
+fn test_try_into_by_ref() {
+    let raw = RawPort {
+        number: "22".to_string(),
+    };
+    assert_eq!(
+        raw.try_into_port_number().unwrap(),
+        PortNumber { value: 22 }
+    );
+    assert_eq!(raw.number, "22");
+}
+
+// An acronym is one word of the method name: `try_into_http_port`
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawPort, try_from, try_into_self)]
+struct HTTPPort {
+    #[relate(_.parse()?)]
+    number: u16,
+}
+
+#[test]
+fn test_try_into_acronym_target() {
+    let raw = RawPort {
+        number: "443".to_string(),
+    };
+    assert_eq!(raw.try_into_http_port().unwrap(), HTTPPort { number: 443 });
+}
@@ -0,0 +1,93 @@
+//! Tests for converting between structs and plain tuples
+//! (`#[relate((String, i32))]`).
+
+use relate::Relate;
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate((String, i32), both)]
+struct Setting {
+    name:  String,
+    value: i32,
+}
+
+#[test]
+fn test_from_tuple() {
+    let setting: Setting = ("retries".to_string(), 3).into();
+    assert_eq!(
+        setting,
+        Setting {
+            name:  "retries".to_string(),
+            value: 3,
+        }
+    );
+}
+
+#[test]
+fn test_from_tuple_ref() {
+    let row = ("timeout".to_string(), 30);
+    let setting: Setting = (&row).into();
+    assert_eq!(setting.name, "timeout");
+    assert_eq!(row.1, 30);
+}
+
+#[test]
+fn test_into_tuple() {
+    let setting = Setting {
+        name:  "retries".to_string(),
+        value: 3,
+    };
+    let row: (String, i32) = (&setting).into();
+    assert_eq!(row, ("retries".to_string(), 3));
+    let row: (String, i32) = setting.into();
+    assert_eq!(row, ("retries".to_string(), 3));
+}
+
+// Transforms read the same-position element, and defaults skip it
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate((String, u32, bool))]
+struct Entry {
+    #[relate(_.to_uppercase())]
+    key:     String,
+    count:   u32,
+    #[relate(.2)]
+    enabled: bool,
+    #[relate(default)]
+    notes:   Vec<String>,
+}
+
+#[test]
+fn test_from_tuple_with_transforms() {
+    let entry: Entry = ("db".to_string(), 2, true).into();
+    assert_eq!(
+        entry,
+        Entry {
+            key:     "DB".to_string(),
+            count:   2,
+            enabled: true,
+            notes:   Vec::new(),
+        }
+    );
+}
+
+// A defaulted field in the middle takes no position
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate((String, i32))]
+struct Row {
+    name:  String,
+    #[relate(default)]
+    extra: u8,
+    value: i32,
+}
+
+#[test]
+fn test_from_tuple_default_in_middle() {
+    let row: Row = ("limit".to_string(), 9).into();
+    assert_eq!(
+        row,
+        Row {
+            name:  "limit".to_string(),
+            extra: 0,
+            value: 9,
+        }
+    );
+}
@@ -0,0 +1,122 @@
+//! Tests for deriving Relate on tuple structs and newtypes.
+
+use relate::Relate;
+
+// =============================================================================
+// Newtypes
+// =============================================================================
+
+mod newtype {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct DbUserId(i64);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Relate)]
+    #[relate(DbUserId, both)]
+    struct UserId(i64);
+
+    #[derive(Debug, Clone)]
+    struct RawName(String);
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawName)]
+    struct Name(String);
+
+    #[test]
+    fn test_newtype_forwards_inner() {
+        let id: UserId = DbUserId(42).into();
+        assert_eq!(id, UserId(42));
+    }
+
+    #[test]
+    fn test_newtype_round_trip() {
+        let db: DbUserId = UserId(7).into();
+        assert_eq!(db.0, 7);
+    }
+
+    #[test]
+    fn test_newtype_from_ref_clones() {
+        let raw = RawName("ada".to_string());
+        let name: Name = (&raw).into();
+
+        assert_eq!(name, Name("ada".to_string()));
+        assert_eq!(raw.0, "ada");
+    }
+}
+
+// =============================================================================
+// Positional Mapping and Element Attributes
+// =============================================================================
+
+mod positional {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct RawPoint(String, i32, Vec<u8>);
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawPoint)]
+    struct Point(
+        #[relate(_.parse()?)] f64,
+        #[relate(into, copy)] i64,
+        #[relate([u32::from(*_)])] Vec<u32>,
+    );
+
+    #[test]
+    fn test_tuple_struct_attributes() {
+        let point: Point = RawPoint("1.5".to_string(), -3, vec![1, 2])
+            .try_into()
+            .expect("should parse");
+        assert_eq!(point, Point(1.5, -3, vec![1, 2]));
+    }
+
+    #[test]
+    fn test_tuple_struct_failure_from_ref() {
+        let raw = RawPoint("x".to_string(), 0, vec![]);
+        let result: Result<Point, relate::ConversionError> = (&raw).try_into();
+        assert!(result.is_err());
+    }
+
+    // Elements can read other elements with `.N`
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(RawPoint)]
+    struct Summary(
+        #[relate(with = format!("{}@{}", .0, .1))] String,
+        #[relate(default)] u8,
+    );
+
+    #[test]
+    fn test_tuple_index_access() {
+        let summary: Summary = RawPoint("a".to_string(), 9, vec![]).into();
+        assert_eq!(summary, Summary("a@9".to_string(), 0));
+    }
+}
+
+// =============================================================================
+// Tuple Struct from Named Struct
+// =============================================================================
+
+mod from_named {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Pair {
+        left:  String,
+        right: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Relate)]
+    #[relate(Pair)]
+    struct PairTuple(#[relate(with = .left)] String, #[relate(with = .right)] u32);
+
+    #[test]
+    fn test_named_to_tuple() {
+        let pair = Pair {
+            left:  "l".to_string(),
+            right: 2,
+        };
+        let tuple: PairTuple = (&pair).into();
+        assert_eq!(tuple, PairTuple("l".to_string(), 2));
+    }
+}
@@ -0,0 +1,64 @@
+//! Tests for converting from a unit struct source.
+
+use relate::Relate;
+
+#[derive(Debug, Clone, Copy)]
+struct Empty;
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Empty)]
+struct Config {
+    #[relate(default)]
+    retries: u32,
+    #[relate(default = 30)]
+    timeout: u64,
+    #[relate(default = "localhost".to_string())]
+    host:    String,
+}
+
+#[test]
+fn test_unit_source_owned() {
+    let config = Config::from(Empty);
+    assert_eq!(
+        config,
+        Config {
+            retries: 0,
+            timeout: 30,
+            host:    "localhost".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_unit_source_ref() {
+    let empty = Empty;
+    let config: Config = (&empty).into();
+    assert_eq!(config.timeout, 30);
+    assert_eq!(config.host, "localhost");
+}
+
+/// Test a fallible conversion from a unit struct
+mod fallible {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Relate)]
+    #[relate(Empty, error = std::num::ParseIntError)]
+    struct Limits {
+        #[relate(default = "64".parse::<u16>()?)]
+        max_connections: u16,
+        #[relate(default)]
+        burst:           u16,
+    }
+
+    #[test]
+    fn test_unit_source_try_from() {
+        let limits = Limits::try_from(Empty).unwrap();
+        assert_eq!(
+            limits,
+            Limits {
+                max_connections: 64,
+                burst:           0,
+            }
+        );
+    }
+}
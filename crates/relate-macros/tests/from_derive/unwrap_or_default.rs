@@ -0,0 +1,63 @@
+//! Tests for `#[relate(unwrap_or_default)]` on `Option` source fields.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Settings {
+    retries: Option<u32>,
+    theme:   Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Settings)]
+struct ResolvedSettings {
+    #[relate(unwrap_or_default)]
+    retries: u32,
+    #[relate(unwrap_or_default)]
+    theme:   String,
+}
+
+#[test]
+fn test_some_values() {
+    let settings = Settings {
+        retries: Some(3),
+        theme:   Some("dark".to_string()),
+    };
+    let resolved: ResolvedSettings = settings.into();
+    assert_eq!(
+        resolved,
+        ResolvedSettings {
+            retries: 3,
+            theme:   "dark".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_none_values() {
+    let settings = Settings {
+        retries: None,
+        theme:   None,
+    };
+    let resolved: ResolvedSettings = settings.into();
+    assert_eq!(
+        resolved,
+        ResolvedSettings {
+            retries: 0,
+            theme:   String::new(),
+        }
+    );
+}
+
+#[test]
+fn test_from_ref() {
+    let settings = Settings {
+        retries: None,
+        theme:   Some("light".to_string()),
+    };
+    let resolved: ResolvedSettings = (&settings).into();
+    assert_eq!(resolved.retries, 0);
+    assert_eq!(resolved.theme, "light");
+    // Original still usable
+    assert_eq!(settings.theme.as_deref(), Some("light"));
+}
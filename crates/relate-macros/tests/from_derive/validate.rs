@@ -0,0 +1,82 @@
+//! Tests for `validate = predicate` checking the converted value.
+
+use relate::{ConversionError, Relate};
+
+#[derive(Debug, Clone)]
+struct RawRange {
+    min: String,
+    max: String,
+}
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawRange, try_from, validate = |r: &Range| r.min <= r.max)]
+struct Range {
+    #[relate(_.parse()?)]
+    min: u32,
+    #[relate(_.parse()?)]
+    max: u32,
+}
+
+fn raw(min: &str, max: &str) -> RawRange {
+    RawRange {
+        min: min.to_string(),
+        max: max.to_string(),
+    }
+}
+
+#[test]
+fn test_validate_ok() {
+    assert_eq!(
+        Range::try_from(raw("1", "5")).unwrap(),
+        Range { min: 1, max: 5 }
+    );
+    assert_eq!(
+        Range::try_from(&raw("5", "5")).unwrap(),
+        Range { min: 5, max: 5 }
+    );
+}
+
+#[test]
+fn test_validate_rejects() {
+    let err = Range::try_from(raw("9", "5")).unwrap_err();
+    assert!(matches!(err, ConversionError::Validation("Range")));
+    assert_eq!(err.to_string(), "validation failed for Range");
+}
+
+#[test]
+fn test_validate_runs_after_field_errors() {
+    let err = Range::try_from(&raw("x", "5")).unwrap_err();
+    assert!(matches!(err, ConversionError::ParseInt(_)));
+}
+
+// A predicate path, with collected errors and a context label
+#[derive(Debug, Clone)]
+struct RawPort {
+    port: String,
+}
+
+fn is_unprivileged(port: &Port) -> bool { port.port >= 1024 }
+
+#[derive(Debug, PartialEq, Relate)]
+#[relate(RawPort, collect_errors, context = "parsing Port", validate = is_unprivileged)]
+struct Port {
+    #[relate(_.parse()?)]
+    port: u16,
+}
+
+#[test]
+fn test_validate_path_with_context() {
+    let raw = RawPort {
+        port: "8080".to_string(),
+    };
+    assert_eq!(Port::try_from(raw).unwrap(), Port { port: 8080 });
+
+    let raw = RawPort {
+        port: "80".to_string(),
+    };
+    let ConversionError::Context { context, source } = Port::try_from(&raw).unwrap_err() else {
+        panic!("expected a context error");
+    };
+    assert_eq!(context, "parsing Port");
+    assert!(matches!(*source, ConversionError::Validation("Port")));
+}
@@ -0,0 +1,64 @@
+//! Tests for `#[relate(Source, where = "...")]` extra impl bounds.
+
+use std::marker::PhantomData;
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct Measurement<T> {
+    value:   T,
+    samples: Vec<T>,
+}
+
+// The struct itself has no bounds; the field transforms need `T: Into<U>`
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Measurement<T>, where = "T: Clone + Into<U>")]
+struct Reading<T, U> {
+    #[relate(_.clone().into())]
+    value:   U,
+    #[relate([_.clone().into()])]
+    samples: Vec<U>,
+    #[relate(default)]
+    marker:  PhantomData<T>,
+}
+
+fn measurement() -> Measurement<u8> {
+    Measurement {
+        value:   3,
+        samples: vec![1, 2],
+    }
+}
+
+#[test]
+fn test_where_bounds() {
+    let reading: Reading<u8, u32> = measurement().into();
+
+    assert_eq!(reading.value, 3);
+    assert_eq!(reading.samples, vec![1, 2]);
+}
+
+#[test]
+fn test_where_bounds_from_ref() {
+    let source = measurement();
+    let reading: Reading<u8, f64> = (&source).into();
+    assert_eq!(reading.value, 3.0);
+}
+
+// Bounds merge with an existing where clause
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(Measurement<T>, where = "T: Into<U>", where = "U: Default")]
+struct Latest<T, U>
+where
+    T: Clone,
+{
+    #[relate(_.clone().into())]
+    value:  U,
+    #[relate(default)]
+    marker: PhantomData<T>,
+}
+
+#[test]
+fn test_where_bounds_merge() {
+    let latest: Latest<u8, i64> = measurement().into();
+    assert_eq!(latest.value, 3);
+}
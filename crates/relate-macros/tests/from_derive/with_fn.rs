@@ -0,0 +1,63 @@
+//! Tests for `#[relate(with_fn = path)]` function application.
+
+use std::borrow::Borrow;
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct User {
+    name:  String,
+    email: String,
+    tags:  Vec<String>,
+}
+
+mod normalize {
+    /// Accepts both the owned field and a reference to it.
+    pub fn email(email: impl AsRef<str>) -> String { email.as_ref().to_lowercase() }
+}
+
+fn count<T: Borrow<Vec<String>>>(tags: T) -> usize { tags.borrow().len() }
+
+fn shout(name: String) -> String { name.to_uppercase() }
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(User)]
+struct UserView {
+    #[relate(with_fn = shout, cloned)]
+    name:  String,
+    #[relate(with_fn = normalize::email)]
+    email: String,
+    #[relate(with_fn = count)]
+    tags:  usize,
+}
+
+fn user() -> User {
+    User {
+        name:  "ada".to_string(),
+        email: "Ada@Example.COM".to_string(),
+        tags:  vec!["a".to_string(), "b".to_string()],
+    }
+}
+
+#[test]
+fn test_with_fn() {
+    let view: UserView = user().into();
+    assert_eq!(
+        view,
+        UserView {
+            name:  "ADA".to_string(),
+            email: "ada@example.com".to_string(),
+            tags:  2,
+        }
+    );
+}
+
+#[test]
+fn test_with_fn_from_ref() {
+    let source = user();
+    let view: UserView = (&source).into();
+    assert_eq!(view.email, "ada@example.com");
+    assert_eq!(view.tags, 2);
+    // Original still usable
+    assert_eq!(source.name, "ada");
+}
@@ -0,0 +1,46 @@
+//! Tests for `#[relate(some)]` wrapping into `Option` targets.
+
+use relate::Relate;
+
+#[derive(Debug, Clone)]
+struct User {
+    id:    u32,
+    email: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Relate)]
+#[relate(User)]
+struct UserPatch {
+    #[relate(some)]
+    id:    Option<u32>,
+    #[relate(some)]
+    email: Option<String>,
+}
+
+fn user() -> User {
+    User {
+        id:    3,
+        email: "a@b.c".to_string(),
+    }
+}
+
+#[test]
+fn test_wrap_some() {
+    let patch: UserPatch = user().into();
+    assert_eq!(
+        patch,
+        UserPatch {
+            id:    Some(3),
+            email: Some("a@b.c".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_wrap_some_from_ref() {
+    let source = user();
+    let patch: UserPatch = (&source).into();
+    assert_eq!(patch.email.as_deref(), Some("a@b.c"));
+    // Original still usable
+    assert_eq!(source.email, "a@b.c");
+}
@@ -0,0 +1,21 @@
+//! Test: `debug_expand` warns with the generated code and still compiles
+
+use relate::Relate;
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Relate)]
+#[relate(Point, debug_expand)]
+struct PointDto {
+    x: i32,
+    #[relate(_ * 2)]
+    y: i32,
+}
+
+fn main() {
+    let dto = PointDto::from(Point { x: 1, y: 2 });
+    assert_eq!((dto.x, dto.y), (1, 4));
+}
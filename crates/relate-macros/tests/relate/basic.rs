@@ -102,3 +102,448 @@ mod bidirectional {
         assert_eq!(original, back);
     }
 }
+
+// Test `#![owned_only]` skipping the `&Source` impls
+mod owned_only {
+    use super::*;
+
+    // Not `Clone`: a ref impl couldn't be generated
+    #[derive(Debug, PartialEq)]
+    struct Handle(u32);
+
+    #[derive(Debug)]
+    struct Connection {
+        id:     u32,
+        handle: Handle,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Session {
+        id:     u32,
+        handle: Handle,
+    }
+
+    relate_structs! {
+        Connection ~ Session {
+            #![owned_only]
+            id;
+            handle;
+        }
+    }
+
+    #[test]
+    fn test_owned_only() {
+        let session: Session = Connection {
+            id:     1,
+            handle: Handle(9),
+        }
+        .into();
+        assert_eq!(session.handle, Handle(9));
+
+        let back: Connection = session.into();
+        assert_eq!(back.id, 1);
+    }
+}
+
+// Test `#![ref_only]` skipping the owning impls
+mod ref_only {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Article {
+        title: String,
+        words: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Teaser {
+        title: String,
+        words: u32,
+    }
+
+    relate_structs! {
+        Article ~> Teaser {
+            #![ref_only]
+            title;
+            words;
+        }
+    }
+
+    #[test]
+    fn test_ref_only() {
+        let article = Article {
+            title: "Notes".to_string(),
+            words: 900,
+        };
+        let teaser = Teaser::from(&article);
+        assert_eq!(teaser.title, article.title);
+        assert_eq!(teaser.words, 900);
+    }
+}
+
+// Test fields named like the relation options still map by identity
+mod option_named_fields {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Flags {
+        owned_only:      bool,
+        ref_only:        bool,
+        roundtrip_check: bool,
+        inline:          bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct FlagsView {
+        owned_only:      bool,
+        ref_only:        bool,
+        roundtrip_check: bool,
+        inline:          bool,
+    }
+
+    relate_structs! {
+        Flags ~> FlagsView {
+            owned_only;
+            ref_only;
+            roundtrip_check;
+            inline;
+        }
+    }
+
+    #[test]
+    fn test_option_named_fields() {
+        let flags = Flags {
+            owned_only:      true,
+            ref_only:        false,
+            roundtrip_check: true,
+            inline:          false,
+        };
+        let view = FlagsView::from(&flags);
+        assert_eq!(
+            view,
+            FlagsView {
+                owned_only:      true,
+                ref_only:        false,
+                roundtrip_check: true,
+                inline:          false,
+            }
+        );
+        assert_eq!(FlagsView::from(flags), view);
+    }
+}
+
+// Test `#![into_fn]` for a target in another crate: a free `into_<target>`
+// function
+mod foreign_target {
+    use super::*;
+
+    // Stands in for a type from another crate
+    mod other_crate {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct UserProfile {
+            pub name: String,
+            pub age:  u8,
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct LocalUser {
+        name: String,
+        age:  u8,
+    }
+
+    relate_structs! {
+        LocalUser ~ other_crate::UserProfile {
+            #![into_fn]
+            name;
+            age;
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Form {
+        name: String,
+        age:  String,
+    }
+
+    mod parsed {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct ParsedForm {
+            pub name: String,
+            pub age:  u8,
+        }
+    }
+
+    relate_structs! {
+        Form ~> parsed::ParsedForm {
+            #![ref_only]
+            #![into_fn]
+            name;
+            age: with = _.parse()?;
+        }
+    }
+
+    // An acronym is one word of the function name: `into_http_config`
+    mod http {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct HTTPConfig {
+            pub port: u16,
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Settings {
+        port: u16,
+    }
+
+    relate_structs! {
+        Settings ~> http::HTTPConfig {
+            #![into_fn]
+            port;
+        }
+    }
+
+    #[test]
+    fn test_into_fn() {
+        let user = LocalUser {
+            name: "ada".to_string(),
+            age:  36,
+        };
+        let profile = into_user_profile(user.clone());
+        assert_eq!(
+            profile,
+            other_crate::UserProfile {
+                name: "ada".to_string(),
+                age:  36,
+            }
+        );
+
+        // The backward direction is a regular `From` impl
+        let back: LocalUser = profile.into();
+        assert_eq!(back, user);
+    }
+
+    #[test]
+    fn test_try_into_fn_by_ref() {
+        let form = Form {
+            name: "bo".to_string(),
+            age:  "7".to_string(),
+        };
+        assert_eq!(into_parsed_form(&form).unwrap().age, 7);
+
+        let bad = Form {
+            age: "x".to_string(),
+            ..form
+        };
+        assert!(into_parsed_form(&bad).is_err());
+    }
+
+    #[test]
+    fn test_into_fn_acronym_target() {
+        let config = into_http_config(Settings { port: 80 });
+        assert_eq!(config, http::HTTPConfig { port: 80 });
+    }
+}
+
+// Test types in other modules of this crate, named by path
+mod module_paths {
+    use super::*;
+
+    mod db {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct User {
+            pub id:   i64,
+            pub name: String,
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Page<T> {
+            pub items: Vec<T>,
+            pub total: usize,
+        }
+    }
+
+    mod api {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct User {
+            pub id:   i64,
+            pub name: String,
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Page<T> {
+            pub items: Vec<T>,
+            pub total: usize,
+        }
+    }
+
+    relate_structs! {
+        db::User ~ api::User {
+            id;
+            name;
+        }
+
+        db::Page<T: Clone> ~> self::api::Page<T> {
+            items;
+            total;
+        }
+    }
+
+    #[test]
+    fn test_paths_on_both_sides() {
+        let user = db::User {
+            id:   1,
+            name: "ada".to_string(),
+        };
+        let api_user: api::User = (&user).into();
+        assert_eq!(api_user.name, "ada");
+
+        let back: db::User = api_user.into();
+        assert_eq!(back, user);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Account {
+        id:   i64,
+        name: String,
+    }
+
+    // A bare source next to a target path still gets `From`
+    relate_structs! {
+        Account ~> api::User {
+            id;
+            name;
+        }
+    }
+
+    #[test]
+    fn test_path_target_only() {
+        let account = Account {
+            id:   2,
+            name: "grace".to_string(),
+        };
+        let user: api::User = account.into();
+        assert_eq!(user.id, 2);
+        assert_eq!(user.name, "grace");
+    }
+
+    #[test]
+    fn test_generics_on_final_segment() {
+        let page = db::Page {
+            items: vec![1, 2],
+            total: 2,
+        };
+        let api_page: api::Page<i32> = page.into();
+        assert_eq!(api_page.items, vec![1, 2]);
+        assert_eq!(api_page.total, 2);
+    }
+}
+
+// Test `#![roundtrip_check]` generating `__relate_roundtrip`
+mod roundtrip_check {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Celsius {
+        degrees: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Reading {
+        value: i32,
+    }
+
+    relate_structs! {
+        Celsius ~ Reading {
+            #![roundtrip_check]
+            value: with = .degrees;
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Level {
+        raw: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Clamped {
+        raw: i32,
+    }
+
+    // Clamping loses information, which the roundtrip check catches
+    relate_structs! {
+        Level ~ Clamped {
+            #![roundtrip_check]
+            raw: with = _.min(10), reverse = _;
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_check() {
+        assert!(Reading::__relate_roundtrip(Celsius { degrees: -4 }));
+        assert!(Clamped::__relate_roundtrip(Level { raw: 3 }));
+        assert!(!Clamped::__relate_roundtrip(Level { raw: 30 }));
+    }
+}
+
+// Test raw identifier field names
+mod raw_identifiers {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Source {
+        r#type:   String,
+        r#struct: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        r#type:   String,
+        r#struct: u32,
+        label:    String,
+        r#enum:   u32,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            r#type;
+            r#struct: with = _ + 1;
+            label: with = .r#type;
+            r#enum: with = .r#struct;
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Row {
+        r#type:   String,
+        r#struct: u32,
+    }
+
+    relate_structs! {
+        Source ~ Row {
+            r#type;
+            r#struct;
+        }
+    }
+
+    #[test]
+    fn test_raw_identifiers() {
+        let source = Source {
+            r#type:   "widget".to_string(),
+            r#struct: 20,
+        };
+
+        let from_ref: Target = (&source).into();
+        let owned: Target = source.clone().into();
+
+        assert_eq!(owned.r#type, "widget");
+        assert_eq!(owned.r#struct, 21);
+        assert_eq!(owned.label, "widget");
+        assert_eq!(owned.r#enum, 20);
+        assert_eq!(owned, from_ref);
+
+        let row: Row = source.clone().into();
+        let back: Source = row.into();
+        assert_eq!(back, source);
+    }
+}
@@ -150,6 +150,51 @@ mod move_modifier {
     }
 }
 
+// Test `: move` on a field read several times: method calls borrow it
+// before its last use moves it
+mod move_multi_use {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        name:  String,
+        len:   usize,
+        upper: String,
+        copy:  String,
+    }
+
+    // `name` moves before the method calls in field order
+    relate_structs! {
+        Source ~> Target {
+            name: move;
+            len: with = .name.len(), move;
+            upper: with = .name.to_uppercase(), move;
+            copy: with = .name, move;
+        }
+    }
+
+    #[test]
+    fn test_move_multi_use() {
+        let source = Source {
+            name: "ada".to_string(),
+        };
+        let expected = Target {
+            name:  "ada".to_string(),
+            len:   3,
+            upper: "ADA".to_string(),
+            copy:  "ada".to_string(),
+        };
+
+        assert_eq!(Target::from(&source), expected);
+        assert_eq!(Target::from(source), expected);
+    }
+}
+
 // Test clone modifiers with transforms
 mod clone_modes_with_transform {
     use super::*;
@@ -218,3 +263,165 @@ mod clone_modes_with_field_access {
         assert_eq!(target.target_name, "test");
     }
 }
+
+// Test `: arc_clone` cloning with `Arc::clone`
+mod arc_clone {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        pool: Arc<Vec<u8>>,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Target {
+        pool: Arc<Vec<u8>>,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            pool: arc_clone;
+        }
+    }
+
+    #[test]
+    fn test_arc_clone_from_ref() {
+        let source = Source {
+            pool: Arc::new(vec![1, 2]),
+        };
+        let target: Target = (&source).into();
+        assert!(Arc::ptr_eq(&target.pool, &source.pool));
+    }
+}
+
+// Test `#![reverse_cloned]` / `#![reverse_copy]` setting the backward impls'
+// clone mode
+mod reverse_clone_modes {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq)]
+    struct Counted(u32);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Self(self.0)
+        }
+    }
+
+    // Copy, but cloning it explicitly panics
+    #[derive(Debug, PartialEq, Copy)]
+    struct Flag(bool);
+
+    #[allow(clippy::non_canonical_clone_impl)]
+    impl Clone for Flag {
+        fn clone(&self) -> Self { panic!("`Flag` is copied, not cloned") }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Row {
+        count: Counted,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Model {
+        count: Counted,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct FlagRow {
+        flag: Flag,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct FlagModel {
+        flag: Flag,
+    }
+
+    relate_structs! {
+        Row ~ Model {
+            #![reverse_cloned]
+            count: move;
+        }
+
+        FlagRow ~ FlagModel {
+            #![reverse_copy]
+            flag;
+        }
+    }
+
+    #[test]
+    fn test_reverse_cloned() {
+        let model: Model = Row { count: Counted(1) }.into();
+        let before = CLONES.load(Ordering::SeqCst);
+        let row: Row = model.into();
+        assert_eq!(row, Row { count: Counted(1) });
+        assert!(CLONES.load(Ordering::SeqCst) > before);
+    }
+
+    #[test]
+    fn test_reverse_copy() {
+        let model = FlagModel { flag: Flag(true) };
+        let row = FlagRow::from(&model);
+        assert_eq!(row, FlagRow { flag: Flag(true) });
+        assert_eq!(FlagModel::from(row), model);
+    }
+}
+
+// Test `, cloned` on a `with` expression reading several fields by value
+mod cloned_multi_field_with {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct FullName {
+        first: String,
+        last:  String,
+    }
+
+    impl FullName {
+        fn new(first: String, last: String) -> Self { Self { first, last } }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Person {
+        first: String,
+        last:  String,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Contact {
+        name:    FullName,
+        aliases: Vec<String>,
+        label:   String,
+    }
+
+    relate_structs! {
+        Person ~> Contact {
+            name: with = FullName::new(.first, .last), cloned;
+            aliases: with = vec![.first, .last], cloned;
+            label: with = format!("{} {}", .first, .last), cloned;
+        }
+    }
+
+    #[test]
+    fn test_cloned_reads_each_field() {
+        let person = Person {
+            first: "Grace".to_string(),
+            last:  "Hopper".to_string(),
+        };
+        let expected = Contact {
+            name:    FullName::new("Grace".to_string(), "Hopper".to_string()),
+            aliases: vec!["Grace".to_string(), "Hopper".to_string()],
+            label:   "Grace Hopper".to_string(),
+        };
+
+        assert_eq!(Contact::from(&person), expected);
+        assert_eq!(Contact::from(person), expected);
+    }
+}
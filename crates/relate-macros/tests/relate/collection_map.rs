@@ -122,3 +122,449 @@ mod basic_collection_map {
         assert!(result.variants.is_empty());
     }
 }
+
+// Test map value mapping keeps keys
+mod map_values {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DbVariant {
+        sku: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Variant {
+        sku: String,
+    }
+
+    impl From<DbVariant> for Variant {
+        fn from(db: DbVariant) -> Self { Self { sku: db.sku } }
+    }
+
+    #[derive(Debug, Clone)]
+    struct DbProduct {
+        variants: HashMap<String, DbVariant>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Product {
+        variants: HashMap<String, Variant>,
+    }
+
+    relate_structs! {
+        DbProduct ~> Product {
+            variants: with = map[_.clone().into()];
+        }
+    }
+
+    #[test]
+    fn test_map_values() {
+        let mut variants = HashMap::new();
+        variants.insert(
+            "small".to_string(),
+            DbVariant {
+                sku: "S-1".to_string(),
+            },
+        );
+        let db = DbProduct { variants };
+
+        let from_ref: Product = (&db).into();
+        let owned: Product = db.into();
+
+        assert_eq!(from_ref.variants["small"].sku, "S-1");
+        assert_eq!(owned, from_ref);
+    }
+
+    #[test]
+    fn test_empty_map() {
+        let db = DbProduct {
+            variants: HashMap::new(),
+        };
+        let result: Product = db.into();
+        assert!(result.variants.is_empty());
+    }
+}
+
+// Test bare `[_]` converting each item with Into
+mod collection_into_items {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct DbTag {
+        label: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Tag {
+        label: String,
+    }
+
+    relate_structs! {
+        DbTag ~> Tag {
+            label;
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct DbPost {
+        tags: Vec<DbTag>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Post {
+        tags: Vec<Tag>,
+    }
+
+    relate_structs! {
+        DbPost ~> Post {
+            tags: with = [_];
+        }
+    }
+
+    #[test]
+    fn test_collection_into_items() {
+        let db = DbPost {
+            tags: vec![DbTag {
+                label: "rust".to_string(),
+            }],
+        };
+
+        let from_ref: Post = (&db).into();
+        let owned: Post = db.into();
+
+        assert_eq!(from_ref.tags[0].label, "rust");
+        assert_eq!(owned, from_ref);
+    }
+}
+
+// Test `[idx, _ => expr]` enumerating the items
+mod enumerated {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        rows: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        rows: Vec<(usize, String)>,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            rows: with = [row, _ => (row, _.to_uppercase())];
+        }
+    }
+
+    #[test]
+    fn test_enumerated_rows() {
+        let source = Source {
+            rows: vec!["a".to_string(), "b".to_string()],
+        };
+
+        let from_ref: Target = (&source).into();
+        let owned: Target = source.into();
+
+        assert_eq!(
+            from_ref.rows,
+            vec![(0, "A".to_string()), (1, "B".to_string())]
+        );
+        assert_eq!(owned, from_ref);
+    }
+}
+
+// Test `[_.key, _.value]` pairs collecting a `Vec` into a map
+mod key_value_pairs {
+    use std::collections::{BTreeMap, HashMap};
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Entry {
+        key:   String,
+        value: i32,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Settings {
+        entries: Vec<Entry>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct SettingsMap {
+        entries: HashMap<String, i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct SortedSettings {
+        entries: BTreeMap<String, i64>,
+    }
+
+    relate_structs! {
+        Settings ~> SettingsMap {
+            entries: with = [_.key.clone(), _.value];
+        }
+
+        Settings ~> SortedSettings {
+            entries: with = [.key, i64::from(_.value)], cloned;
+        }
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            entries: vec![
+                Entry {
+                    key:   "a".to_string(),
+                    value: 1,
+                },
+                Entry {
+                    key:   "b".to_string(),
+                    value: 2,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_pairs_into_map() {
+        let expected = HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+
+        assert_eq!(SettingsMap::from(&settings()).entries, expected);
+        assert_eq!(SettingsMap::from(settings()).entries, expected);
+    }
+
+    #[test]
+    fn test_pairs_cloned() {
+        let sorted = SortedSettings::from(&settings());
+        assert_eq!(
+            sorted.entries,
+            BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)])
+        );
+    }
+
+    #[test]
+    fn test_pairs_empty_source() {
+        let empty = Settings {
+            entries: Vec::new(),
+        };
+        assert!(SettingsMap::from(&empty).entries.is_empty());
+        assert!(SortedSettings::from(empty).entries.is_empty());
+    }
+}
+
+// Test `Vec<Arc<A>>` -> `Vec<Arc<B>>` through `relate::arc_into`
+mod arc_elements {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Node {
+        id: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct NodeDto {
+        id: i32,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Graph {
+        nodes: Vec<Arc<Node>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct GraphDto {
+        nodes: Vec<Arc<NodeDto>>,
+    }
+
+    relate_structs! {
+        Node ~> NodeDto { id; }
+
+        Graph ~> GraphDto {
+            nodes: with = [relate::arc_into(_)];
+        }
+    }
+
+    #[test]
+    fn test_arc_elements() {
+        let shared = Arc::new(Node { id: 1 });
+        let graph = Graph {
+            nodes: vec![Arc::clone(&shared), Arc::new(Node { id: 2 })],
+        };
+        let expected = vec![Arc::new(NodeDto { id: 1 }), Arc::new(NodeDto { id: 2 })];
+
+        assert_eq!(GraphDto::from(&graph).nodes, expected);
+        assert_eq!(GraphDto::from(graph).nodes, expected);
+        assert_eq!(shared.id, 1);
+    }
+}
+
+// Test the owned impl moves the items instead of cloning them
+mod owned_items {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Item {
+        name: String,
+    }
+
+    // A clone would fail the test
+    impl Clone for Item {
+        fn clone(&self) -> Self { panic!("item was cloned") }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Basket {
+        items:  Vec<Item>,
+        labels: Vec<Item>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct BasketNames {
+        items:  Vec<String>,
+        labels: Vec<usize>,
+    }
+
+    relate_structs! {
+        Basket ~> BasketNames {
+            #![owned_only]
+            items: with = [_.name];
+            labels: with = [_.name.len()], cloned;
+        }
+    }
+
+    #[test]
+    fn test_owned_items_not_cloned() {
+        let basket = Basket {
+            items:  vec![
+                Item {
+                    name: "apple".to_string(),
+                },
+                Item {
+                    name: "pear".to_string(),
+                },
+            ],
+            labels: vec![Item {
+                name: "ripe".to_string(),
+            }],
+        };
+        let names = BasketNames::from(basket);
+        assert_eq!(names.items, vec!["apple".to_string(), "pear".to_string()]);
+        assert_eq!(names.labels, vec![4]);
+    }
+}
+
+// Test a collection map whose source field another field reads again
+mod shared_source_field {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Item {
+        id: u32,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Order {
+        items: Vec<Item>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct OrderSummary {
+        items: Vec<u32>,
+        count: usize,
+    }
+
+    relate_structs! {
+        Order ~> OrderSummary {
+            items: with = [_.id];
+            count: with = .items.len();
+        }
+    }
+
+    #[test]
+    fn test_collection_map_with_another_read() {
+        let order = Order {
+            items: vec![Item { id: 1 }, Item { id: 2 }],
+        };
+        let expected = OrderSummary {
+            items: vec![1, 2],
+            count: 2,
+        };
+        assert_eq!(OrderSummary::from(&order), expected);
+        assert_eq!(OrderSummary::from(order), expected);
+    }
+}
+
+// Test `opt[..]` mapping over a collection inside an `Option`
+mod optional_collection {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Tag {
+        label: String,
+    }
+
+    #[derive(Debug, Clone)]
+    struct ArticleRow {
+        tags:    Option<Vec<Tag>>,
+        ratings: Option<Vec<String>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Article {
+        tags:    Option<Vec<String>>,
+        ratings: Option<Vec<u8>>,
+    }
+
+    relate_structs! {
+        ArticleRow ~>? Article {
+            tags: with = opt[_.label.clone()];
+            ratings: with = opt[_.parse()?];
+        }
+    }
+
+    #[test]
+    fn test_option_collection_some() {
+        let row = ArticleRow {
+            tags:    Some(vec![Tag {
+                label: "rust".to_string(),
+            }]),
+            ratings: Some(vec!["4".to_string(), "5".to_string()]),
+        };
+        let expected = Article {
+            tags:    Some(vec!["rust".to_string()]),
+            ratings: Some(vec![4, 5]),
+        };
+        assert_eq!(Article::try_from(&row).unwrap(), expected);
+        assert_eq!(Article::try_from(row).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_option_collection_none() {
+        let row = ArticleRow {
+            tags:    None,
+            ratings: None,
+        };
+        let article = Article::try_from(row).unwrap();
+        assert_eq!(
+            article,
+            Article {
+                tags:    None,
+                ratings: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_collection_error() {
+        let row = ArticleRow {
+            tags:    None,
+            ratings: Some(vec!["high".to_string()]),
+        };
+        assert!(Article::try_from(&row).is_err());
+    }
+}
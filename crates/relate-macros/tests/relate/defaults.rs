@@ -161,3 +161,314 @@ mod fields_with_transform {
         assert!(target.added);
     }
 }
+
+// Test conditional default with `skip_if`
+mod skip_if {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        score:  u32,
+        label:  String,
+        hidden: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        score: u32,
+        label: String,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            score: skip_if = .hidden, default = 0;
+            label: skip_if = .hidden;
+        }
+    }
+
+    #[test]
+    fn test_skip_if_false() {
+        let source = Source {
+            score:  9,
+            label:  "gold".to_string(),
+            hidden: false,
+        };
+        let target: Target = (&source).into();
+        assert_eq!(
+            target,
+            Target {
+                score: 9,
+                label: "gold".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_skip_if_true() {
+        let source = Source {
+            score:  9,
+            label:  "gold".to_string(),
+            hidden: true,
+        };
+        let target: Target = source.into();
+        assert_eq!(
+            target,
+            Target {
+                score: 0,
+                label: String::new(),
+            }
+        );
+    }
+}
+
+// Test `unwrap_or_default` for `Option` source fields
+mod unwrap_or_default {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        count: Option<i32>,
+        note:  Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        count: i32,
+        note:  String,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            count: unwrap_or_default;
+            note: unwrap_or_default;
+        }
+    }
+
+    #[test]
+    fn test_some() {
+        let source = Source {
+            count: Some(4),
+            note:  Some("hi".to_string()),
+        };
+        let target: Target = source.into();
+        assert_eq!(
+            target,
+            Target {
+                count: 4,
+                note:  "hi".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_none_from_ref() {
+        let source = Source {
+            count: None,
+            note:  None,
+        };
+        let target: Target = (&source).into();
+        assert_eq!(
+            target,
+            Target {
+                count: 0,
+                note:  String::new(),
+            }
+        );
+    }
+}
+
+// Test `default_if_none` falling back to a legacy `Option` field
+mod default_if_none {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        name:        Option<String>,
+        legacy_name: Option<String>,
+        count:       Option<u32>,
+        old_count:   Option<u32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        name:        Option<String>,
+        legacy_name: Option<String>,
+        count:       Option<u32>,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            name: default_if_none = .legacy_name;
+            legacy_name;
+            count: default_if_none = .old_count, copy;
+        }
+    }
+
+    fn source(name: Option<&str>, count: Option<u32>) -> Source {
+        Source {
+            name: name.map(str::to_string),
+            legacy_name: Some("legacy".to_string()),
+            count,
+            old_count: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_primary_present() {
+        let target: Target = source(Some("new"), Some(5)).into();
+        assert_eq!(
+            target,
+            Target {
+                name:        Some("new".to_string()),
+                legacy_name: Some("legacy".to_string()),
+                count:       Some(5),
+            }
+        );
+    }
+
+    #[test]
+    fn test_falls_back_when_none() {
+        let target: Target = source(None, None).into();
+        assert_eq!(target.name.as_deref(), Some("legacy"));
+        assert_eq!(target.count, Some(1));
+
+        let target: Target = (&source(None, None)).into();
+        assert_eq!(target.name, target.legacy_name);
+    }
+}
+
+// Test struct literals, blocks, and closures as default expressions
+mod complex_defaults {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Limits {
+        min: u32,
+        max: u32,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        id: i32,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Target {
+        id:     i32,
+        limits: Limits,
+        size:   u32,
+        label:  fn(i32) -> String,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            limits: default = Limits { min: 1, max: 10 };
+            size: default = { let base = 5; base * 2 };
+            label: default = |id| format!("#{id}");
+            id;
+        }
+    }
+
+    #[test]
+    fn test_complex_defaults() {
+        let target: Target = Source { id: 7 }.into();
+        assert_eq!(target.id, 7);
+        assert_eq!(target.limits, Limits { min: 1, max: 10 });
+        assert_eq!(target.size, 10);
+        assert_eq!((target.label)(target.id), "#7");
+    }
+}
+
+// Test `base = expr;` filling unlisted fields with struct update syntax
+mod struct_update_base {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Patch {
+        name:    String,
+        retries: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Config {
+        name:    String,
+        retries: u32,
+        timeout: u64,
+        verbose: bool,
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Self {
+                name:    "default".into(),
+                retries: 3,
+                timeout: 30,
+                verbose: true,
+            }
+        }
+    }
+
+    relate_structs! {
+        Patch ~> Config {
+            base = Config::default();
+            name;
+            retries: with = .retries + 1;
+        }
+    }
+
+    #[test]
+    fn test_base_fills_unlisted_fields() {
+        let patch = Patch {
+            name:    "custom".into(),
+            retries: 1,
+        };
+        let expected = Config {
+            name:    "custom".into(),
+            retries: 2,
+            timeout: 30,
+            verbose: true,
+        };
+
+        assert_eq!(Config::from(&patch), expected);
+        assert_eq!(Config::from(patch), expected);
+    }
+}
+
+// Test a conversion where every field is a default, so `src` is never read
+mod all_defaults {
+    #![deny(warnings)]
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Request {
+        #[allow(dead_code)]
+        path: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Metrics {
+        hits:   u64,
+        status: &'static str,
+    }
+
+    relate_structs! {
+        Request ~> Metrics {
+            hits: default;
+            status: default = "pending";
+        }
+    }
+
+    #[test]
+    fn test_all_defaults() {
+        let request = Request {
+            path: "/".to_string(),
+        };
+        let expected = Metrics {
+            hits:   0,
+            status: "pending",
+        };
+        assert_eq!(Metrics::from(&request), expected);
+        assert_eq!(Metrics::from(request), expected);
+    }
+}
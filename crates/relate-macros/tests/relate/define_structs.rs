@@ -0,0 +1,279 @@
+//! Tests for defining structs inline in relate_structs!
+
+use relate::relate_structs;
+
+// Test defining both structs with a shared field list
+mod shared_fields {
+    use super::*;
+
+    relate_structs! {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Source
+        ~
+        #[derive(Debug, Clone, PartialEq)]
+        struct Target {
+            id:   i32,
+            name: String,
+        }
+    }
+
+    #[test]
+    fn test_define_both_forward() {
+        let source = Source {
+            id:   1,
+            name: "one".to_string(),
+        };
+        let target: Target = (&source).into();
+
+        assert_eq!(
+            target,
+            Target {
+                id:   1,
+                name: "one".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_define_both_backward() {
+        let target = Target {
+            id:   2,
+            name: "two".to_string(),
+        };
+        let source: Source = target.into();
+        assert_eq!(source.id, 2);
+    }
+}
+
+// Test defining only the target next to an existing domain type
+mod define_target {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct User {
+        id:       u64,
+        name:     String,
+        password: String,
+    }
+
+    relate_structs! {
+        User ~> #[derive(Debug, Clone, PartialEq)] pub struct UserDto {
+            pub id:   String,
+            pub name: String,
+        } {
+            id: with = _.to_string();
+            name;
+        }
+    }
+
+    #[test]
+    fn test_define_target_with_mappings() {
+        let user = User {
+            id:       7,
+            name:     "ada".to_string(),
+            password: "secret".to_string(),
+        };
+        let dto: UserDto = user.into();
+
+        assert_eq!(
+            dto,
+            UserDto {
+                id:   "7".to_string(),
+                name: "ada".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_source_fields_unused_by_dto() {
+        let user = User {
+            id:       1,
+            name:     "bob".to_string(),
+            password: "hunter2".to_string(),
+        };
+        let _dto: UserDto = (&user).into();
+        assert_eq!(user.password, "hunter2");
+    }
+}
+
+// Test defining the source with its own fields and an implicit identity body
+mod define_source {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    relate_structs! {
+        #[derive(Debug, Clone)]
+        struct RawPoint {
+            x: i32,
+            y: i32,
+        }
+        ~> Point { x; y; }
+    }
+
+    #[test]
+    fn test_define_source() {
+        let point: Point = RawPoint { x: 1, y: 2 }.into();
+        assert_eq!(point, Point { x: 1, y: 2 });
+    }
+}
+
+// Test generic inline definitions
+mod generic_definitions {
+    use super::*;
+
+    relate_structs! {
+        #[derive(Debug, Clone)]
+        struct Wrapper<T: Clone>
+        ~
+        #[derive(Debug, Clone, PartialEq)]
+        struct Mirror<T: Clone> {
+            value: T,
+        }
+    }
+
+    #[test]
+    fn test_generic_define_both() {
+        let mirror: Mirror<u8> = Wrapper { value: 3u8 }.into();
+        assert_eq!(mirror, Mirror { value: 3 });
+    }
+}
+
+// Test `..` spread auto-maps the remaining fields of a defined target
+mod spread {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Order {
+        id:       u64,
+        customer: String,
+        total:    u32,
+        notes:    Vec<String>,
+    }
+
+    relate_structs! {
+        Order ~> #[derive(Debug, Clone, PartialEq)] struct OrderDto {
+            id:       String,
+            customer: String,
+            total:    u32,
+            notes:    Vec<String>,
+        } {
+            id: with = _.to_string();
+            ..
+        }
+    }
+
+    #[test]
+    fn test_spread_fills_remaining_fields() {
+        let order = Order {
+            id:       9,
+            customer: "ada".to_string(),
+            total:    120,
+            notes:    vec!["gift".to_string()],
+        };
+        let dto: OrderDto = (&order).into();
+
+        assert_eq!(
+            dto,
+            OrderDto {
+                id:       "9".to_string(),
+                customer: "ada".to_string(),
+                total:    120,
+                notes:    vec!["gift".to_string()],
+            }
+        );
+    }
+
+    relate_structs! {
+        #[derive(Debug, Clone)]
+        struct Celsius
+        ~>
+        #[derive(Debug, Clone, PartialEq)]
+        struct Reading {
+            sensor: String,
+            value:  i32,
+        }
+        {
+            sensor: with = _.to_uppercase(), cloned;
+            ..
+        }
+    }
+
+    #[test]
+    fn test_spread_with_shared_fields() {
+        let celsius = Celsius {
+            sensor: "north".to_string(),
+            value:  21,
+        };
+        let reading: Reading = celsius.into();
+        assert_eq!(
+            reading,
+            Reading {
+                sensor: "NORTH".to_string(),
+                value:  21,
+            }
+        );
+    }
+}
+
+// Test field attributes and visibility pass through to the defined structs
+mod field_attributes {
+    mod api {
+        use relate::relate_structs;
+        use serde::{Deserialize, Serialize};
+
+        relate_structs! {
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            pub struct AccountRow
+            ~
+            #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+            pub struct AccountDto {
+                #[serde(rename = "accountId")]
+                pub id:           u64,
+                /// Shown to other users
+                pub display_name: String,
+                #[serde(skip)]
+                pub(crate) token: String,
+            }
+        }
+    }
+
+    use api::{AccountDto, AccountRow};
+
+    #[test]
+    fn test_serde_field_attributes_survive() {
+        let dto = AccountDto {
+            id:           4,
+            display_name: "ada".to_string(),
+            token:        "secret".to_string(),
+        };
+        let json = serde_json::to_value(&dto).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "accountId": 4, "display_name": "ada" })
+        );
+
+        let row: AccountRow = dto.into();
+        assert_eq!(row.token, "secret");
+        let json = serde_json::to_value(&row).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "accountId": 4, "display_name": "ada" })
+        );
+    }
+
+    #[test]
+    fn test_visibility_survives() {
+        let row: AccountRow =
+            serde_json::from_str(r#"{"accountId":7,"display_name":"bo"}"#).unwrap();
+        assert_eq!(row.id, 7);
+        assert_eq!(row.token, "");
+
+        let dto: AccountDto = row.into();
+        assert_eq!(dto.display_name, "bo");
+    }
+}
@@ -46,3 +46,97 @@ mod existing_generic_with_bound {
         assert_eq!(w.inner, 100);
     }
 }
+
+// Test: identity-only moves need no bound; only the ref impl requires Clone
+mod existing_generic_without_bound {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct NotClone(u32);
+
+    #[derive(Debug, Clone)]
+    struct Source<T> {
+        inner: T,
+        code:  u16,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Response<T> {
+        inner: T,
+        code:  u16,
+    }
+
+    relate_structs! {
+        Source<T> ~ Response<T> {
+            inner;
+            code;
+        }
+    }
+
+    #[test]
+    fn test_owned_without_clone() {
+        let source = Source {
+            inner: NotClone(7),
+            code:  200,
+        };
+        let response: Response<NotClone> = source.into();
+        assert_eq!(response.inner, NotClone(7));
+
+        let back: Source<NotClone> = response.into();
+        assert_eq!(back.code, 200);
+    }
+
+    #[test]
+    fn test_ref_with_clone() {
+        let source = Source {
+            inner: "cloned".to_string(),
+            code:  404,
+        };
+        let response: Response<String> = (&source).into();
+        assert_eq!(
+            response,
+            Response {
+                inner: "cloned".to_string(),
+                code:  404,
+            }
+        );
+    }
+}
+
+// Test: a trailing `where` clause adds to the bounds written in the generics
+mod trailing_where_clause {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Batch<T: Clone> {
+        items: Vec<T>,
+        size:  usize,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Labels {
+        items: Vec<String>,
+        size:  usize,
+    }
+
+    relate_structs! {
+        Batch<T: Clone> ~> Labels where T: Into<String> {
+            items: with = .items.iter().cloned().map(Into::into).collect();
+            size;
+        }
+    }
+
+    #[test]
+    fn test_where_clause_bounds_elements() {
+        let batch = Batch {
+            items: vec!["a", "b"],
+            size:  2,
+        };
+        let expected = Labels {
+            items: vec!["a".to_string(), "b".to_string()],
+            size:  2,
+        };
+        assert_eq!(Labels::from(&batch), expected);
+        assert_eq!(Labels::from(batch), expected);
+    }
+}
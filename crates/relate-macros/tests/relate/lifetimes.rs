@@ -79,3 +79,145 @@ mod multi_lifetime {
         assert_eq!(owned.second, "second");
     }
 }
+
+// Test `into_owned` turning `Cow` fields into owned values
+mod cow_into_owned {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Borrowed<'a> {
+        text: Cow<'a, str>,
+        tags: Cow<'a, [u32]>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Owned {
+        text: String,
+        tags: Vec<u32>,
+    }
+
+    // The impls are generic over the source lifetime: `impl<'a> From<Borrowed<'a>>`
+    relate_structs! {
+        Borrowed<'a> ~> Owned {
+            text: into_owned;
+            tags: into_owned;
+        }
+    }
+
+    fn convert<'a>(borrowed: Borrowed<'a>) -> Owned { borrowed.into() }
+
+    #[test]
+    fn test_borrowed_cow() {
+        let input = String::from("parsed");
+        let tags = [1, 2];
+        let owned = convert(Borrowed {
+            text: Cow::Borrowed(&input),
+            tags: Cow::Borrowed(&tags),
+        });
+        assert_eq!(
+            owned,
+            Owned {
+                text: "parsed".to_string(),
+                tags: vec![1, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn test_owned_cow_from_ref() {
+        let borrowed = Borrowed {
+            text: Cow::Owned("owned".to_string()),
+            tags: Cow::Owned(vec![3]),
+        };
+        let owned: Owned = (&borrowed).into();
+        assert_eq!(owned.text, "owned");
+        assert_eq!(owned.tags, vec![3]);
+        assert_eq!(borrowed.text, "owned");
+    }
+}
+
+// Test a target borrowing from the source: `From<&'a Source> for View<'a>`
+mod borrowing_target {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Article {
+        title:  String,
+        tags:   Vec<String>,
+        author: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct ArticleView<'a> {
+        title:  &'a str,
+        tags:   &'a [String],
+        author: Option<&'a String>,
+    }
+
+    relate_structs! {
+        Article ~> ArticleView<'a> {
+            #![ref_only]
+            title: with = _.as_str();
+            tags: with = &_;
+            author: with = _.as_ref();
+        }
+    }
+
+    #[test]
+    fn test_view_borrows_source() {
+        let article = Article {
+            title:  "Lifetimes".to_string(),
+            tags:   vec!["rust".to_string()],
+            author: Some("ferris".to_string()),
+        };
+        let view = ArticleView::from(&article);
+
+        assert_eq!(view.title, "Lifetimes");
+        assert_eq!(view.tags, ["rust".to_string()]);
+        assert_eq!(view.author.map(String::as_str), Some("ferris"));
+    }
+}
+
+// Test a generic source with a borrowing target
+mod borrowing_generic_target {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Labeled<T> {
+        label: String,
+        value: T,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct LabelView<'a, T> {
+        label: &'a str,
+        value: &'a T,
+    }
+
+    relate_structs! {
+        Labeled<T> ~> LabelView<'a, T> {
+            #![ref_only]
+            label: with = _.as_str();
+            value: with = &_;
+        }
+    }
+
+    #[test]
+    fn test_generic_view_borrows_source() {
+        let labeled = Labeled {
+            label: "count".to_string(),
+            value: 3_u8,
+        };
+        let view: LabelView<'_, u8> = (&labeled).into();
+
+        assert_eq!(
+            view,
+            LabelView {
+                label: "count",
+                value: &3,
+            }
+        );
+    }
+}
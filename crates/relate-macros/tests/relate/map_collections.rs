@@ -0,0 +1,63 @@
+//! Tests for `HashMap` and `BTreeMap` fields in relate_structs!
+
+use std::collections::{BTreeMap, HashMap};
+
+use relate::relate_structs;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Source {
+    tags:   HashMap<String, u32>,
+    scores: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Renamed {
+    labels: HashMap<String, u32>,
+    scores: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Widened {
+    tags:   HashMap<String, u64>,
+    scores: BTreeMap<String, u64>,
+}
+
+relate_structs! {
+    Source ~ Renamed {
+        labels: with = .tags;
+        scores: cloned;
+    }
+
+    Source ~> Widened {
+        tags: with = map[_], cloned;
+        scores: with = map[_], cloned;
+    }
+}
+
+fn source() -> Source {
+    Source {
+        tags:   HashMap::from([("a".to_string(), 1)]),
+        scores: BTreeMap::from([("b".to_string(), 2)]),
+    }
+}
+
+#[test]
+fn test_identity_and_rename_round_trip() {
+    let source = source();
+    let renamed: Renamed = (&source).into();
+    assert_eq!(renamed.labels, source.tags);
+
+    let back: Source = renamed.into();
+    assert_eq!(back, source);
+}
+
+#[test]
+fn test_values_converted() {
+    let source = source();
+    let from_ref: Widened = (&source).into();
+    let owned: Widened = source.into();
+
+    assert_eq!(owned.tags["a"], 1_u64);
+    assert_eq!(owned.scores["b"], 2_u64);
+    assert_eq!(from_ref, owned);
+}
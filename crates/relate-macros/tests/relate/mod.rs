@@ -4,9 +4,12 @@ mod basic;
 mod clone_modes;
 mod collection_map;
 mod defaults;
+mod define_structs;
 mod generics_existing;
 mod implied_closures;
 mod lifetimes;
+mod map_collections;
 mod renames;
+mod reverse;
 mod transforms;
 mod try_from;
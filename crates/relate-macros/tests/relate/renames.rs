@@ -37,3 +37,167 @@ mod simple {
         assert_eq!(warehouse.name, "Main");
     }
 }
+
+// Test a plain rename is reversed automatically in bidirectional relations
+mod bidirectional_rename {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct ApiStore {
+        id:   String,
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Warehouse {
+        store_id: String,
+        name:     String,
+    }
+
+    relate_structs! {
+        ApiStore ~ Warehouse {
+            store_id: with = .id;
+            name;
+        }
+    }
+
+    #[test]
+    fn test_rename_round_trip() {
+        let store = ApiStore {
+            id:   "abc".to_string(),
+            name: "Main".to_string(),
+        };
+        let warehouse: Warehouse = (&store).into();
+        assert_eq!(warehouse.store_id, "abc");
+
+        let back: ApiStore = warehouse.into();
+        assert_eq!(back, store);
+    }
+}
+
+// Test `{a, b}: ...` maps one source field into several target fields
+mod split_targets {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Order {
+        id:     u32,
+        region: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Shipment {
+        id:             u32,
+        origin:         String,
+        billing_region: String,
+    }
+
+    relate_structs! {
+        Order ~> Shipment {
+            id;
+            {origin, billing_region}: with = .region;
+        }
+    }
+
+    #[test]
+    fn test_split_owned() {
+        let order = Order {
+            id:     1,
+            region: "EU".to_string(),
+        };
+        let shipment: Shipment = order.into();
+        assert_eq!(shipment.origin, "EU");
+        assert_eq!(shipment.billing_region, "EU");
+    }
+
+    #[test]
+    fn test_split_from_ref() {
+        let order = Order {
+            id:     2,
+            region: "US".to_string(),
+        };
+        let shipment: Shipment = (&order).into();
+        assert_eq!(shipment.origin, "US");
+        assert_eq!(shipment.billing_region, "US");
+        assert_eq!(order.region, "US");
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label {
+        primary:   String,
+        secondary: String,
+    }
+
+    relate_structs! {
+        Order ~> Label {
+            {primary, secondary}: with = .region.to_lowercase();
+        }
+    }
+
+    #[test]
+    fn test_split_with_expr() {
+        let order = Order {
+            id:     3,
+            region: "APAC".to_string(),
+        };
+        let label: Label = order.into();
+        assert_eq!(label.primary, "apac");
+        assert_eq!(label.secondary, "apac");
+    }
+}
+
+// Test `strip_prefix = "..";` maps prefixed source fields both ways
+mod strip_prefix {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct UserRow {
+        db_id:    i64,
+        db_name:  String,
+        db_email: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct User {
+        id:    i64,
+        name:  String,
+        email: String,
+    }
+
+    relate_structs! {
+        UserRow ~ User {
+            strip_prefix = "db_";
+            id;
+            name;
+            email: with = .db_email.clone().unwrap_or_default(),
+                reverse = Some(_.clone());
+        }
+    }
+
+    fn row() -> UserRow {
+        UserRow {
+            db_id:    1,
+            db_name:  "Ada".to_string(),
+            db_email: Some("ada@example.com".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        let user: User = (&row()).into();
+        assert_eq!(
+            user,
+            User {
+                id:    1,
+                name:  "Ada".to_string(),
+                email: "ada@example.com".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_strip_prefix_round_trip() {
+        let back: UserRow = User::from(row()).into();
+        assert_eq!(back, row());
+    }
+}
@@ -0,0 +1,263 @@
+//! Tests for `reverse = expr` and fallible bidirectional (`~?`) relations.
+
+use relate::{ConversionError, relate_structs};
+
+// Test `reverse = expr` providing the inverse of a transform
+mod reverse_transform {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Source {
+        id:   i32,
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        id:   i32,
+        name: String,
+    }
+
+    relate_structs! {
+        Source ~ Target {
+            id;
+            name: with = _.to_uppercase(), reverse = _.to_lowercase();
+        }
+    }
+
+    #[test]
+    fn test_forward() {
+        let source = Source {
+            id:   1,
+            name: "widget".to_string(),
+        };
+        let target: Target = source.into();
+        assert_eq!(target.name, "WIDGET");
+    }
+
+    #[test]
+    fn test_reverse() {
+        let target = Target {
+            id:   2,
+            name: "GADGET".to_string(),
+        };
+        let from_ref: Source = (&target).into();
+        let owned: Source = target.into();
+        assert_eq!(
+            owned,
+            Source {
+                id:   2,
+                name: "gadget".to_string(),
+            }
+        );
+        assert_eq!(from_ref, owned);
+    }
+}
+
+// Test reverse writing back to a differently named source field
+mod reverse_rename {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Source {
+        value: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        doubled: i32,
+    }
+
+    relate_structs! {
+        Source ~ Target {
+            doubled: with = .value * 2, reverse = _ / 2;
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let source = Source { value: 21 };
+        let target: Target = source.clone().into();
+        assert_eq!(target.doubled, 42);
+
+        let back: Source = target.into();
+        assert_eq!(back, source);
+    }
+}
+
+// Test `~?` generating TryFrom in both directions
+mod try_bidirectional {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Form {
+        age: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Person {
+        age: u8,
+    }
+
+    relate_structs! {
+        Form ~? Person {
+            age: with = _.parse()?, reverse = _.to_string();
+        }
+    }
+
+    #[test]
+    fn test_forward() {
+        let form = Form {
+            age: "30".to_string(),
+        };
+        let person: Result<Person, ConversionError> = form.try_into();
+        assert_eq!(person.unwrap().age, 30);
+
+        let bad = Form {
+            age: "old".to_string(),
+        };
+        let result: Result<Person, ConversionError> = (&bad).try_into();
+        assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let person = Person { age: 30 };
+        let form: Result<Form, ConversionError> = (&person).try_into();
+        assert_eq!(form.unwrap().age, "30");
+    }
+}
+
+// Test `~?[E]` using a custom error type in both directions
+mod try_bidirectional_custom_error {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct MyError;
+
+    impl From<std::num::ParseIntError> for MyError {
+        fn from(_: std::num::ParseIntError) -> Self { MyError }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Text {
+        count: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Number {
+        count: i64,
+    }
+
+    relate_structs! {
+        Text ~?[MyError] Number {
+            count: with = _.parse()?, reverse = _.to_string();
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let number = Number { count: -5 };
+        let text: Result<Text, MyError> = number.try_into();
+        let back: Result<Number, MyError> = text.unwrap().try_into();
+        assert_eq!(back, Ok(Number { count: -5 }));
+    }
+
+    #[test]
+    fn test_forward_error() {
+        let text = Text {
+            count: "many".to_string(),
+        };
+        let result: Result<Number, MyError> = text.try_into();
+        assert_eq!(result, Err(MyError));
+    }
+}
+
+// Test a fallible `reverse` auto-upgrading the backward impls to TryFrom
+mod fallible_reverse {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Source {
+        code: u16,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        code: String,
+    }
+
+    relate_structs! {
+        Source ~ Target {
+            code: with = _.to_string(), reverse = _.parse()?;
+        }
+    }
+
+    #[test]
+    fn test_forward_is_infallible() {
+        let target: Target = Source { code: 404 }.into();
+        assert_eq!(target.code, "404");
+    }
+
+    #[test]
+    fn test_reverse_is_fallible() {
+        let ok: Result<Source, ConversionError> = Target {
+            code: "200".to_string(),
+        }
+        .try_into();
+        assert_eq!(ok.unwrap().code, 200);
+
+        let err: Result<Source, ConversionError> = Target {
+            code: "teapot".to_string(),
+        }
+        .try_into();
+        assert!(err.is_err());
+    }
+}
+
+// Test identity fields named like the reverse clone modes, which are inner
+// attributes and so don't shadow them
+mod reverse_mode_named_fields {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Source {
+        reverse_cloned: bool,
+        reverse_copy:   u8,
+        reverse_move:   String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        reverse_cloned: bool,
+        reverse_copy:   u8,
+        reverse_move:   String,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            reverse_cloned;
+            reverse_copy;
+            reverse_move;
+        }
+    }
+
+    #[test]
+    fn test_reverse_mode_named_fields() {
+        let source = Source {
+            reverse_cloned: true,
+            reverse_copy:   7,
+            reverse_move:   "moved".to_string(),
+        };
+        let target = Target::from(&source);
+        assert_eq!(
+            target,
+            Target {
+                reverse_cloned: true,
+                reverse_copy:   7,
+                reverse_move:   "moved".to_string(),
+            }
+        );
+        assert_eq!(Target::from(source), target);
+    }
+}
@@ -401,3 +401,769 @@ mod chained_access_with_method {
         assert_eq!(target.text, "");
     }
 }
+
+// Test `into` for nested related structs
+mod into_nested {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Inner {
+        value: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct InnerDto {
+        value: String,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Outer {
+        inner: Inner,
+        label: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct OuterDto {
+        inner: InnerDto,
+        label: String,
+    }
+
+    relate_structs! {
+        Inner ~> InnerDto { value; }
+
+        Outer ~> OuterDto {
+            inner: into;
+            label;
+        }
+    }
+
+    #[test]
+    fn test_into_nested() {
+        let outer = Outer {
+            inner: Inner {
+                value: "x".to_string(),
+            },
+            label: "l".to_string(),
+        };
+
+        let from_ref: OuterDto = (&outer).into();
+        let owned: OuterDto = outer.into();
+
+        assert_eq!(from_ref.inner.value, "x");
+        assert_eq!(owned, from_ref);
+    }
+}
+
+// Test `some` wrapping a field for an `Option` target
+mod wrap_some {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        id:   u32,
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        id:   Option<u32>,
+        name: Option<String>,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            id: some;
+            name: some;
+        }
+    }
+
+    #[test]
+    fn test_wrap_some() {
+        let source = Source {
+            id:   1,
+            name: "one".to_string(),
+        };
+        let from_ref: Target = (&source).into();
+        let owned: Target = source.into();
+
+        assert_eq!(
+            owned,
+            Target {
+                id:   Some(1),
+                name: Some("one".to_string()),
+            }
+        );
+        assert_eq!(from_ref, owned);
+    }
+}
+
+// Test `@field` references to other target fields
+mod target_field_refs {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Person {
+        first: String,
+        last:  String,
+        age:   String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Badge {
+        label: String,
+        full:  String,
+        first: String,
+        last:  String,
+        age:   u8,
+        adult: bool,
+    }
+
+    // `label` reads `full`, which reads `first` and `last`
+    relate_structs! {
+        Person ~> Badge {
+            label: with = format!("[{}]", @full);
+            full: with = format!("{} {}", @first, @last);
+            first;
+            last: with = _.to_uppercase();
+            age: with = _.parse()?;
+            adult: with = @age >= 18;
+        }
+    }
+
+    fn person(age: &str) -> Person {
+        Person {
+            first: "Ada".to_string(),
+            last:  "Lovelace".to_string(),
+            age:   age.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_target_field_refs() {
+        let badge: Badge = person("36").try_into().unwrap();
+        assert_eq!(
+            badge,
+            Badge {
+                label: "[Ada LOVELACE]".to_string(),
+                full:  "Ada LOVELACE".to_string(),
+                first: "Ada".to_string(),
+                last:  "LOVELACE".to_string(),
+                age:   36,
+                adult: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_target_field_refs_from_ref() {
+        let source = person("12");
+        let badge: Badge = (&source).try_into().unwrap();
+        assert_eq!(badge.full, "Ada LOVELACE");
+        assert!(!badge.adult);
+    }
+}
+
+// Test smart pointer wrapping and unboxing
+mod pointers {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Node {
+        value: i32,
+        name:  String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct SharedNode {
+        value: Box<i32>,
+        name:  Arc<String>,
+    }
+
+    relate_structs! {
+        Node ~ SharedNode {
+            value: boxed, reverse = *_;
+            name: arc, reverse = _.as_ref().clone();
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Unboxed {
+        value: i32,
+    }
+
+    relate_structs! {
+        SharedNode ~> Unboxed {
+            value: unbox;
+        }
+    }
+
+    #[test]
+    fn test_pointers_round_trip() {
+        let node = Node {
+            value: 3,
+            name:  "n".to_string(),
+        };
+
+        let shared: SharedNode = (&node).into();
+        assert_eq!(*shared.value, 3);
+        assert_eq!(*shared.name, "n");
+
+        let back: Node = shared.into();
+        assert_eq!(back, node);
+    }
+
+    #[test]
+    fn test_unbox() {
+        let shared = SharedNode {
+            value: Box::new(5),
+            name:  Arc::new("n".to_string()),
+        };
+
+        let from_ref: Unboxed = (&shared).into();
+        let owned: Unboxed = shared.into();
+
+        assert_eq!(owned.value, 5);
+        assert_eq!(owned, from_ref);
+    }
+}
+
+// Test `$src` passing the whole source to a helper
+mod whole_source {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Packet {
+        name:    String,
+        payload: Vec<u8>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Summary {
+        name:     String,
+        payload:  Vec<u8>,
+        checksum: u32,
+    }
+
+    fn checksum(packet: &Packet) -> u32 {
+        packet.name.len() as u32 + packet.payload.iter().map(|&b| u32::from(b)).sum::<u32>()
+    }
+
+    // `name` and `payload` move out first in the owned impl
+    relate_structs! {
+        Packet ~> Summary {
+            name;
+            payload;
+            checksum: with = checksum($src);
+        }
+    }
+
+    fn packet() -> Packet {
+        Packet {
+            name:    "ab".to_string(),
+            payload: vec![1, 2, 3],
+        }
+    }
+
+    #[test]
+    fn test_whole_source() {
+        let summary: Summary = packet().into();
+        assert_eq!(summary.checksum, 8);
+        assert_eq!(summary.payload, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_whole_source_from_ref() {
+        let source = packet();
+        let summary: Summary = (&source).into();
+        assert_eq!(summary.checksum, checksum(&source));
+    }
+}
+
+// Test `with_fn = path` applied to the same-named field
+mod with_fn {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Reading {
+        celsius: f64,
+        label:   String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Display {
+        celsius: String,
+        label:   usize,
+    }
+
+    fn format_temp<T: std::borrow::Borrow<f64>>(value: T) -> String {
+        format!("{:.1}°C", value.borrow())
+    }
+
+    fn char_count(text: impl AsRef<str>) -> usize { text.as_ref().chars().count() }
+
+    relate_structs! {
+        Reading ~> Display {
+            celsius: with_fn = format_temp;
+            label: with_fn = char_count;
+        }
+    }
+
+    #[test]
+    fn test_with_fn() {
+        let reading = Reading {
+            celsius: 21.55,
+            label:   "hall".to_string(),
+        };
+
+        let from_ref: Display = (&reading).into();
+        let owned: Display = reading.into();
+
+        assert_eq!(owned.celsius, "21.6°C");
+        assert_eq!(owned.label, 4);
+        assert_eq!(owned, from_ref);
+    }
+}
+
+// Test tuple fields built from several source fields: `with = (.x, .y)`
+mod tuple_fields {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Shape {
+        x:     i32,
+        y:     i32,
+        z:     i32,
+        label: String,
+        unit:  String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Flat {
+        point: (i32, i32),
+        tags:  (String, String),
+        label: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Solid {
+        point: (i32, i32, i32),
+        names: (String, String, String),
+    }
+
+    // `label` is read again after the tuple, so the tuple clones it
+    relate_structs! {
+        Shape ~> Flat {
+            point: with = (.x, .y);
+            tags: with = (.label, .unit);
+            label;
+        }
+
+        Shape ~> Solid {
+            point: with = (.x, .y, .z);
+            names: with = (.label, .unit, .label);
+        }
+    }
+
+    fn shape() -> Shape {
+        Shape {
+            x:     1,
+            y:     2,
+            z:     3,
+            label: "box".to_string(),
+            unit:  "cm".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_pair() {
+        let from_ref: Flat = (&shape()).into();
+        let owned: Flat = shape().into();
+
+        assert_eq!(owned.point, (1, 2));
+        assert_eq!(owned.tags, ("box".to_string(), "cm".to_string()));
+        assert_eq!(owned.label, "box");
+        assert_eq!(owned, from_ref);
+    }
+
+    #[test]
+    fn test_triple() {
+        let from_ref: Solid = (&shape()).into();
+        let owned: Solid = shape().into();
+
+        assert_eq!(owned.point, (1, 2, 3));
+        assert_eq!(
+            owned.names,
+            ("box".to_string(), "cm".to_string(), "box".to_string())
+        );
+        assert_eq!(owned, from_ref);
+    }
+}
+
+// Test qualified paths and turbofish `_` (an inferred type, not the field)
+mod qualified_paths {
+    use super::*;
+
+    struct Small {
+        small: i32,
+        items: Vec<i32>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Wide {
+        small: i64,
+        items: Vec<i64>,
+        limit: i64,
+    }
+
+    relate_structs! {
+        Small ~> Wide {
+            small: with = <i64 as From<i32>>::from(.small);
+            items: with = _.iter().map(|&i| i64::from(i)).collect::<Vec<_>>();
+            limit: with = i64::MAX - <i64>::from(.small);
+        }
+    }
+
+    #[test]
+    fn test_qualified_paths() {
+        let wide: Wide = Small {
+            small: 1,
+            items: vec![2, 3],
+        }
+        .into();
+        assert_eq!(
+            wide,
+            Wide {
+                small: 1,
+                items: vec![2, 3],
+                limit: i64::MAX - 1,
+            }
+        );
+    }
+}
+
+// Test `<<` and `<` against a `.field` operand (operators, not generics)
+mod shift_operators {
+    use super::*;
+
+    struct Packed {
+        base:  u32,
+        shift: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Unpacked {
+        base:  u32,
+        small: bool,
+    }
+
+    relate_structs! {
+        Packed ~> Unpacked {
+            base: with = _ << .shift;
+            small: with = .base < .shift;
+        }
+    }
+
+    #[test]
+    fn test_shift_operators() {
+        let unpacked: Unpacked = Packed { base: 3, shift: 4 }.into();
+        assert_eq!(
+            unpacked,
+            Unpacked {
+                base:  48,
+                small: true,
+            }
+        );
+    }
+}
+
+// Test `as Type` numeric casts
+mod numeric_casts {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Sample {
+        count: i32,
+        ratio: f64,
+        level: u16,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct WideSample {
+        count: i64,
+        ratio: f32,
+        level: u8,
+    }
+
+    relate_structs! {
+        Sample ~ WideSample {
+            count: as i64, reverse = _ as i32;
+            ratio: as f32, reverse = f64::from(_);
+            level: as u8, reverse = u16::from(_);
+        }
+    }
+
+    #[test]
+    fn test_casts_owned_and_ref() {
+        let sample = Sample {
+            count: -3,
+            ratio: 0.5,
+            level: 300,
+        };
+        let expected = WideSample {
+            count: -3,
+            ratio: 0.5,
+            level: 44,
+        };
+
+        assert_eq!(WideSample::from(&sample), expected);
+        assert_eq!(WideSample::from(sample), expected);
+
+        let back = Sample::from(&expected);
+        assert_eq!((back.count, back.ratio, back.level), (-3, 0.5, 44));
+    }
+}
+
+// Test `Result` source fields: `.ok()`, `.map_err(..)`, `.unwrap_or_else(..)`
+mod result_fields {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct LookupError(String);
+
+    impl From<String> for LookupError {
+        fn from(message: String) -> Self { Self(message) }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Lookup {
+        score:  Result<u32, String>,
+        owner:  Result<String, String>,
+        region: Result<String, String>,
+        extra:  Result<u32, String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Resolved {
+        score:  Option<u32>,
+        owner:  Result<String, LookupError>,
+        region: String,
+        bonus:  u32,
+    }
+
+    relate_structs! {
+        Lookup ~> Resolved {
+            score: with = _.ok();
+            owner: with = _.map_err(Into::into);
+            region: with = _.unwrap_or_else(|e| format!("unknown ({e})"));
+            bonus: with = .extra.ok().map_or(0, |x| x * 2);
+        }
+    }
+
+    #[test]
+    fn test_result_fields_owned_and_ref() {
+        let lookup = Lookup {
+            score:  Ok(7),
+            owner:  Err("no owner".to_string()),
+            region: Err("offline".to_string()),
+            extra:  Ok(4),
+        };
+        let expected = Resolved {
+            score:  Some(7),
+            owner:  Err(LookupError("no owner".to_string())),
+            region: "unknown (offline)".to_string(),
+            bonus:  8,
+        };
+
+        assert_eq!(Resolved::from(&lookup), expected);
+        assert_eq!(Resolved::from(lookup), expected);
+    }
+}
+
+// Test `getter = method` reading a private field through a method
+mod getters {
+    use super::*;
+
+    mod model {
+        #[derive(Debug, Clone)]
+        pub struct Account {
+            first: String,
+            last:  String,
+            cents: i64,
+        }
+
+        impl Account {
+            pub fn new(first: &str, last: &str, cents: i64) -> Self {
+                Self {
+                    first: first.to_string(),
+                    last: last.to_string(),
+                    cents,
+                }
+            }
+
+            pub fn full_name(&self) -> String { format!("{} {}", self.first, self.last) }
+
+            pub const fn balance(&self) -> i64 { self.cents / 100 }
+
+            pub fn last(&self) -> &String { &self.last }
+        }
+    }
+
+    use model::Account;
+
+    #[derive(Debug, PartialEq)]
+    struct AccountView {
+        name:    String,
+        balance: i64,
+        surname: String,
+    }
+
+    relate_structs! {
+        Account ~> AccountView {
+            name: getter = full_name;
+            balance: getter = balance;
+            surname: getter = last, cloned;
+        }
+    }
+
+    #[test]
+    fn test_getter_owned_and_ref() {
+        let account = Account::new("Ada", "Lovelace", 12_345);
+        let expected = AccountView {
+            name:    "Ada Lovelace".to_string(),
+            balance: 123,
+            surname: "Lovelace".to_string(),
+        };
+
+        assert_eq!(AccountView::from(&account), expected);
+        assert_eq!(AccountView::from(account), expected);
+    }
+}
+
+// Test constant `with` expressions that don't read the source
+mod constant_with {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Draft {
+        title: String,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Published {
+        title:   String,
+        version: String,
+        tags:    Vec<String>,
+        stars:   u32,
+    }
+
+    relate_structs! {
+        Draft ~> Published {
+            title;
+            version: with = String::from("v1");
+            tags: with = Vec::new();
+            stars: with = 3 * 7;
+        }
+    }
+
+    #[test]
+    fn test_constant_with_owned_and_ref() {
+        let draft = Draft {
+            title: "Notes".to_string(),
+        };
+        let expected = Published {
+            title:   "Notes".to_string(),
+            version: "v1".to_string(),
+            tags:    Vec::new(),
+            stars:   21,
+        };
+
+        assert_eq!(Published::from(&draft), expected);
+        assert_eq!(Published::from(draft), expected);
+    }
+}
+
+// Test `vec` copying array and slice fields into `Vec` targets
+mod to_vec {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Packet {
+        header:  [u8; 4],
+        payload: &'static [u16],
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct PacketDto {
+        header:  Vec<u8>,
+        payload: Vec<u16>,
+    }
+
+    relate_structs! {
+        Packet ~> PacketDto {
+            header: vec;
+            payload: vec;
+        }
+    }
+
+    #[test]
+    fn test_to_vec_owned_and_ref() {
+        let packet = Packet {
+            header:  [0xCA, 0xFE, 0xBA, 0xBE],
+            payload: &[1, 2, 3],
+        };
+        let expected = PacketDto {
+            header:  vec![0xCA, 0xFE, 0xBA, 0xBE],
+            payload: vec![1, 2, 3],
+        };
+
+        assert_eq!(PacketDto::from(&packet), expected);
+        assert_eq!(PacketDto::from(packet), expected);
+    }
+}
+
+// Test `secs_to_duration` / `duration_to_secs` converting back in `~` relations
+mod durations {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct JobRow {
+        name:        String,
+        timeout:     u64,
+        retry_after: Duration,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Job {
+        name:        String,
+        timeout:     Duration,
+        retry_after: u64,
+    }
+
+    relate_structs! {
+        JobRow ~ Job {
+            name;
+            timeout: secs_to_duration;
+            retry_after: duration_to_secs;
+        }
+    }
+
+    #[test]
+    fn test_durations_both_ways() {
+        let row = JobRow {
+            name:        "sync".to_string(),
+            timeout:     30,
+            retry_after: Duration::from_millis(5_900),
+        };
+        let job: Job = (&row).into();
+        assert_eq!(job.timeout, Duration::from_secs(30));
+        assert_eq!(job.retry_after, 5);
+
+        let back: JobRow = job.into();
+        assert_eq!(back.timeout, 30);
+        assert_eq!(back.retry_after, Duration::from_secs(5));
+    }
+}
@@ -317,3 +317,599 @@ mod auto_try_from_detection {
         assert_eq!(result.unwrap().value, 42);
     }
 }
+
+// Test `required` - Option source must be Some, inner value converted with Into
+mod required_fields {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        id:   Option<i32>,
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        id:   i64,
+        name: String,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            id: required;
+            name;
+        }
+    }
+
+    #[test]
+    fn test_required_present() {
+        let source = Source {
+            id:   Some(5),
+            name: "x".to_string(),
+        };
+        let result: Result<Target, ConversionError> = source.try_into();
+        assert_eq!(result.unwrap().id, 5);
+    }
+
+    #[test]
+    fn test_required_missing() {
+        let source = Source {
+            id:   None,
+            name: "x".to_string(),
+        };
+        let result: Result<Target, ConversionError> = (&source).try_into();
+        assert!(matches!(result, Err(ConversionError::MissingField("id"))));
+    }
+}
+
+// Test fallible collection map auto-upgrades `~>` to TryFrom
+mod fallible_collection_map {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        values: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        values: Vec<i32>,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            values: with = [_.parse()?];
+        }
+    }
+
+    #[test]
+    fn test_fallible_collection_success() {
+        let source = Source {
+            values: vec!["1".to_string(), "-2".to_string()],
+        };
+        let result: Result<Target, ConversionError> = source.try_into();
+        assert_eq!(result.unwrap().values, vec![1, -2]);
+    }
+
+    #[test]
+    fn test_fallible_collection_short_circuits() {
+        let source = Source {
+            values: vec!["1".to_string(), "two".to_string(), "3".to_string()],
+        };
+        let result: Result<Target, ConversionError> = (&source).try_into();
+        assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+    }
+}
+
+// Test custom error type declared in the relation body
+mod body_error_type {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        value: String,
+        error: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        value: i32,
+        error: String,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct MyError(String);
+
+    impl From<std::num::ParseIntError> for MyError {
+        fn from(e: std::num::ParseIntError) -> Self { MyError(e.to_string()) }
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            error = MyError;
+            value: with = _.parse()?;
+            error: cloned;
+        }
+    }
+
+    #[test]
+    fn test_body_error_success() {
+        let source = Source {
+            value: "7".to_string(),
+            error: "none".to_string(),
+        };
+        let result: Result<Target, MyError> = (&source).try_into();
+        assert_eq!(
+            result.unwrap(),
+            Target {
+                value: 7,
+                error: "none".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_body_error_failure() {
+        let source = Source {
+            value: "seven".to_string(),
+            error: "none".to_string(),
+        };
+        let result: Result<Target, MyError> = source.try_into();
+        assert!(result.is_err());
+    }
+}
+
+// Test boxed errors are kept as the `source()` of `ConversionError`
+mod boxed_error_source {
+    use std::error::Error;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        code: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        code: u8,
+    }
+
+    fn parse_code(code: &str) -> Result<u8, Box<dyn Error + Send + Sync>> {
+        let value: u8 = code.parse()?;
+        if value == 0 {
+            return Err("code must be non-zero".into());
+        }
+        Ok(value)
+    }
+
+    relate_structs! {
+        Source ~>? Target {
+            code: with = parse_code(&_)?;
+        }
+    }
+
+    #[test]
+    fn test_source_chain() {
+        let source = Source {
+            code: "0".to_string(),
+        };
+        let result: Result<Target, ConversionError> = source.try_into();
+        let err = result.unwrap_err();
+
+        assert!(matches!(err, ConversionError::Other(_)));
+        assert_eq!(err.to_string(), "conversion failed: code must be non-zero");
+        assert_eq!(err.source().unwrap().to_string(), "code must be non-zero");
+    }
+
+    #[test]
+    fn test_parse_int_source() {
+        let err = ConversionError::from("x".parse::<i32>().unwrap_err());
+        assert!(err.source().is_some());
+
+        let wrapped = ConversionError::other(std::fmt::Error);
+        assert!(wrapped.source().unwrap().is::<std::fmt::Error>());
+    }
+}
+
+// Test per-field `map_err` adapting each field's error
+mod per_field_map_err {
+    use std::num::ParseIntError;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        port:    String,
+        retries: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        port:    u16,
+        retries: u8,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum ConfigError {
+        Port(String),
+        Retries(String),
+    }
+
+    relate_structs! {
+        Source ~>?[ConfigError] Target {
+            port: with = _.parse()?, map_err = |e: ParseIntError| ConfigError::Port(e.to_string());
+            retries: with = _.parse()?, map_err = |e: ParseIntError| ConfigError::Retries(e.to_string());
+        }
+    }
+
+    fn source(port: &str, retries: &str) -> Source {
+        Source {
+            port:    port.to_string(),
+            retries: retries.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_map_err_success() {
+        let target: Target = source("8080", "3").try_into().unwrap();
+        assert_eq!(
+            target,
+            Target {
+                port:    8080,
+                retries: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_map_err_distinguishes_fields() {
+        let result: Result<Target, ConfigError> = source("http", "3").try_into();
+        assert!(matches!(result, Err(ConfigError::Port(_))));
+
+        let result: Result<Target, ConfigError> = (&source("80", "many")).try_into();
+        assert!(matches!(result, Err(ConfigError::Retries(_))));
+    }
+}
+
+// Test `?` in the middle of an expression - still detected as fallible
+mod mid_expression_question_mark {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        value: String,
+        label: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        value: String,
+        label: String,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            value: with = _.parse::<i32>()?.to_string();
+            label;
+        }
+    }
+
+    fn source(value: &str) -> Source {
+        Source {
+            value: value.to_string(),
+            label: "x".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_mid_question_mark_success() {
+        let target: Target = source("+42").try_into().unwrap();
+        assert_eq!(target.value, "42");
+
+        let target: Target = (&source("-7")).try_into().unwrap();
+        assert_eq!(target.value, "-7");
+    }
+
+    #[test]
+    fn test_mid_question_mark_failure() {
+        let result: Result<Target, ConversionError> = source("forty").try_into();
+        assert!(result.is_err());
+    }
+}
+
+// Test `try_into = path` calling a fallible function with `?`
+mod try_into_fn {
+    use std::num::ParseIntError;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum PortError {
+        Invalid,
+    }
+
+    fn parse_port(port: impl AsRef<str>) -> Result<u16, ParseIntError> { port.as_ref().parse() }
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        port:  String,
+        other: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        port:  u16,
+        other: u16,
+    }
+
+    relate_structs! {
+        Source ~>?[PortError] Target {
+            port: try_into = parse_port, map_err = |_| PortError::Invalid;
+            other: try_into = parse_port, map_err = |_| PortError::Invalid;
+        }
+    }
+
+    #[test]
+    fn test_try_into_fn() {
+        let source = Source {
+            port:  "80".to_string(),
+            other: "81".to_string(),
+        };
+        let target: Target = (&source).try_into().unwrap();
+        assert_eq!(
+            target,
+            Target {
+                port:  80,
+                other: 81,
+            }
+        );
+        assert_eq!(Target::try_from(source), Ok(target));
+
+        let source = Source {
+            port:  "80".to_string(),
+            other: "x".to_string(),
+        };
+        assert_eq!(Target::try_from(source), Err(PortError::Invalid));
+    }
+}
+
+// Test `#![default_error(Type)]` sets the error of every fallible relation
+mod default_error {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        value: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Parsed {
+        value: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Checked {
+        value: u8,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Plain {
+        value: i64,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct MyError(String);
+
+    impl From<std::num::ParseIntError> for MyError {
+        fn from(e: std::num::ParseIntError) -> Self { MyError(e.to_string()) }
+    }
+
+    relate_structs! {
+        #![default_error(MyError)]
+
+        Source ~>? Parsed {
+            value: with = _.parse()?;
+        }
+
+        // Auto-detected fallible relations use the default too
+        Source ~> Checked {
+            value: with = _.parse()?;
+        }
+
+        // An explicit error type wins
+        Source ~>?[ConversionError] Plain {
+            value: with = _.parse()?;
+        }
+    }
+
+    fn source(value: &str) -> Source {
+        Source {
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_default_error() {
+        let result: Result<Parsed, MyError> = source("42").try_into();
+        assert_eq!(result, Ok(Parsed { value: 42 }));
+        let result: Result<Parsed, MyError> = source("x").try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_default_error_auto_detected() {
+        let result: Result<Checked, MyError> = (&source("300")).try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_explicit_error_wins() {
+        let result: Result<Plain, ConversionError> = source("7").try_into();
+        assert_eq!(result.unwrap(), Plain { value: 7 });
+    }
+}
+
+// Test `~>?[Option]`: `?` on `Option`s, with the error type `()`
+mod option_short_circuit {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Tokens {
+        words: Vec<String>,
+        count: Option<u32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Command {
+        words: String,
+        count: u32,
+    }
+
+    relate_structs! {
+        Tokens ~>?[Option] Command {
+            words: with = _.first()?.clone();
+            count: with = _?;
+        }
+    }
+
+    #[test]
+    fn test_option_some() {
+        let tokens = Tokens {
+            words: vec!["run".to_string(), "fast".to_string()],
+            count: Some(2),
+        };
+        let command = Command::try_from(&tokens).unwrap();
+        assert_eq!(
+            command,
+            Command {
+                words: "run".to_string(),
+                count: 2,
+            }
+        );
+        assert_eq!(Command::try_from(tokens).unwrap().count, 2);
+    }
+
+    #[test]
+    fn test_option_none() {
+        let tokens = Tokens {
+            words: Vec::new(),
+            count: Some(1),
+        };
+        assert_eq!(Command::try_from(tokens), Err(()));
+        let tokens = Tokens {
+            words: vec!["run".to_string()],
+            count: None,
+        };
+        assert_eq!(Command::try_from(&tokens), Err(()));
+    }
+}
+
+// Test a block-valued `with`, whose `?` inside the block marks it fallible
+mod block_expression {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        min: String,
+        max: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        min: u32,
+        max: u32,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            min: with = { let trimmed = .min.trim(); trimmed.parse::<u32>()? };
+            max: with = {
+                let max: u32 = _.trim().parse()?;
+                max.max(1)
+            };
+        }
+    }
+
+    fn source(min: &str, max: &str) -> Source {
+        Source {
+            min: min.to_string(),
+            max: max.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_block_success() {
+        let target: Target = source(" 2 ", "0").try_into().unwrap();
+        assert_eq!(target, Target { min: 2, max: 1 });
+
+        let target: Target = (&source("3", " 9")).try_into().unwrap();
+        assert_eq!(target, Target { min: 3, max: 9 });
+    }
+
+    #[test]
+    fn test_block_failure() {
+        let result: Result<Target, ConversionError> = source("2", "many").try_into();
+        assert!(matches!(result, Err(ConversionError::ParseInt(_))));
+    }
+}
+
+// Test a `?` inside a closure, which returns from the closure and keeps `From`
+mod closure_question_mark {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Source {
+        codes: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Target {
+        codes: Vec<u8>,
+    }
+
+    relate_structs! {
+        Source ~> Target {
+            codes: with = _.iter().filter_map(|s| Some(s.parse::<u8>().ok()? + 1)).collect();
+        }
+    }
+
+    #[test]
+    fn test_closure_question_mark_is_infallible() {
+        let source = Source {
+            codes: vec!["1".to_string(), "x".to_string(), "9".to_string()],
+        };
+        let target = Target::from(&source);
+        assert_eq!(target.codes, vec![2, 10]);
+        assert_eq!(Target::from(source), target);
+    }
+}
+
+// Test `~>?[()]` keeping a `Result` body, so `?` applies to `Result<_, ()>`
+mod unit_error {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Raw {
+        n: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Parsed {
+        n: u32,
+    }
+
+    relate_structs! {
+        Raw ~>?[()] Parsed {
+            n: with = _.parse::<u32>().map_err(drop)?;
+        }
+    }
+
+    #[test]
+    fn test_unit_error_result() {
+        let raw = Raw { n: "5".to_string() };
+        assert_eq!(Parsed::try_from(&raw), Ok(Parsed { n: 5 }));
+        let raw = Raw { n: "x".to_string() };
+        assert_eq!(Parsed::try_from(raw), Err(()));
+    }
+}
@@ -0,0 +1,98 @@
+//! Helpers for converting collections with the generated impls.
+
+use std::sync::Arc;
+
+/// Convert every element of a `Vec` with `From`.
+///
+/// # Example
+///
+/// ```rust
+/// let wide: Vec<u32> = relate::convert_vec(vec![1u8, 2, 3]);
+/// assert_eq!(wide, vec![1, 2, 3]);
+/// ```
+#[inline]
+pub fn convert_vec<S, T: From<S>>(v: Vec<S>) -> Vec<T> { v.into_iter().map(T::from).collect() }
+
+/// Convert every element of a `Vec` with `TryFrom`, stopping at the first
+/// error.
+///
+/// # Example
+///
+/// ```rust
+/// let bytes: Result<Vec<u8>, _> = relate::try_convert_vec(vec![1i32, 2, 3]);
+/// assert_eq!(bytes.unwrap(), vec![1, 2, 3]);
+///
+/// let bytes: Result<Vec<u8>, _> = relate::try_convert_vec(vec![1i32, 300]);
+/// assert!(bytes.is_err());
+/// ```
+#[inline]
+pub fn try_convert_vec<S, T: TryFrom<S>>(v: Vec<S>) -> Result<Vec<T>, T::Error> {
+    v.into_iter().map(T::try_from).collect()
+}
+
+/// Convert the value behind a shared `Arc` with `From<&S>`, into a new `Arc`.
+///
+/// Collection maps read elements by reference, so a `Vec<Arc<Source>>` field
+/// becomes a `Vec<Arc<Target>>` with `with = [relate::arc_into(_)]`. The
+/// reference impls generated next to the owned ones provide `From<&Source>`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// struct Celsius(f64);
+/// struct Kelvin(f64);
+///
+/// impl From<&Celsius> for Kelvin {
+///     fn from(c: &Celsius) -> Self { Self(c.0 + 273.15) }
+/// }
+///
+/// let shared = vec![Arc::new(Celsius(0.0))];
+/// let kelvin: Vec<Arc<Kelvin>> = shared.iter().map(relate::arc_into).collect();
+/// assert_eq!(kelvin[0].0, 273.15);
+/// ```
+#[inline]
+pub fn arc_into<S, T>(src: &Arc<S>) -> Arc<T>
+where
+    T: for<'a> From<&'a S>,
+{
+    Arc::new(T::from(src))
+}
+
+/// Extension methods that name the target type at the call site.
+///
+/// `src.relate::<Config>()` reads better than annotating the binding for
+/// `src.into()`, and `try_relate` does the same for `TryFrom`. Implemented for
+/// every sized type.
+///
+/// # Example
+///
+/// ```rust
+/// use relate::RelateExt;
+///
+/// let wide = 7u8.relate::<u32>();
+/// assert_eq!(wide, 7);
+///
+/// assert_eq!(300i32.try_relate::<u16>(), Ok(300));
+/// assert!(300i32.try_relate::<u8>().is_err());
+/// ```
+pub trait RelateExt: Sized {
+    /// Convert `self` into `T` with `From`.
+    fn relate<T: From<Self>>(self) -> T;
+
+    /// Convert `self` into `T` with `TryFrom`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `T::Error` when the conversion fails.
+    fn try_relate<T: TryFrom<Self>>(self) -> Result<T, T::Error>;
+}
+
+impl<S> RelateExt for S {
+    #[inline]
+    fn relate<T: From<Self>>(self) -> T { T::from(self) }
+
+    #[inline]
+    fn try_relate<T: TryFrom<Self>>(self) -> Result<T, T::Error> { T::try_from(self) }
+}
@@ -0,0 +1,41 @@
+//! Reading fields out of a delimited `&str` row.
+
+use std::str::FromStr;
+
+use crate::ConversionError;
+
+/// Parse column `index` of a row split on `delimiter`.
+///
+/// The column is trimmed before parsing. A row with too few columns is a
+/// [`ConversionError::MissingField`] naming the field; a column that fails
+/// to parse is labelled with it. This is what
+/// `#[relate(&str, delimiter = ",")]` calls for each field.
+///
+/// # Example
+///
+/// ```rust
+/// let id: u32 = relate::delimited_field("7, Ada", ",", 0, "id").unwrap();
+/// assert_eq!(id, 7);
+/// let name: String = relate::delimited_field("7, Ada", ",", 1, "name").unwrap();
+/// assert_eq!(name, "Ada");
+/// assert!(relate::delimited_field::<u8>("7, Ada", ",", 2, "age").is_err());
+/// ```
+pub fn delimited_field<T>(
+    src: &str,
+    delimiter: &str,
+    index: usize,
+    name: &'static str,
+) -> Result<T, ConversionError>
+where
+    T: FromStr,
+    T::Err: Into<ConversionError>,
+{
+    let column = src
+        .split(delimiter)
+        .nth(index)
+        .ok_or(ConversionError::MissingField(name))?;
+    column
+        .trim()
+        .parse()
+        .map_err(|error| ConversionError::with_context(name, error))
+}
@@ -5,7 +5,14 @@ use thiserror::Error;
 /// Error type for `TryFrom` conversions generated by `relate_structs!`.
 ///
 /// This is the default error type when using `~>?` syntax without specifying
-/// a custom error type. It automatically captures common conversion errors.
+/// a custom error type. It automatically captures common conversion errors,
+/// and any other error can be boxed into [`ConversionError::Other`]. It
+/// implements [`std::error::Error`] with `source()` returning the wrapped
+/// error, so `?` works from `try_into()` into `anyhow::Result` and friends.
+///
+/// The enum is `#[non_exhaustive]`: variants are added over time, and some
+/// only exist with a feature enabled (`Json` needs `serde`), so a `match`
+/// needs a wildcard arm.
 ///
 /// # Example
 ///
@@ -26,6 +33,7 @@ use thiserror::Error;
 /// }
 /// ```
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ConversionError {
     /// A required field was None when a value was expected.
     #[error("missing required field: {0}")]
@@ -51,9 +59,49 @@ pub enum ConversionError {
     #[error("invalid UTF-8 string: {0}")]
     FromUtf8(#[from] std::string::FromUtf8Error),
 
+    /// A JSON value had the wrong shape for its field
+    /// (`#[relate(serde_json::Value)]`).
+    #[cfg(feature = "serde")]
+    #[error("invalid JSON value: {0}")]
+    Json(#[from] serde_json::Error),
+
     /// Custom error message.
     #[error("{0}")]
     Custom(String),
+
+    /// The converted value failed its `validate = predicate` check; holds
+    /// the target type's name.
+    #[error("validation failed for {0}")]
+    Validation(&'static str),
+
+    /// An integer matched no variant of an enum converted from its
+    /// discriminant (`#[relate(i32)]` on a fieldless enum).
+    #[error("no variant of {enum_name} has discriminant {value}")]
+    UnknownDiscriminant {
+        /// The enum's name
+        enum_name: &'static str,
+        /// The unmatched integer
+        value:     i128,
+    },
+
+    /// Several fields failed (`#[relate(Source, collect_errors)]`), in field
+    /// order.
+    #[error("{} fields failed to convert: {}", .0.len(), join_errors(.0))]
+    Multiple(Vec<ConversionError>),
+
+    /// An error labelled with the conversion it came from
+    /// (`#[relate(Source, context = "...")]`).
+    #[error("{context}: {source}")]
+    Context {
+        /// The static label, e.g. `"parsing Config"`
+        context: &'static str,
+        /// The underlying error
+        source:  Box<ConversionError>,
+    },
+
+    /// Any other error, kept as the `source()` of this one.
+    #[error("conversion failed: {0}")]
+    Other(#[from] Box<dyn std::error::Error + Send + Sync>),
 }
 
 impl ConversionError {
@@ -61,11 +109,40 @@ impl ConversionError {
     #[must_use]
     pub fn custom(msg: impl Into<String>) -> Self { Self::Custom(msg.into()) }
 
+    /// Wrap any other error, keeping it as the `source()`.
+    #[must_use]
+    pub fn other(error: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Other(Box::new(error))
+    }
+
+    /// Label an error with the conversion it came from.
+    #[must_use]
+    pub fn with_context(context: &'static str, error: impl Into<Self>) -> Self {
+        Self::Context {
+            context,
+            source: Box::new(error.into()),
+        }
+    }
+
     /// Create a missing field error.
     #[must_use]
     pub const fn missing_field(field: &'static str) -> Self { Self::MissingField(field) }
 }
 
+/// Join errors into a single `; `-separated message.
+fn join_errors(errors: &[ConversionError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+// Parsing a `String` can't fail
+impl From<std::convert::Infallible> for ConversionError {
+    fn from(never: std::convert::Infallible) -> Self { match never {} }
+}
+
 impl From<String> for ConversionError {
     fn from(s: String) -> Self { Self::Custom(s) }
 }
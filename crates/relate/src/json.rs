@@ -0,0 +1,36 @@
+//! Reading fields out of a `serde_json::Value` (`serde` feature).
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::ConversionError;
+
+/// Deserialize the field `key` of a JSON object.
+///
+/// A missing key reads as `null`, so `Option` fields become `None`; for any
+/// other type it's a [`ConversionError::MissingField`]. A value of the wrong
+/// shape is a [`ConversionError::Json`] labelled with the key. This is what
+/// `#[relate(serde_json::Value)]` calls for each field.
+///
+/// # Example
+///
+/// ```rust
+/// let value = serde_json::json!({ "name": "Ada", "age": 36 });
+///
+/// let name: String = relate::json_field(&value, "name").unwrap();
+/// assert_eq!(name, "Ada");
+/// let email: Option<String> = relate::json_field(&value, "email").unwrap();
+/// assert_eq!(email, None);
+/// assert!(relate::json_field::<u8>(&value, "name").is_err());
+/// ```
+pub fn json_field<T>(value: &Value, key: &'static str) -> Result<T, ConversionError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    match value.get(key) {
+        Some(field) => {
+            T::deserialize(field).map_err(|error| ConversionError::with_context(key, error))
+        }
+        None => T::deserialize(&Value::Null).map_err(|_| ConversionError::MissingField(key)),
+    }
+}
@@ -52,10 +52,42 @@
 //! // Default error type is ConversionError
 //! let config: Result<Config, ConversionError> = raw.try_into();
 //! ```
+//!
+//! # Bulk conversions
+//!
+//! [`convert_vec`] and [`try_convert_vec`] convert a whole `Vec` with the
+//! generated `From` / `TryFrom` impls. [`arc_into`] converts a shared
+//! `Arc<Source>` into an `Arc<Target>`, for collections of shared elements.
+//!
+//! # Naming the target at the call site
+//!
+//! [`RelateExt`] adds `relate::<T>()` and `try_relate::<T>()` to every type,
+//! so `raw.try_relate::<Config>()` needs no annotated binding.
+//!
+//! # JSON objects
+//!
+//! With the `serde` feature, `#[derive(Relate)]` on a struct with
+//! `#[relate(serde_json::Value)]` generates a `TryFrom<serde_json::Value>`
+//! that reads each field from the object key of the same name (or its
+//! rename) with [`json_field`].
+//!
+//! # Delimited rows
+//!
+//! `#[derive(Relate)]` on a struct with `#[relate(&str, delimiter = ",")]`
+//! generates a `TryFrom<&str>` that splits the row and parses each field from
+//! its column, in order, with [`delimited_field`].
 
+mod convert;
+mod delimited;
 mod error;
+#[cfg(feature = "serde")]
+mod json;
 
+pub use convert::{RelateExt, arc_into, convert_vec, try_convert_vec};
+pub use delimited::delimited_field;
 pub use error::ConversionError;
+#[cfg(feature = "serde")]
+pub use json::json_field;
 // Re-export macros when the derive feature is enabled
 #[cfg(feature = "derive")]
 pub use relate_macros::{Relate, relate_structs};